@@ -18,7 +18,10 @@ use utoipa::ToSchema;
 
 use super::payments::AddressDetails;
 use crate::{
-    consts::{MAX_ORDER_FULFILLMENT_EXPIRY, MIN_ORDER_FULFILLMENT_EXPIRY},
+    consts::{
+        MAX_ORDER_FULFILLMENT_EXPIRY, MAX_WEBHOOK_ENDPOINTS_PER_PROFILE,
+        MAX_WEBHOOK_ENDPOINT_ID_LENGTH, MIN_ORDER_FULFILLMENT_EXPIRY,
+    },
     enums as api_enums, payment_methods,
 };
 #[cfg(feature = "v1")]
@@ -266,6 +269,34 @@ pub struct CardTestingGuardConfig {
     pub customer_id_blocking_threshold: i32,
     /// Determines Redis Expiry for Card Testing Guard for profile
     pub card_testing_guard_expiry: i32,
+    /// Merchant defined velocity rules evaluated on every card payment confirmation
+    #[serde(default)]
+    pub velocity_rules: Vec<VelocityRule>,
+}
+
+/// A limit on the payment attempts made over a sliding window by the payments sharing the values
+/// of the keys of the rule
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct VelocityRule {
+    /// Name of the rule, unique within the profile
+    #[schema(example = "card_ip_hourly_amount")]
+    pub name: String,
+    /// The attributes of the payment that the attempts are grouped by. Payments missing any of
+    /// the attributes are not evaluated against the rule.
+    #[schema(value_type = Vec<VelocityRuleKey>)]
+    pub keys: Vec<common_enums::VelocityRuleKey>,
+    /// Whether the number of attempts or the sum of their amounts is limited
+    #[schema(value_type = VelocityAggregation)]
+    pub aggregation: common_enums::VelocityAggregation,
+    /// The action is taken when the attempt would take the aggregate above this value
+    #[schema(example = 5)]
+    pub threshold: i64,
+    /// Length of the sliding window in seconds
+    #[schema(example = 3600)]
+    pub window_in_secs: u32,
+    /// Action taken on the payments exceeding the threshold
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: common_enums::VelocityRuleAction,
 }
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -715,6 +746,39 @@ pub struct WebhookDetails {
     #[cfg(feature = "payouts")]
     #[schema(value_type = Option<Vec<PayoutStatus>>, example = json!(["success", "failed"]))]
     pub payout_statuses_enabled: Option<Vec<api_enums::PayoutStatus>>,
+
+    /// Additional endpoints the webhooks are delivered to, along with the webhook url
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
+
+    /// Time in seconds for which webhooks continue to be signed with a signing secret after it is
+    /// replaced, along with the new signing secret. Defaults to 86400 seconds (24 hours).
+    #[schema(example = 86400)]
+    pub signing_secret_rotation_grace_period_in_secs: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpoint {
+    /// The identifier for the endpoint, unique within the profile
+    #[schema(max_length = 16, example = "analytics")]
+    pub endpoint_id: String,
+
+    /// The url for the webhook endpoint
+    #[schema(value_type = String, example = "www.ekart.com/webhooks/analytics")]
+    pub url: Secret<String>,
+
+    /// Whether webhooks are delivered to the endpoint. Defaults to true.
+    #[schema(example = true)]
+    pub is_enabled: Option<bool>,
+
+    /// The event types delivered to the endpoint. All event types are delivered if not provided.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// The secret used to sign the webhooks delivered to the endpoint. The payment response hash
+    /// key of the profile is used if not provided.
+    #[schema(value_type = Option<String>, example = "whsec_5fa1b3c8e9")]
+    pub signing_secret: Option<Secret<String>>,
 }
 
 impl WebhookDetails {
@@ -751,6 +815,57 @@ impl WebhookDetails {
             }
         }
 
+        if let Some(webhook_endpoints) = &self.webhook_endpoints {
+            Self::validate_webhook_endpoints(webhook_endpoints)?;
+        }
+
+        Ok(())
+    }
+
+    fn validate_webhook_endpoints(webhook_endpoints: &[WebhookEndpoint]) -> Result<(), String> {
+        if webhook_endpoints.len() > MAX_WEBHOOK_ENDPOINTS_PER_PROFILE {
+            return Err(format!(
+                "At most {MAX_WEBHOOK_ENDPOINTS_PER_PROFILE} webhook endpoints can be configured"
+            ));
+        }
+
+        let mut endpoint_ids = HashSet::new();
+        for endpoint in webhook_endpoints {
+            let endpoint_id = endpoint.endpoint_id.as_str();
+            if endpoint_id.is_empty()
+                || endpoint_id.len() > MAX_WEBHOOK_ENDPOINT_ID_LENGTH
+                || !endpoint_id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                return Err(format!(
+                    "Invalid webhook endpoint id `{endpoint_id}`, it must contain at most \
+                     {MAX_WEBHOOK_ENDPOINT_ID_LENGTH} alphanumeric characters, underscores or \
+                     hyphens"
+                ));
+            }
+
+            if !endpoint_ids.insert(endpoint_id) {
+                return Err(format!("Duplicate webhook endpoint id `{endpoint_id}`"));
+            }
+
+            if endpoint.url.peek().trim().is_empty() {
+                return Err(format!(
+                    "Webhook url of the endpoint `{endpoint_id}` must not be empty"
+                ));
+            }
+
+            if endpoint
+                .signing_secret
+                .as_ref()
+                .is_some_and(|secret| secret.peek().is_empty())
+            {
+                return Err(format!(
+                    "Signing secret of the webhook endpoint `{endpoint_id}` must not be empty"
+                ));
+            }
+        }
+
         Ok(())
     }
 }
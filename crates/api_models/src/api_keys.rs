@@ -1,3 +1,4 @@
+pub use common_types::api_keys::{ApiKeyPermission, ApiKeyRestrictions};
 use common_utils::custom_serde;
 use masking::StrongSecret;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,10 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Restrictions on the access granted by the API Key. The API Key has full access to the
+    /// merchant account when not provided.
+    pub restrictions: Option<ApiKeyRestrictions>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +65,9 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The restrictions on the access granted by the API Key.
+    pub restrictions: Option<ApiKeyRestrictions>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +110,9 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The restrictions on the access granted by the API Key.
+    pub restrictions: Option<ApiKeyRestrictions>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +141,9 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// Restrictions on the access granted by the API Key.
+    pub restrictions: Option<ApiKeyRestrictions>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
@@ -9,6 +9,26 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    BinRange(CardBinRange),
+    #[schema(value_type = CountryAlpha2)]
+    IssuerCountry(enums::CountryAlpha2),
+    #[schema(value_type = CardNetwork)]
+    CardNetwork(enums::CardNetwork),
+    /// Blocks the email domain along with all of its subdomains
+    EmailDomain(String),
+    /// An IPv4 or IPv6 network in CIDR notation, for example `192.168.0.0/16`
+    IpCidr(String),
+}
+
+/// An inclusive range of card BINs, both ends of the range must either be 6 or 8 digits long
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CardBinRange {
+    /// The first BIN of the range
+    #[schema(example = "411111")]
+    pub start: String,
+    /// The last BIN of the range
+    #[schema(example = "411199")]
+    pub end: String,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
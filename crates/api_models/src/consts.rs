@@ -3,3 +3,9 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Max number of additional webhook endpoints per business profile
+pub const MAX_WEBHOOK_ENDPOINTS_PER_PROFILE: usize = 10;
+
+/// Max length of the identifier of an additional webhook endpoint
+pub const MAX_WEBHOOK_ENDPOINT_ID_LENGTH: usize = 16;
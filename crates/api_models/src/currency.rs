@@ -7,6 +7,10 @@ pub struct CurrencyConversionParams {
     pub amount: MinorUnit,
     pub to_currency: String,
     pub from_currency: String,
+    /// Convert with the exchange rates that were in effect at this time instead of the latest
+    /// rates
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub as_of: Option<time::PrimitiveDateTime>,
 }
 
 /// Response to be send for convert currency route
@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
 use common_utils::types::{StringMinorUnit, TimeRange};
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
 use masking::{Deserialize, Serialize};
 use serde::de::Error;
 use time::PrimitiveDateTime;
@@ -230,6 +234,107 @@ pub struct DisputesAggregateResponse {
     pub status_with_count: HashMap<DisputeStatus, i64>,
 }
 
+/// The action to be taken on an incoming dispute when a dispute auto response rule matches
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "data")]
+pub enum DisputeAutoResponseAction {
+    /// Accept the dispute at the connector
+    Accept,
+    /// Submit the evidence assembled from the evidence template and the payment data to the connector
+    SubmitEvidence { evidence_template: String },
+    /// Leave the dispute to be handled by the merchant
+    ManualReview,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisputeAutoResponseOutput {
+    pub action: DisputeAutoResponseAction,
+}
+
+impl EuclidDirFilter for DisputeAutoResponseOutput {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::DisputeReasonCode,
+        DirKeyKind::DisputeConnector,
+    ];
+}
+
+/// Evidence which is submitted for a dispute by the dispute auto response rules. Evidence
+/// derived from the payment, such as the customer details and addresses, is added at the time of
+/// submission.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeEvidenceTemplate {
+    /// Name of the template, referred to by the dispute auto response rules
+    pub name: String,
+    /// Logs showing the usage of service by customer
+    pub access_activity_log: Option<String>,
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Details telling why customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// File Id of customer communication
+    pub customer_communication: Option<String>,
+    /// Product Description
+    pub product_description: Option<String>,
+    /// File Id of receipt
+    pub receipt: Option<String>,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// Details why customer is not entitled to refund
+    pub refund_refusal_explanation: Option<String>,
+    /// File Id service documentation
+    pub service_documentation: Option<String>,
+    /// Delivery service that shipped the product
+    pub shipping_carrier: Option<String>,
+    /// File Id shipping documentation
+    pub shipping_documentation: Option<String>,
+    /// File Id of recurring transaction agreement
+    pub recurring_transaction_agreement: Option<String>,
+    /// Any additional supporting file
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeAutoResponseConfigRecord {
+    pub name: String,
+    pub algorithm: Program<DisputeAutoResponseOutput>,
+    pub evidence_templates: Vec<DisputeEvidenceTemplate>,
+    /// Time in seconds before the connector deadline at which the evidence is submitted
+    pub evidence_submission_buffer_in_secs: u32,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+impl DisputeAutoResponseConfigRecord {
+    pub fn get_evidence_template(&self, name: &str) -> Option<&DisputeEvidenceTemplate> {
+        self.evidence_templates
+            .iter()
+            .find(|template| template.name == name)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutoResponseConfigRequest {
+    pub name: Option<String>,
+    pub algorithm: Option<Program<DisputeAutoResponseOutput>>,
+    #[serde(default)]
+    pub evidence_templates: Vec<DisputeEvidenceTemplate>,
+    pub evidence_submission_buffer_in_secs: Option<u32>,
+}
+
+/// Default time in seconds before the connector deadline at which the evidence is submitted
+pub const DEFAULT_EVIDENCE_SUBMISSION_BUFFER_IN_SECS: u32 = 24 * 60 * 60;
+
 fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -113,6 +113,7 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError, #[serde(skip_serializing)] u64),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -142,6 +143,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i, _)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -160,6 +162,7 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i, _)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -175,7 +178,8 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_, _) => "invalid_request",
             Self::InternalServerError(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
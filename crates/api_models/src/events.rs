@@ -9,6 +9,7 @@ mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refund;
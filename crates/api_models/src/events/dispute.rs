@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputesAggregateResponse, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeAutoResponseConfigRecord, DisputeAutoResponseConfigRequest,
+    DisputeResponse, DisputeResponsePaymentsRetrieve, DisputesAggregateResponse,
+    SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -39,3 +40,15 @@ impl ApiEventMetric for DisputesAggregateResponse {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for DisputeAutoResponseConfigRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DisputeAutoResponseConfigRecord {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -37,3 +37,21 @@ impl ApiEventMetric for gsm::GsmResponse {
         Some(ApiEventsType::Gsm)
     }
 }
+
+impl ApiEventMetric for gsm::GsmEvaluateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmEvaluateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
+
+impl ApiEventMetric for gsm::GsmListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Gsm)
+    }
+}
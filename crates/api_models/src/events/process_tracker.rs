@@ -0,0 +1,51 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::process_tracker::admin;
+
+impl ApiEventMetric for admin::ProcessTrackerListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ProcessTrackerId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ProcessTrackerRescheduleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ProcessTrackerRunnerRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ProcessTrackerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ProcessTrackerListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::PausedRunnerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for admin::ResumedRunnerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
@@ -6,11 +6,11 @@ use crate::routing::{
     LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
     RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingKind,
     RoutingLinkWrapper, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
-    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, RoutingVolumeSplit,
-    RoutingVolumeSplitResponse, RoutingVolumeSplitWrapper, RuleMigrationError, RuleMigrationQuery,
-    RuleMigrationResponse, RuleMigrationResult, SuccessBasedRoutingConfig,
-    SuccessBasedRoutingPayloadWrapper, ToggleDynamicRoutingPath, ToggleDynamicRoutingQuery,
-    ToggleDynamicRoutingWrapper,
+    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, RoutingSimulationRequest,
+    RoutingSimulationResponse, RoutingVolumeSplit, RoutingVolumeSplitResponse,
+    RoutingVolumeSplitWrapper, RuleMigrationError, RuleMigrationQuery, RuleMigrationResponse,
+    RuleMigrationResult, SuccessBasedRoutingConfig, SuccessBasedRoutingPayloadWrapper,
+    ToggleDynamicRoutingPath, ToggleDynamicRoutingQuery, ToggleDynamicRoutingWrapper,
 };
 
 impl ApiEventMetric for RoutingKind {
@@ -202,3 +202,15 @@ impl ApiEventMetric for crate::open_router::UpdateScoreResponse {
         Some(ApiEventsType::Routing)
     }
 }
+
+impl ApiEventMetric for RoutingSimulationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for RoutingSimulationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
@@ -1,8 +1,17 @@
 use common_enums::ErrorCategory;
+use common_utils::id_type;
+use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
 use crate::enums::Connector;
 
+/// Sub flow of the rules looked up on connector errors
+pub const DEFAULT_GSM_SUB_FLOW: &str = "sub_flow";
+
+pub const DEFAULT_GSM_LIST_LIMIT: u32 = 100;
+
+pub const MAX_GSM_LIST_LIMIT: u32 = 1000;
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmCreateRequest {
     /// The connector through which payment has gone through
@@ -33,6 +42,65 @@ pub struct GsmCreateRequest {
     pub clear_pan_possible: bool,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmEvaluateRequest {
+    /// The connector which returned the error
+    pub connector: Connector,
+    /// The flow in which the code and message occurred for a connector
+    pub flow: String,
+    /// The sub_flow in which the code and message occurred for a connector, defaults to the
+    /// sub_flow the retry engine looks up
+    pub sub_flow: Option<String>,
+    /// code received from the connector
+    pub code: String,
+    /// message received from the connector
+    pub message: String,
+    /// The merchant whose step up configuration is considered
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// The authentication type of the failed payment
+    #[schema(value_type = Option<AuthenticationType>)]
+    pub authentication_type: Option<common_enums::AuthenticationType>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct GsmEvaluateResponse {
+    /// The rule matching the connector error, if any
+    pub matched_rule: Option<GsmResponse>,
+    /// decision of the matched rule, `do_default` when no rule matches
+    pub decision: GsmDecision,
+    /// What the retry engine would do for a payment failing with the connector error. Retries
+    /// are subject to the retries configured for the merchant and the connectors left to route
+    /// to.
+    pub retry_action: GsmRetryAction,
+}
+
+#[derive(Clone, Copy, Debug, strum::Display, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GsmRetryAction {
+    /// Retry the payment on the same connector with 3DS
+    StepUp,
+    /// Retry the payment on the next eligible connector
+    Retry,
+    /// Requeue the payment
+    Requeue,
+    /// Fail the payment without retrying
+    DoNotRetry,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct GsmListRequest {
+    /// The connector whose rules are listed
+    pub connector: Connector,
+    /// Only list the rules of the flow
+    pub flow: Option<String>,
+    /// Maximum number of rules to return, defaults to 100
+    pub limit: Option<u32>,
+    /// Number of rules to skip
+    pub offset: Option<u32>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct GsmRetrieveRequest {
     /// The connector through which payment has gone through
@@ -154,4 +222,10 @@ pub struct GsmResponse {
     pub error_category: Option<ErrorCategory>,
     /// indicates if retry with pan is possible
     pub clear_pan_possible: bool,
+    /// number of times the rule was matched by a connector error
+    pub hit_count: i64,
+    /// time at which the rule was last matched by a connector error
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_hit_at: Option<PrimitiveDateTime>,
 }
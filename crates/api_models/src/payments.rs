@@ -1181,6 +1181,10 @@ pub struct PaymentsRequest {
     /// If enabled, provides whole connector response
     pub all_keys_required: Option<bool>,
 
+    /// Decision of the customer on the dynamic currency conversion offer returned by a previous confirm
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub dcc_decision: Option<DccDecision>,
+
     /// Indicates whether the `payment_id` was provided by the merchant
     /// This value is inferred internally based on the request
     #[serde(skip_deserializing)]
@@ -5217,6 +5221,61 @@ pub struct PaymentsResponse {
     /// Contains whole connector response
     #[schema(value_type = Option<String>)]
     pub whole_connector_response: Option<Secret<String>>,
+
+    /// Offer to pay in the currency of the card, returned when the payment awaits the decision of the customer
+    pub dcc_offer: Option<DccOffer>,
+
+    /// Velocity rules of the card testing guard that the payment exceeded without being blocked
+    #[schema(value_type = Option<Vec<TriggeredVelocityRule>>)]
+    pub triggered_velocity_rules: Option<Vec<common_types::payments::TriggeredVelocityRule>>,
+}
+
+/// Offer to pay in the currency of the card instead of the currency of the payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DccOffer {
+    /// Identifier of the offer, to be sent back along with the decision of the customer
+    #[schema(example = "dcc_b9d6Xq1bpKmFRbk2Vb0u")]
+    pub offer_id: String,
+
+    /// The amount of the payment in the payment currency
+    #[schema(value_type = i64, example = 6540)]
+    pub original_amount: MinorUnit,
+
+    /// The currency of the payment
+    #[schema(example = "USD", value_type = Currency)]
+    pub original_currency: common_enums::Currency,
+
+    /// The amount the customer pays in the currency of the card, inclusive of markup
+    #[schema(value_type = i64, example = 545800)]
+    pub converted_amount: MinorUnit,
+
+    /// The currency in which the card was issued
+    #[schema(example = "INR", value_type = Currency)]
+    pub card_currency: common_enums::Currency,
+
+    /// Rate, inclusive of markup, applied to one unit of the payment currency
+    #[schema(example = "83.4556")]
+    pub exchange_rate: String,
+
+    /// Markup over the wholesale exchange rate, in percent
+    #[schema(example = "3.5")]
+    pub markup_percentage: String,
+
+    /// Time after which the offer can no longer be accepted
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+/// Decision of the customer on a dynamic currency conversion offer
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DccDecision {
+    /// Identifier of the offer returned in the confirm response
+    #[schema(example = "dcc_b9d6Xq1bpKmFRbk2Vb0u")]
+    pub offer_id: String,
+
+    /// Whether the customer chose to pay in the currency of their card
+    pub accepted: bool,
 }
 
 #[cfg(feature = "v2")]
@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Default number of tasks returned when listing process tracker tasks
+pub const DEFAULT_LIST_LIMIT: u32 = 10;
+
+/// Maximum number of tasks that can be returned when listing process tracker tasks
+pub const MAX_LIST_LIMIT: u32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListConstraints {
+    /// Runner of the tasks to be listed
+    #[schema(value_type = Option<String>, example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<enums::ProcessTrackerRunner>,
+
+    /// Status of the tasks to be listed
+    #[schema(value_type = Option<ProcessTrackerStatus>, example = "pending")]
+    pub status: Option<enums::ProcessTrackerStatus>,
+
+    /// Business status of the tasks to be listed
+    #[schema(example = "Pending")]
+    pub business_status: Option<String>,
+
+    /// Number of tasks to be returned, defaults to 10 and is capped at 100
+    pub limit: Option<u32>,
+
+    /// Number of tasks to be skipped, defaults to 0
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerId {
+    /// Identifier of the process tracker task
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// Identifier of the process tracker task
+    #[serde(skip_deserializing)]
+    pub id: String,
+
+    /// Time at which the task should be executed, must not be in the past
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerRunnerRequest {
+    /// Runner whose tasks are to be paused or resumed
+    #[schema(value_type = String, example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: enums::ProcessTrackerRunner,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerResponse {
+    /// Identifier of the process tracker task
+    pub id: String,
+
+    /// Name of the task
+    pub name: Option<String>,
+
+    /// Runner of the task
+    #[schema(example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<String>,
+
+    /// Tags associated with the task
+    pub tag: Vec<String>,
+
+    /// Number of times the task has been retried
+    pub retry_count: i32,
+
+    /// Time at which the task is scheduled to be executed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    /// Data required by the runner to execute the task
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+
+    #[schema(value_type = ProcessTrackerStatus, example = "pending")]
+    pub status: enums::ProcessTrackerStatus,
+
+    /// Outcome of the task as recorded by its runner
+    #[schema(example = "Pending")]
+    pub business_status: String,
+
+    /// History of retries and operator actions on the task, oldest first. Only populated when
+    /// retrieving a single task.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history: Option<Vec<ProcessTrackerEventResponse>>,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerEventResponse {
+    #[schema(value_type = ProcessTrackerEventType, example = "retried")]
+    pub event_type: enums::ProcessTrackerEventType,
+
+    /// Status of the task before the event
+    #[schema(value_type = ProcessTrackerStatus, example = "process_started")]
+    pub previous_status: enums::ProcessTrackerStatus,
+
+    /// Business status of the task before the event
+    pub previous_business_status: String,
+
+    /// Retry count of the task before the event
+    pub retry_count: i32,
+
+    /// Time at which the task was scheduled to be executed after the event
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerListResponse {
+    /// Number of tasks returned
+    pub count: usize,
+
+    /// The list of tasks
+    pub data: Vec<ProcessTrackerResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PausedRunnerResponse {
+    #[schema(value_type = String, example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: enums::ProcessTrackerRunner,
+
+    /// Time at which the runner was paused
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub paused_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ResumedRunnerResponse {
+    #[schema(value_type = String, example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: enums::ProcessTrackerRunner,
+
+    /// Whether the runner was paused before it was resumed
+    pub was_paused: bool,
+
+    /// Number of overdue tasks of the runner which were rescheduled to be executed immediately
+    pub rescheduled_tasks_count: usize,
+}
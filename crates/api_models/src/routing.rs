@@ -10,7 +10,7 @@ use euclid::frontend::ast::Program;
 pub use euclid::{
     dssa::types::EuclidAnalysable,
     frontend::{
-        ast,
+        ast::{self, printer::EuclidPrintable},
         dir::{DirKeyKind, EuclidDirFilter},
     },
 };
@@ -28,6 +28,7 @@ const DEFAULT_BUCKET_SIZE: i32 = 200;
 const DEFAULT_HEDGING_PERCENT: f64 = 5.0;
 const DEFAULT_ELIMINATION_THRESHOLD: f64 = 0.35;
 const DEFAULT_PAYMENT_METHOD: &str = "CARD";
+const DEFAULT_ROLLBACK_MIN_SAMPLE_SIZE: u32 = 100;
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -84,6 +85,35 @@ pub struct RoutingRetrieveQuery {
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct RoutingActivatePayload {
     pub transaction_type: Option<TransactionType>,
+    /// Time at which the routing algorithm should be activated, the algorithm is activated
+    /// immediately if not provided
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub activate_at: Option<time::PrimitiveDateTime>,
+    /// Guard applied after activation to roll back to the previously active algorithm
+    pub rollback_config: Option<RoutingRollbackConfig>,
+}
+
+impl RoutingActivatePayload {
+    pub fn is_scheduled(&self) -> bool {
+        self.activate_at.is_some() || self.rollback_config.is_some()
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RoutingRollbackConfig {
+    /// Minimum authorization success rate (in percentage) to be maintained during the guard window
+    pub success_rate_threshold: f64,
+    /// Duration (in seconds) after activation during which the success rate is measured
+    pub guard_window_in_secs: u32,
+    /// Minimum number of payments required in the guard window to consider a rollback
+    pub min_sample_size: Option<u32>,
+}
+
+impl RoutingRollbackConfig {
+    pub fn get_min_sample_size(&self) -> u32 {
+        self.min_sample_size
+            .unwrap_or(DEFAULT_ROLLBACK_MIN_SAMPLE_SIZE)
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -185,6 +215,31 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
+impl EuclidPrintable for ConnectorSelection {
+    fn print_output(&self) -> String {
+        ast::ConnectorSelection::from(self.clone()).print_output()
+    }
+}
+
+impl From<ConnectorSelection> for ast::ConnectorSelection {
+    fn from(value: ConnectorSelection) -> Self {
+        match value {
+            ConnectorSelection::Priority(connectors) => {
+                Self::Priority(connectors.into_iter().map(Into::into).collect())
+            }
+            ConnectorSelection::VolumeSplit(splits) => Self::VolumeSplit(
+                splits
+                    .into_iter()
+                    .map(|split| ast::ConnectorVolumeSplit {
+                        connector: split.connector.into(),
+                        split: split.split,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
@@ -228,6 +283,19 @@ impl std::fmt::Display for RoutableConnectorChoice {
     }
 }
 
+impl From<RoutableConnectorChoice> for ast::RoutableConnectorChoice {
+    fn from(value: RoutableConnectorChoice) -> Self {
+        Self {
+            choice_kind: match value.choice_kind {
+                RoutableChoiceKind::OnlyConnector => ast::RoutableChoiceKind::OnlyConnector,
+                RoutableChoiceKind::FullStruct => ast::RoutableChoiceKind::FullStruct,
+            },
+            connector: value.connector,
+            merchant_connector_id: value.merchant_connector_id,
+        }
+    }
+}
+
 impl From<RoutableConnectorChoice> for ast::ConnectorChoice {
     fn from(value: RoutableConnectorChoice) -> Self {
         Self {
@@ -518,11 +586,14 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// The algorithm that was active before the current one, used for rolling back
+    #[serde(default)]
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 impl RoutingAlgorithmRef {
     pub fn update_algorithm_id(&mut self, new_id: common_utils::id_type::RoutingId) {
-        self.algorithm_id = Some(new_id);
+        self.previous_algorithm_id = self.algorithm_id.replace(new_id);
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
@@ -558,6 +629,54 @@ pub struct RoutingDictionaryRecord {
     pub modified_at: i64,
     pub algorithm_for: Option<TransactionType>,
     pub decision_engine_routing_id: Option<String>,
+    /// Findings of the static analysis performed on the routing algorithm while it was created,
+    /// such as rules that can never be selected
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<RoutingAnalysisWarning>>,
+}
+
+/// A non fatal finding of the static analysis performed on an advanced routing algorithm
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum RoutingAnalysisWarning {
+    /// The rule can never be selected as none of its conditions can be satisfied
+    UnreachableRule { rule_name: String },
+    /// The rule can never be selected as every payment it matches is already matched by the
+    /// rules preceding it
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    /// The connector selected by the rule is not valid for the merchant
+    InvalidConnectorSelection {
+        rule_name: String,
+        connector: RoutableConnectors,
+    },
+}
+
+impl From<euclid::dssa::types::AnalysisWarning> for RoutingAnalysisWarning {
+    fn from(value: euclid::dssa::types::AnalysisWarning) -> Self {
+        match value {
+            euclid::dssa::types::AnalysisWarning::UnreachableRule { rule_name } => {
+                Self::UnreachableRule { rule_name }
+            }
+            euclid::dssa::types::AnalysisWarning::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            } => Self::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            },
+            euclid::dssa::types::AnalysisWarning::InvalidConnectorSelection {
+                rule_name,
+                connector,
+                ..
+            } => Self::InvalidConnectorSelection {
+                rule_name,
+                connector,
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1444,3 +1563,71 @@ pub enum RoutingResultSource {
     /// Inbuilt Hyperswitch Routing Engine
     HyperswitchRouting,
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingSimulationRequest {
+    /// The profile whose historical payments are replayed
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    /// The candidate routing algorithm to evaluate
+    pub algorithm: StaticRoutingAlgorithm,
+    /// Time window of stored payments to replay the algorithm against
+    pub time_range: common_utils::types::TimeRange,
+    /// Maximum number of payments to replay, defaults to 100 and is capped at 1000
+    pub limit: Option<u32>,
+}
+
+impl RoutingSimulationRequest {
+    pub fn validated_limit(&self) -> u32 {
+        self.limit.unwrap_or(100).min(1000)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// Aggregate comparison of the simulated routing against the actual routing
+    pub summary: RoutingSimulationSummary,
+    /// Per payment outcome of the simulation
+    pub results: Vec<RoutingSimulationResult>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationSummary {
+    /// Number of payments replayed
+    pub total_payments: usize,
+    /// Number of payments for which the simulated connector differs from the actual connector
+    pub changed_count: usize,
+    /// Number of payments for which the simulated connector matches the actual connector
+    pub unchanged_count: usize,
+    /// Number of payments which could not be evaluated against the algorithm
+    pub failed_count: usize,
+    /// Per connector count of actual and simulated selections
+    pub connector_distribution: Vec<RoutingSimulationConnectorCount>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationConnectorCount {
+    /// Name of the connector
+    pub connector: String,
+    /// Number of replayed payments that were actually routed to this connector
+    pub actual_count: usize,
+    /// Number of replayed payments that the candidate algorithm routes to this connector
+    pub simulated_count: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResult {
+    /// The identifier of the replayed payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The identifier of the replayed payment attempt
+    pub attempt_id: String,
+    /// The connector the payment was actually routed to
+    pub actual_connector: Option<String>,
+    /// The connector the candidate algorithm would have chosen
+    pub simulated_connector: Option<RoutableConnectorChoice>,
+    /// Whether the simulated connector differs from the actual connector
+    pub is_changed: bool,
+    /// Reason for the payment not being evaluated, if evaluation failed
+    pub error: Option<String>,
+}
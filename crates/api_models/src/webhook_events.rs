@@ -89,6 +89,11 @@ pub struct EventListItemResponse {
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
     pub initial_attempt_id: String,
 
+    /// The identifier of the additional webhook endpoint of the profile the event was delivered
+    /// to. Not present for events delivered to the webhook URL of the profile.
+    #[schema(max_length = 64, example = "analytics")]
+    pub webhook_endpoint_id: Option<String>,
+
     /// Time at which the event was created.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601")]
@@ -226,3 +231,148 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The constraints to apply when listing events in the dead-letter queue, which holds the events
+/// whose automatic delivery retries were exhausted without a successful delivery.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeadLetterEventListConstraints {
+    /// Filter events moved to the dead-letter queue after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub dead_lettered_after: Option<PrimitiveDateTime>,
+
+    /// Filter events moved to the dead-letter queue before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub dead_lettered_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of events.
+    pub limit: Option<u16>,
+
+    /// Include events after the specified offset.
+    pub offset: Option<u16>,
+
+    /// Filter all events associated with the specified business profile ID.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// Filter events by their type.
+    pub event_types: Option<HashSet<EventType>>,
+
+    /// Filter events by the HTTP status code received for the last delivery attempt.
+    #[schema(example = 503)]
+    pub status_code: Option<u16>,
+}
+
+/// The response body for each item when listing events in the dead-letter queue.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterEventResponse {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+
+    /// Time at which the automatic delivery retries of the event were exhausted.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub dead_lettered_at: PrimitiveDateTime,
+
+    /// The HTTP status code received for the last delivery attempt. Not present if the merchant
+    /// server could not be reached.
+    #[schema(example = 503)]
+    pub status_code: Option<u16>,
+}
+
+/// The response body of list dead-letter events api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TotalDeadLetterEventsResponse {
+    /// The list of events
+    pub events: Vec<DeadLetterEventResponse>,
+    /// Count of total events
+    pub total_count: i64,
+}
+
+impl common_utils::events::ApiEventMetric for TotalDeadLetterEventsResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self
+                .events
+                .first()
+                .map(|event| event.event_information.merchant_id.clone())?,
+        })
+    }
+}
+
+/// The request body for replaying events in the dead-letter queue.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct DeadLetterEventReplayRequest {
+    /// The identifiers of the events in the dead-letter queue to be replayed.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Vec<String>,
+
+    /// The maximum number of events delivered to the merchant server per minute, so that a
+    /// recovering server is not flooded with webhooks. Defaults to 60.
+    #[schema(example = 60)]
+    pub max_events_per_minute: Option<u16>,
+}
+
+/// The status of the replay of an event in the dead-letter queue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadLetterEventReplayStatus {
+    /// The delivery of the event is scheduled.
+    Scheduled,
+    /// The delivery of the event was already scheduled by a previous replay.
+    AlreadyScheduled,
+    /// The event does not exist or is not in the dead-letter queue.
+    NotDeadLettered,
+}
+
+/// The result of the replay for each event in the request.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterEventReplayResult {
+    /// The identifier for the Event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The status of the replay of the event.
+    pub status: DeadLetterEventReplayStatus,
+
+    /// Time at which the delivery of the event is scheduled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+}
+
+/// The response body of replay dead-letter events api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeadLetterEventReplayResponse {
+    /// The result of the replay for each event in the request.
+    pub events: Vec<DeadLetterEventReplayResult>,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterEventReplayResponse {}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEventListRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub constraints: DeadLetterEventListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterEventListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DeadLetterEventReplayRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub request: DeadLetterEventReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for DeadLetterEventReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
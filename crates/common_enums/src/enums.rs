@@ -422,6 +422,11 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    BinRange,
+    IssuerCountry,
+    CardNetwork,
+    EmailDomain,
+    IpCidr,
 }
 
 /// Specifies how the payment is captured.
@@ -1472,6 +1477,7 @@ impl EventClass {
                 EventType::DisputeChallenged,
                 EventType::DisputeWon,
                 EventType::DisputeLost,
+                EventType::DisputeDeadlineApproaching,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
             #[cfg(feature = "payouts")]
@@ -1524,6 +1530,8 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    /// The deadline to respond to an open dispute is approaching
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
     #[cfg(feature = "payouts")]
@@ -7552,7 +7560,9 @@ pub enum ParentGroup {
     Theme,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize, ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum Resource {
     Payment,
@@ -7583,7 +7593,19 @@ pub enum Resource {
     Theme,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, Hash)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    serde::Serialize,
+    serde::Deserialize,
+    Hash,
+    ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum PermissionScope {
     Read = 0,
@@ -8472,6 +8494,34 @@ pub enum ProcessTrackerStatus {
     Review,
 }
 
+/// Kind of change recorded in the history of a process tracker task
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProcessTrackerEventType {
+    /// Task was retried by the consumer after a failure
+    Retried,
+    /// Task was reset by the consumer to start over
+    Reset,
+    /// Task was retried immediately by an operator
+    ForceRetried,
+    /// Task was rescheduled by an operator
+    Rescheduled,
+    /// Task was cancelled by an operator
+    Cancelled,
+}
+
 #[derive(
     serde::Serialize,
     serde::Deserialize,
@@ -8480,6 +8530,7 @@ pub enum ProcessTrackerStatus {
     Debug,
     PartialEq,
     Eq,
+    Hash,
     strum::EnumString,
     strum::Display,
 )]
@@ -8494,6 +8545,10 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    RoutingActivationWorkflow,
+    ForexRefreshWorkflow,
+    DisputeAutoResponseWorkflow,
+    DisputeDeadlineReminderWorkflow,
 }
 
 #[derive(Debug)]
@@ -8601,3 +8656,103 @@ impl RoutingApproach {
 pub enum CallbackMapperIdType {
     NetworkTokenRequestorReferenceID,
 }
+
+/// The attribute of a payment that the attempts are grouped by in a velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleKey {
+    CardFingerprint,
+    IpAddress,
+    Email,
+    CustomerId,
+    CardBin,
+    /// Fingerprint of the device derived from the browser information of the payment
+    DeviceId,
+}
+
+/// The measure of the attempts in the window that is compared against the threshold of a
+/// velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityAggregation {
+    /// Number of payment attempts
+    Count,
+    /// Sum of the amounts of the payment attempts, in the minor unit of the payment currency
+    SumOfAmount,
+}
+
+/// The action taken on a payment that exceeds the threshold of a velocity rule
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityRuleAction {
+    /// Reject the payment
+    Block,
+    /// Authenticate the payment using 3DS
+    ForceThreeDs,
+    /// Let the payment through, recording that it requires a review
+    FlagForReview,
+}
+
+/// The way the retry engine changed a failed payment attempt to retry it
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentRetryStrategy {
+    /// Retried on the same connector with 3DS
+    StepUp,
+    /// Retried on the same connector with the raw card number instead of the network token
+    ClearPan,
+    /// Retried on the same connector through another network of the co-badged card
+    AlternateNetwork,
+    /// Retried on the next connector of the routing result
+    NextConnector,
+}
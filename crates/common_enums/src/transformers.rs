@@ -5,8 +5,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "payouts")]
 use crate::enums::PayoutStatus;
 use crate::enums::{
-    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, DisputeStatus, EventType, IntentStatus,
-    MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
+    AttemptStatus, Country, CountryAlpha2, CountryAlpha3, Currency, DisputeStatus, EventType,
+    IntentStatus, MandateStatus, PaymentMethod, PaymentMethodType, RefundStatus,
 };
 
 impl Display for NumericCountryCodeParseError {
@@ -269,6 +269,238 @@ impl CountryAlpha2 {
             Self::ZW => CountryAlpha3::ZWE,
         }
     }
+
+    /// The currency in local use in the country, if it is one of the supported currencies
+    pub const fn get_local_currency(self) -> Option<Currency> {
+        match self {
+            Self::AE => Some(Currency::AED),
+            Self::AF => Some(Currency::AFN),
+            Self::AL => Some(Currency::ALL),
+            Self::AM => Some(Currency::AMD),
+            Self::CW | Self::SX => Some(Currency::ANG),
+            Self::AO => Some(Currency::AOA),
+            Self::AR => Some(Currency::ARS),
+            Self::AU
+            | Self::CX
+            | Self::CC
+            | Self::HM
+            | Self::KI
+            | Self::NR
+            | Self::NF
+            | Self::TV => Some(Currency::AUD),
+            Self::AW => Some(Currency::AWG),
+            Self::AZ => Some(Currency::AZN),
+            Self::BA => Some(Currency::BAM),
+            Self::BB => Some(Currency::BBD),
+            Self::BD => Some(Currency::BDT),
+            Self::BG => Some(Currency::BGN),
+            Self::BH => Some(Currency::BHD),
+            Self::BI => Some(Currency::BIF),
+            Self::BM => Some(Currency::BMD),
+            Self::BN => Some(Currency::BND),
+            Self::BO => Some(Currency::BOB),
+            Self::BR => Some(Currency::BRL),
+            Self::BS => Some(Currency::BSD),
+            Self::BT => Some(Currency::BTN),
+            Self::BW => Some(Currency::BWP),
+            Self::BY => Some(Currency::BYN),
+            Self::BZ => Some(Currency::BZD),
+            Self::CA => Some(Currency::CAD),
+            Self::CD => Some(Currency::CDF),
+            Self::LI | Self::CH => Some(Currency::CHF),
+            Self::CL => Some(Currency::CLP),
+            Self::CN => Some(Currency::CNY),
+            Self::CO => Some(Currency::COP),
+            Self::CR => Some(Currency::CRC),
+            Self::CU => Some(Currency::CUP),
+            Self::CV => Some(Currency::CVE),
+            Self::CZ => Some(Currency::CZK),
+            Self::DJ => Some(Currency::DJF),
+            Self::DK | Self::FO | Self::GL => Some(Currency::DKK),
+            Self::DO => Some(Currency::DOP),
+            Self::DZ => Some(Currency::DZD),
+            Self::EG => Some(Currency::EGP),
+            Self::ER => Some(Currency::ERN),
+            Self::ET => Some(Currency::ETB),
+            Self::AX
+            | Self::AD
+            | Self::AT
+            | Self::BE
+            | Self::HR
+            | Self::CY
+            | Self::EE
+            | Self::FI
+            | Self::FR
+            | Self::GF
+            | Self::TF
+            | Self::DE
+            | Self::GR
+            | Self::GP
+            | Self::VA
+            | Self::IE
+            | Self::IT
+            | Self::LV
+            | Self::LT
+            | Self::LU
+            | Self::MT
+            | Self::MQ
+            | Self::YT
+            | Self::MC
+            | Self::ME
+            | Self::NL
+            | Self::PT
+            | Self::RE
+            | Self::BL
+            | Self::MF
+            | Self::PM
+            | Self::SM
+            | Self::SK
+            | Self::SI
+            | Self::ES => Some(Currency::EUR),
+            Self::FJ => Some(Currency::FJD),
+            Self::FK => Some(Currency::FKP),
+            Self::GG | Self::IM | Self::JE | Self::GS | Self::GB => Some(Currency::GBP),
+            Self::GE => Some(Currency::GEL),
+            Self::GH => Some(Currency::GHS),
+            Self::GI => Some(Currency::GIP),
+            Self::GM => Some(Currency::GMD),
+            Self::GN => Some(Currency::GNF),
+            Self::GT => Some(Currency::GTQ),
+            Self::GY => Some(Currency::GYD),
+            Self::HK => Some(Currency::HKD),
+            Self::HN => Some(Currency::HNL),
+            Self::HT => Some(Currency::HTG),
+            Self::HU => Some(Currency::HUF),
+            Self::ID => Some(Currency::IDR),
+            Self::IL | Self::PS => Some(Currency::ILS),
+            Self::IN => Some(Currency::INR),
+            Self::IQ => Some(Currency::IQD),
+            Self::IR => Some(Currency::IRR),
+            Self::IS => Some(Currency::ISK),
+            Self::JM => Some(Currency::JMD),
+            Self::JO => Some(Currency::JOD),
+            Self::JP => Some(Currency::JPY),
+            Self::KE => Some(Currency::KES),
+            Self::KG => Some(Currency::KGS),
+            Self::KH => Some(Currency::KHR),
+            Self::KM => Some(Currency::KMF),
+            Self::KP => Some(Currency::KPW),
+            Self::KR => Some(Currency::KRW),
+            Self::KW => Some(Currency::KWD),
+            Self::KY => Some(Currency::KYD),
+            Self::KZ => Some(Currency::KZT),
+            Self::LA => Some(Currency::LAK),
+            Self::LB => Some(Currency::LBP),
+            Self::LK => Some(Currency::LKR),
+            Self::LR => Some(Currency::LRD),
+            Self::LS => Some(Currency::LSL),
+            Self::LY => Some(Currency::LYD),
+            Self::MA | Self::EH => Some(Currency::MAD),
+            Self::MD => Some(Currency::MDL),
+            Self::MG => Some(Currency::MGA),
+            Self::MK => Some(Currency::MKD),
+            Self::MM => Some(Currency::MMK),
+            Self::MN => Some(Currency::MNT),
+            Self::MO => Some(Currency::MOP),
+            Self::MR => Some(Currency::MRU),
+            Self::MU => Some(Currency::MUR),
+            Self::MV => Some(Currency::MVR),
+            Self::MW => Some(Currency::MWK),
+            Self::MX => Some(Currency::MXN),
+            Self::MY => Some(Currency::MYR),
+            Self::MZ => Some(Currency::MZN),
+            Self::NA => Some(Currency::NAD),
+            Self::NG => Some(Currency::NGN),
+            Self::NI => Some(Currency::NIO),
+            Self::BV | Self::NO | Self::SJ => Some(Currency::NOK),
+            Self::NP => Some(Currency::NPR),
+            Self::CK | Self::NZ | Self::NU | Self::PN | Self::TK => Some(Currency::NZD),
+            Self::OM => Some(Currency::OMR),
+            Self::PA => Some(Currency::PAB),
+            Self::PE => Some(Currency::PEN),
+            Self::PG => Some(Currency::PGK),
+            Self::PH => Some(Currency::PHP),
+            Self::PK => Some(Currency::PKR),
+            Self::PL => Some(Currency::PLN),
+            Self::PY => Some(Currency::PYG),
+            Self::QA => Some(Currency::QAR),
+            Self::RO => Some(Currency::RON),
+            Self::RS => Some(Currency::RSD),
+            Self::RU => Some(Currency::RUB),
+            Self::RW => Some(Currency::RWF),
+            Self::SA => Some(Currency::SAR),
+            Self::SB => Some(Currency::SBD),
+            Self::SC => Some(Currency::SCR),
+            Self::SD => Some(Currency::SDG),
+            Self::SE => Some(Currency::SEK),
+            Self::SG => Some(Currency::SGD),
+            Self::SH => Some(Currency::SHP),
+            Self::SL => Some(Currency::SLE),
+            Self::SO => Some(Currency::SOS),
+            Self::SR => Some(Currency::SRD),
+            Self::SS => Some(Currency::SSP),
+            Self::ST => Some(Currency::STN),
+            Self::SY => Some(Currency::SYP),
+            Self::SZ => Some(Currency::SZL),
+            Self::TH => Some(Currency::THB),
+            Self::TJ => Some(Currency::TJS),
+            Self::TM => Some(Currency::TMT),
+            Self::TN => Some(Currency::TND),
+            Self::TO => Some(Currency::TOP),
+            Self::TR => Some(Currency::TRY),
+            Self::TT => Some(Currency::TTD),
+            Self::TW => Some(Currency::TWD),
+            Self::TZ => Some(Currency::TZS),
+            Self::UA => Some(Currency::UAH),
+            Self::UG => Some(Currency::UGX),
+            Self::AS
+            | Self::BQ
+            | Self::IO
+            | Self::EC
+            | Self::SV
+            | Self::GU
+            | Self::MH
+            | Self::FM
+            | Self::MP
+            | Self::PW
+            | Self::PR
+            | Self::TL
+            | Self::TC
+            | Self::UM
+            | Self::VG
+            | Self::VI
+            | Self::US => Some(Currency::USD),
+            Self::UY => Some(Currency::UYU),
+            Self::UZ => Some(Currency::UZS),
+            Self::VE => Some(Currency::VES),
+            Self::VN => Some(Currency::VND),
+            Self::VU => Some(Currency::VUV),
+            Self::WS => Some(Currency::WST),
+            Self::CM | Self::CF | Self::TD | Self::CG | Self::GQ | Self::GA => Some(Currency::XAF),
+            Self::AI
+            | Self::AG
+            | Self::DM
+            | Self::GD
+            | Self::MS
+            | Self::KN
+            | Self::LC
+            | Self::VC => Some(Currency::XCD),
+            Self::BJ
+            | Self::BF
+            | Self::CI
+            | Self::GW
+            | Self::ML
+            | Self::NE
+            | Self::SN
+            | Self::TG => Some(Currency::XOF),
+            Self::PF | Self::NC | Self::WF => Some(Currency::XPF),
+            Self::YE => Some(Currency::YER),
+            Self::ZA => Some(Currency::ZAR),
+            Self::ZM => Some(Currency::ZMW),
+            Self::ZW => Some(Currency::ZWL),
+            Self::AQ => None,
+        }
+    }
 }
 impl Country {
     pub const fn from_alpha2(code: CountryAlpha2) -> Self {
@@ -0,0 +1,156 @@
+//! Types for API keys
+
+use std::net::IpAddr;
+
+use common_enums::{PermissionScope, Resource};
+use common_utils::{id_type, impl_to_sql_from_sql_json};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Restrictions on the access granted by an API key. API keys without restrictions have full
+/// access to the merchant account.
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyRestrictions {
+    /// The permissions granted to the API key
+    pub permissions: Vec<ApiKeyPermission>,
+    /// The profiles that can be accessed using the API key. All the profiles of the merchant can
+    /// be accessed when not provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pro_abcdefghijklmnopqrst"]))]
+    pub profile_ids: Option<Vec<id_type::ProfileId>>,
+    /// The IP addresses from which the API key can be used. The API key can be used from any IP
+    /// address when not provided.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["203.0.113.10"]))]
+    pub allowed_ips: Option<Vec<IpAddr>>,
+}
+
+impl_to_sql_from_sql_json!(ApiKeyRestrictions);
+
+impl ApiKeyRestrictions {
+    /// Checks whether the API key is permitted to access the resource with the scope. Write
+    /// access to a resource also grants read access to it.
+    pub fn is_permitted(&self, resource: Resource, scope: PermissionScope) -> bool {
+        self.permissions
+            .iter()
+            .any(|permission| permission.resource == resource && permission.scope >= scope)
+    }
+
+    /// Checks whether the profile can be accessed using the API key. API keys restricted to
+    /// profiles cannot be used without specifying the profile being accessed.
+    pub fn is_profile_allowed(&self, profile_id: Option<&id_type::ProfileId>) -> bool {
+        match (self.profile_ids.as_ref(), profile_id) {
+            (None, _) => true,
+            (Some(profile_ids), Some(profile_id)) => profile_ids.contains(profile_id),
+            (Some(_), None) => false,
+        }
+    }
+
+    /// Checks whether the API key can be used from the IP address
+    pub fn is_ip_allowed(&self, ip_address: Option<IpAddr>) -> bool {
+        match (self.allowed_ips.as_ref(), ip_address) {
+            (None, _) => true,
+            (Some(allowed_ips), Some(ip_address)) => allowed_ips.contains(&ip_address),
+            (Some(_), None) => false,
+        }
+    }
+}
+
+/// A permission granted to a restricted API key
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyPermission {
+    /// The resource that can be accessed
+    #[schema(value_type = Resource, example = "refund")]
+    pub resource: Resource,
+    /// The access granted to the resource
+    #[schema(value_type = PermissionScope, example = "read")]
+    pub scope: PermissionScope,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn profile_id(id: &'static str) -> id_type::ProfileId {
+        id_type::ProfileId::try_from(Cow::from(id)).unwrap()
+    }
+
+    fn restrictions() -> ApiKeyRestrictions {
+        ApiKeyRestrictions {
+            permissions: vec![
+                ApiKeyPermission {
+                    resource: Resource::Payment,
+                    scope: PermissionScope::Write,
+                },
+                ApiKeyPermission {
+                    resource: Resource::Refund,
+                    scope: PermissionScope::Read,
+                },
+            ],
+            profile_ids: None,
+            allowed_ips: None,
+        }
+    }
+
+    #[test]
+    fn test_is_permitted() {
+        let restrictions = restrictions();
+        assert!(restrictions.is_permitted(Resource::Payment, PermissionScope::Write));
+        // Write access grants read access
+        assert!(restrictions.is_permitted(Resource::Payment, PermissionScope::Read));
+        assert!(restrictions.is_permitted(Resource::Refund, PermissionScope::Read));
+        assert!(!restrictions.is_permitted(Resource::Refund, PermissionScope::Write));
+        assert!(!restrictions.is_permitted(Resource::Customer, PermissionScope::Read));
+
+        let restrictions = ApiKeyRestrictions {
+            permissions: vec![],
+            ..restrictions
+        };
+        assert!(!restrictions.is_permitted(Resource::Payment, PermissionScope::Read));
+    }
+
+    #[test]
+    fn test_is_profile_allowed() {
+        let unrestricted = restrictions();
+        assert!(unrestricted.is_profile_allowed(None));
+        assert!(unrestricted.is_profile_allowed(Some(&profile_id("pro_1"))));
+
+        let restricted = ApiKeyRestrictions {
+            profile_ids: Some(vec![profile_id("pro_1"), profile_id("pro_2")]),
+            ..restrictions()
+        };
+        assert!(restricted.is_profile_allowed(Some(&profile_id("pro_1"))));
+        assert!(restricted.is_profile_allowed(Some(&profile_id("pro_2"))));
+        assert!(!restricted.is_profile_allowed(Some(&profile_id("pro_3"))));
+        // The profile has to be specified when the API key is restricted to profiles
+        assert!(!restricted.is_profile_allowed(None));
+    }
+
+    #[test]
+    fn test_is_ip_allowed() {
+        let allowed_ip: IpAddr = "203.0.113.10".parse().unwrap();
+        let other_ip: IpAddr = "198.51.100.7".parse().unwrap();
+        let allowed_ipv6: IpAddr = "2001:db8::1".parse().unwrap();
+
+        let unrestricted = restrictions();
+        assert!(unrestricted.is_ip_allowed(None));
+        assert!(unrestricted.is_ip_allowed(Some(other_ip)));
+
+        let restricted = ApiKeyRestrictions {
+            allowed_ips: Some(vec![allowed_ip, allowed_ipv6]),
+            ..restrictions()
+        };
+        assert!(restricted.is_ip_allowed(Some(allowed_ip)));
+        assert!(restricted.is_ip_allowed(Some(allowed_ipv6)));
+        assert!(!restricted.is_ip_allowed(Some(other_ip)));
+        // Requests whose IP address cannot be determined are rejected
+        assert!(!restricted.is_ip_allowed(None));
+    }
+}
@@ -297,6 +297,46 @@ pub struct StripeChargeResponseData {
 }
 impl_to_sql_from_sql_json!(StripeChargeResponseData);
 
+/// Decision of the retry engine that created the payment attempt
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct PaymentRetryDetails {
+    /// How the previous attempt was changed to retry it
+    #[schema(value_type = PaymentRetryStrategy)]
+    pub strategy: enums::PaymentRetryStrategy,
+    /// The failed attempt which was retried
+    pub previous_attempt_id: String,
+    /// Connector of the failed attempt
+    pub previous_connector: Option<String>,
+    /// Card network the failed attempt was routed through
+    #[schema(value_type = Option<CardNetwork>)]
+    pub previous_card_network: Option<enums::CardNetwork>,
+    /// Card network the attempt is routed through
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<enums::CardNetwork>,
+}
+impl_to_sql_from_sql_json!(PaymentRetryDetails);
+
+/// Velocity rules of the card testing guard exceeded by the payment attempt
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct TriggeredVelocityRules(pub Vec<TriggeredVelocityRule>);
+impl_to_sql_from_sql_json!(TriggeredVelocityRules);
+
+/// Velocity rule of the card testing guard exceeded by a payment attempt
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct TriggeredVelocityRule {
+    /// Name of the velocity rule
+    pub name: String,
+    /// Action taken on the payment attempt
+    #[schema(value_type = VelocityRuleAction)]
+    pub action: enums::VelocityRuleAction,
+}
+
 /// Charge Information
 #[derive(
     Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
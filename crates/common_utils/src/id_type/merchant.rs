@@ -127,6 +127,11 @@ impl MerchantId {
         format!("fingerprint_secret_{}", self.get_string_repr())
     }
 
+    /// get_dcc_config_key
+    pub fn get_dcc_config_key(&self) -> String {
+        format!("dcc_config_{}", self.get_string_repr())
+    }
+
     /// get_surcharge_dsk_key
     pub fn get_surcharge_dsk_key(&self) -> String {
         format!("surcharge_dsl_{}", self.get_string_repr())
@@ -173,6 +178,11 @@ impl MerchantId {
         format!("payment_method_surcharge_id_{}", self.get_string_repr())
     }
 
+    /// get_dispute_auto_response_config_key
+    pub fn get_dispute_auto_response_config_key(&self) -> String {
+        format!("dispute_auto_response_config_{}", self.get_string_repr())
+    }
+
     /// get_webhook_config_disabled_events_key
     pub fn get_webhook_config_disabled_events_key(&self, connector_id: &str) -> String {
         format!(
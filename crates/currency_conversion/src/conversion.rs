@@ -4,7 +4,7 @@ use rusty_money::Money;
 
 use crate::{
     error::CurrencyConversionError,
-    types::{currency_match, ExchangeRates},
+    types::{currency_match, ExchangeRates, HistoricalExchangeRates},
 };
 
 pub fn convert(
@@ -26,6 +26,18 @@ pub fn convert(
     }
 }
 
+/// Converts the amount using the exchange rates that were in effect at `as_of`
+pub fn convert_as_of(
+    historical_rates: &HistoricalExchangeRates,
+    as_of: time::PrimitiveDateTime,
+    from_currency: Currency,
+    to_currency: Currency,
+    amount: i64,
+) -> Result<Decimal, CurrencyConversionError> {
+    let ex_rates = historical_rates.rates_as_of(as_of)?;
+    convert(ex_rates, from_currency, to_currency, amount)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used, clippy::print_stdout)]
@@ -89,4 +101,55 @@ mod tests {
             convert(&sample_rate, convert_from, convert_to, amount).expect("converted_currency");
         println!("The conversion from {amount} {convert_from} to {convert_to} is {res:?}");
     }
+
+    #[test]
+    fn historical_conversion_uses_rates_as_of_date() {
+        use super::*;
+        let date =
+            |month, day| time::Date::from_calendar_date(2024, month, day).expect("valid date");
+        let rates_with_inr_factors = |to_factor, from_factor| {
+            let mut conversion: HashMap<Currency, CurrencyFactors> = HashMap::new();
+            conversion.insert(
+                Currency::USD,
+                CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0)),
+            );
+            conversion.insert(Currency::INR, CurrencyFactors::new(to_factor, from_factor));
+            ExchangeRates::new(Currency::USD, conversion)
+        };
+        let historical_rates = HistoricalExchangeRates::new([
+            (
+                date(time::Month::January, 1),
+                rates_with_inr_factors(Decimal::new(80, 0), Decimal::new(125, 4)),
+            ),
+            (
+                date(time::Month::March, 1),
+                rates_with_inr_factors(Decimal::new(85, 0), Decimal::new(118, 4)),
+            ),
+        ]);
+        let convert_on = |as_of: time::Date| {
+            convert_as_of(
+                &historical_rates,
+                as_of.with_time(time::Time::MIDNIGHT),
+                Currency::USD,
+                Currency::INR,
+                100,
+            )
+        };
+
+        assert_eq!(
+            convert_on(date(time::Month::February, 15)).expect("converted_currency"),
+            Decimal::new(80, 0)
+        );
+        assert_eq!(
+            convert_on(date(time::Month::March, 1)).expect("converted_currency"),
+            Decimal::new(85, 0)
+        );
+        assert!(matches!(
+            convert_on(
+                time::Date::from_calendar_date(2023, time::Month::December, 31)
+                    .expect("valid date")
+            ),
+            Err(CurrencyConversionError::RatesNotAvailable(_))
+        ));
+    }
 }
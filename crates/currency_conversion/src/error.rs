@@ -5,4 +5,6 @@ pub enum CurrencyConversionError {
     DecimalMultiplicationFailed,
     #[error("Currency not supported: '{0}'")]
     ConversionNotSupported(String),
+    #[error("Exchange rates not available as of '{0}'")]
+    RatesNotAvailable(String),
 }
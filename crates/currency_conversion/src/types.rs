@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use common_enums::Currency;
 use rust_decimal::Decimal;
@@ -75,6 +75,38 @@ impl ExchangeRates {
     }
 }
 
+/// Daily snapshots of exchange rates, used to convert amounts with the rates that were in effect
+/// at a point in time
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct HistoricalExchangeRates {
+    snapshots: BTreeMap<time::Date, ExchangeRates>,
+}
+
+impl HistoricalExchangeRates {
+    pub fn new(snapshots: impl IntoIterator<Item = (time::Date, ExchangeRates)>) -> Self {
+        Self {
+            snapshots: snapshots.into_iter().collect(),
+        }
+    }
+
+    /// Adds the snapshot for the given date, replacing any existing snapshot for that date
+    pub fn insert(&mut self, date: time::Date, rates: ExchangeRates) {
+        self.snapshots.insert(date, rates);
+    }
+
+    /// The rates of the latest snapshot taken on or before the date of `as_of`
+    pub fn rates_as_of(
+        &self,
+        as_of: time::PrimitiveDateTime,
+    ) -> Result<&ExchangeRates, CurrencyConversionError> {
+        self.snapshots
+            .range(..=as_of.date())
+            .next_back()
+            .map(|(_, rates)| rates)
+            .ok_or_else(|| CurrencyConversionError::RatesNotAvailable(as_of.to_string()))
+    }
+}
+
 pub fn currency_match(currency: Currency) -> &'static iso::Currency {
     match currency {
         Currency::AED => iso::AED,
@@ -1,3 +1,4 @@
+use common_types::api_keys::ApiKeyRestrictions;
 use diesel::{AsChangeset, AsExpression, Identifiable, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -18,6 +19,7 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub restrictions: Option<ApiKeyRestrictions>,
 }
 
 #[derive(Debug, Insertable)]
@@ -32,6 +34,7 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub restrictions: Option<ApiKeyRestrictions>,
 }
 
 #[derive(Debug)]
@@ -41,6 +44,7 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        restrictions: Option<ApiKeyRestrictions>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
@@ -54,6 +58,7 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub restrictions: Option<ApiKeyRestrictions>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -64,17 +69,20 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                restrictions,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                restrictions,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                restrictions: None,
             },
         }
     }
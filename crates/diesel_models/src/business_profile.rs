@@ -724,10 +724,22 @@ pub struct CardTestingGuardConfig {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_threshold: i32,
     pub card_testing_guard_expiry: i32,
+    #[serde(default)]
+    pub velocity_rules: Vec<VelocityRule>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(CardTestingGuardConfig);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct VelocityRule {
+    pub name: String,
+    pub keys: Vec<common_enums::VelocityRuleKey>,
+    pub aggregation: common_enums::VelocityAggregation,
+    pub threshold: i64,
+    pub window_in_secs: u32,
+    pub action: common_enums::VelocityRuleAction,
+}
+
 impl Default for CardTestingGuardConfig {
     fn default() -> Self {
         Self {
@@ -743,6 +755,7 @@ impl Default for CardTestingGuardConfig {
                 common_utils::consts::DEFAULT_CUSTOMER_ID_BLOCKING_THRESHOLD,
             card_testing_guard_expiry:
                 common_utils::consts::DEFAULT_CARD_TESTING_GUARD_EXPIRY_IN_SECS,
+            velocity_rules: Vec::new(),
         }
     }
 }
@@ -760,10 +773,38 @@ pub struct WebhookDetails {
     pub payment_statuses_enabled: Option<Vec<common_enums::IntentStatus>>,
     pub refund_statuses_enabled: Option<Vec<common_enums::RefundStatus>>,
     pub payout_statuses_enabled: Option<Vec<common_enums::PayoutStatus>>,
+    pub webhook_endpoints: Option<Vec<WebhookEndpoint>>,
+    pub signing_secret_rotation_grace_period_in_secs: Option<u32>,
+    /// Payment response hash key replaced last, used to sign webhooks until the rotation expires
+    pub payment_response_hash_key_rotation: Option<WebhookSigningSecretRotation>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub url: Secret<String>,
+    pub is_enabled: Option<bool>,
+    pub enabled_events: Option<Vec<common_enums::EventType>>,
+    pub signing_secret: Option<Secret<String>>,
+    /// Signing secret replaced last, used to sign webhooks until the rotation expires
+    pub signing_secret_rotation: Option<WebhookSigningSecretRotation>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSigningSecretRotation {
+    pub previous_signing_secret: Secret<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+impl WebhookSigningSecretRotation {
+    pub fn is_active(&self, now: time::PrimitiveDateTime) -> bool {
+        self.expires_at > now
+    }
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct BusinessPaymentLinkConfig {
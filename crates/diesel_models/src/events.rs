@@ -27,6 +27,9 @@ pub struct EventNew {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub dead_letter_status_code: Option<i32>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -35,6 +38,8 @@ pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub dead_letter_status_code: Option<i32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -60,6 +65,9 @@ pub struct Event {
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
     pub is_overall_delivery_successful: Option<bool>,
+    pub webhook_endpoint_id: Option<String>,
+    pub dead_lettered_at: Option<PrimitiveDateTime>,
+    pub dead_letter_status_code: Option<i32>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -0,0 +1,46 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::exchange_rate_snapshot;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = exchange_rate_snapshot)]
+pub struct ExchangeRateSnapshotNew {
+    pub rate_date: time::Date,
+    pub base_currency: common_enums::Currency,
+    pub rates: serde_json::Value,
+    pub provider: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = exchange_rate_snapshot, primary_key(rate_date), check_for_backend(diesel::pg::Pg))]
+pub struct ExchangeRateSnapshot {
+    pub rate_date: time::Date,
+    pub base_currency: common_enums::Currency,
+    pub rates: serde_json::Value,
+    pub provider: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = exchange_rate_snapshot)]
+pub struct ExchangeRateSnapshotUpdate {
+    pub base_currency: common_enums::Currency,
+    pub rates: serde_json::Value,
+    pub provider: String,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<ExchangeRateSnapshotNew> for ExchangeRateSnapshotUpdate {
+    fn from(snapshot: ExchangeRateSnapshotNew) -> Self {
+        Self {
+            base_currency: snapshot.base_currency,
+            rates: snapshot.rates,
+            provider: snapshot.provider,
+            last_modified_at: snapshot.last_modified_at,
+        }
+    }
+}
@@ -42,6 +42,10 @@ pub struct GatewayStatusMap {
     pub unified_message: Option<String>,
     pub error_category: Option<ErrorCategory>,
     pub clear_pan_possible: bool,
+    /// Number of times the rule was matched by a connector error
+    pub hit_count: i64,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub last_hit_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable)]
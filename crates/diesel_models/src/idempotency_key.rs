@@ -0,0 +1,27 @@
+use common_utils::encryption::Encryption;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::idempotency_key;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = idempotency_key)]
+pub struct IdempotencyKeyNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub response: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = idempotency_key, primary_key(merchant_id, idempotency_key), check_for_backend(diesel::pg::Pg))]
+pub struct IdempotencyKey {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub idempotency_key: String,
+    pub request_fingerprint: String,
+    pub response: Encryption,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+}
@@ -1,4 +1,4 @@
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "v2")]
@@ -15,6 +15,7 @@ use crate::{
     payment_intent::PaymentIntentNew,
     payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
+    query::generics,
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     Mandate, MandateNew, MandateUpdateInternal, PaymentIntent, PaymentMethod, PaymentMethodNew,
@@ -24,8 +25,20 @@ use crate::{
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "db_op", content = "data")]
 pub enum DBOperation {
-    Insert { insertable: Box<Insertable> },
-    Update { updatable: Box<Updateable> },
+    Insert {
+        insertable: Box<Insertable>,
+    },
+    Update {
+        updatable: Box<Updateable>,
+    },
+    Delete {
+        deletable: Box<Deletable>,
+        /// The redis key of the deleted record, the reverse lookups of which are deleted along
+        /// with the record
+        pk_id: String,
+        /// The hash field of the deleted record
+        sk_id: String,
+    },
 }
 
 impl DBOperation {
@@ -33,22 +46,12 @@ impl DBOperation {
         match self {
             Self::Insert { .. } => "insert",
             Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
         }
     }
     pub fn table<'a>(&self) -> &'a str {
         match self {
-            Self::Insert { insertable } => match **insertable {
-                Insertable::PaymentIntent(_) => "payment_intent",
-                Insertable::PaymentAttempt(_) => "payment_attempt",
-                Insertable::Refund(_) => "refund",
-                Insertable::Address(_) => "address",
-                Insertable::Payouts(_) => "payouts",
-                Insertable::PayoutAttempt(_) => "payout_attempt",
-                Insertable::Customer(_) => "customer",
-                Insertable::ReverseLookUp(_) => "reverse_lookup",
-                Insertable::PaymentMethod(_) => "payment_method",
-                Insertable::Mandate(_) => "mandate",
-            },
+            Self::Insert { insertable } => insertable.table(),
             Self::Update { updatable } => match **updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
                 Updateable::PaymentAttemptUpdate(_) => "payment_attempt",
@@ -60,6 +63,7 @@ impl DBOperation {
                 Updateable::PaymentMethodUpdate(_) => "payment_method",
                 Updateable::MandateUpdate(_) => " mandate",
             },
+            Self::Delete { deletable, .. } => deletable.table(),
         }
     }
 }
@@ -76,6 +80,7 @@ pub enum DBResult {
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
     Mandate(Box<Mandate>),
+    Deleted(bool),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -179,6 +184,25 @@ impl DBOperation {
                     Customer::update_by_id(conn, cust.orig.id, cust.update_data).await?,
                 )),
             },
+            Self::Delete {
+                deletable,
+                pk_id,
+                sk_id,
+            } => {
+                let deleted = deletable.delete(conn).await?;
+
+                // The reverse lookups are deleted only after the record, so that lookups do not
+                // fall back to the database record until it is deleted
+                if let Err(error) = ReverseLookup::delete_by_pk_id_sk_id(conn, &pk_id, &sk_id).await
+                {
+                    // Records which are found by their redis key have no reverse lookups
+                    if !matches!(error.current_context(), errors::DatabaseError::NotFound) {
+                        return Err(error);
+                    }
+                }
+
+                DBResult::Deleted(deleted)
+            }
         })
     }
 }
@@ -219,6 +243,79 @@ pub enum Insertable {
     Mandate(MandateNew),
 }
 
+impl Insertable {
+    pub fn table(&self) -> &'static str {
+        match self {
+            Self::PaymentIntent(_) => "payment_intent",
+            Self::PaymentAttempt(_) => "payment_attempt",
+            Self::Refund(_) => "refund",
+            Self::Address(_) => "address",
+            Self::Payouts(_) => "payouts",
+            Self::PayoutAttempt(_) => "payout_attempt",
+            Self::Customer(_) => "customer",
+            Self::ReverseLookUp(_) => "reverse_lookup",
+            Self::PaymentMethod(_) => "payment_method",
+            Self::Mandate(_) => "mandate",
+        }
+    }
+
+    /// Inserts the records using a single multi-row insert. All the records must belong to the
+    /// same table.
+    pub async fn batch_insert(
+        insertables: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> crate::StorageResult<()> {
+        macro_rules! insert_rows {
+            ($variant:ident, $row:ty, $into_new:expr) => {{
+                let values = insertables
+                    .into_iter()
+                    .map(|insertable| match insertable {
+                        Self::$variant(value) => Ok($into_new(value)),
+                        other => Err(report!(errors::DatabaseError::QueryGenerationFailed))
+                            .attach_printable_lazy(|| {
+                                format!(
+                                    "Cannot insert a `{}` record in a batch of `{}` records",
+                                    other.table(),
+                                    stringify!($variant)
+                                )
+                            }),
+                    })
+                    .collect::<crate::StorageResult<Vec<_>>>()?;
+                generics::generic_insert::<_, _, $row>(conn, values).await?;
+            }};
+        }
+
+        let Some(first) = insertables.first() else {
+            return Ok(());
+        };
+
+        match first {
+            Self::PaymentIntent(_) => {
+                insert_rows!(PaymentIntent, PaymentIntent, |value: Box<_>| *value)
+            }
+            Self::PaymentAttempt(_) => {
+                insert_rows!(PaymentAttempt, PaymentAttempt, |value: Box<_>| *value)
+            }
+            Self::Refund(_) => insert_rows!(Refund, Refund, std::convert::identity),
+            Self::Address(_) => insert_rows!(Address, Address, |value: Box<_>| *value),
+            Self::Customer(_) => insert_rows!(Customer, Customer, std::convert::identity),
+            Self::ReverseLookUp(_) => {
+                insert_rows!(ReverseLookUp, ReverseLookup, std::convert::identity)
+            }
+            Self::Payouts(_) => insert_rows!(Payouts, Payouts, std::convert::identity),
+            Self::PayoutAttempt(_) => {
+                insert_rows!(PayoutAttempt, PayoutAttempt, std::convert::identity)
+            }
+            Self::PaymentMethod(_) => {
+                insert_rows!(PaymentMethod, PaymentMethod, std::convert::identity)
+            }
+            Self::Mandate(_) => insert_rows!(Mandate, Mandate, std::convert::identity),
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "table", content = "data")]
 pub enum Updateable {
@@ -233,6 +330,83 @@ pub enum Updateable {
     MandateUpdate(MandateUpdateMems),
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    #[cfg(feature = "v1")]
+    PaymentMethodDelete(PaymentMethodDeleteMems),
+    MandateDelete(MandateDeleteMems),
+    AddressDelete(AddressDeleteMems),
+}
+
+impl Deletable {
+    pub fn table(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "v1")]
+            Self::PaymentMethodDelete(_) => "payment_method",
+            Self::MandateDelete(_) => "mandate",
+            Self::AddressDelete(_) => "address",
+        }
+    }
+
+    /// Deletes the row, returning whether it existed. The row may have already been deleted by an
+    /// earlier execution of the operation.
+    pub async fn delete(self, conn: &PgPooledConn) -> crate::StorageResult<bool> {
+        let result = match self {
+            #[cfg(feature = "v1")]
+            Self::PaymentMethodDelete(pm) => {
+                PaymentMethod::delete_by_merchant_id_payment_method_id(
+                    conn,
+                    &pm.merchant_id,
+                    &pm.payment_method_id,
+                )
+                .await
+                .map(|_| true)
+            }
+            Self::MandateDelete(mandate) => Mandate::delete_by_merchant_id_mandate_id(
+                conn,
+                &mandate.merchant_id,
+                &mandate.mandate_id,
+            )
+            .await
+            .map(|_| true),
+            Self::AddressDelete(address) => Address::delete_by_merchant_id_address_id(
+                conn,
+                &address.merchant_id,
+                &address.address_id,
+            )
+            .await
+            .map(|_| true),
+        };
+
+        match result {
+            Err(error) if matches!(error.current_context(), errors::DatabaseError::NotFound) => {
+                Ok(false)
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaymentMethodDeleteMems {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MandateDeleteMems {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub mandate_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddressDeleteMems {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub address_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CustomerUpdateMems {
     pub orig: Customer,
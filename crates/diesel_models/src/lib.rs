@@ -18,11 +18,13 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod exchange_rate_snapshot;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod idempotency_key;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -207,6 +207,8 @@ pub struct PaymentAttempt {
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
+    pub retry_details: Option<common_types::payments::PaymentRetryDetails>,
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -428,6 +430,8 @@ pub struct PaymentAttemptNew {
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
+    pub retry_details: Option<common_types::payments::PaymentRetryDetails>,
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -504,6 +508,7 @@ pub enum PaymentAttemptUpdate {
         card_discovery: Option<storage_enums::CardDiscovery>,
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
+        triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -1056,6 +1061,7 @@ pub struct PaymentAttemptUpdateInternal {
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -1247,6 +1253,7 @@ impl PaymentAttemptUpdate {
             setup_future_usage_applied,
             routing_approach,
             connector_request_reference_id,
+            triggered_velocity_rules,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1316,6 +1323,7 @@ impl PaymentAttemptUpdate {
             routing_approach: routing_approach.or(source.routing_approach),
             connector_request_reference_id: connector_request_reference_id
                 .or(source.connector_request_reference_id),
+            triggered_velocity_rules: triggered_velocity_rules.or(source.triggered_velocity_rules),
             ..source
         }
     }
@@ -2376,6 +2384,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2440,6 +2449,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2478,6 +2488,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_discovery,
                 routing_approach,
                 connector_request_reference_id,
+                triggered_velocity_rules,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2538,6 +2549,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach,
                 connector_request_reference_id,
+                triggered_velocity_rules,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2603,6 +2615,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2669,6 +2682,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2735,6 +2749,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -2799,6 +2814,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2863,6 +2879,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2955,6 +2972,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -3038,6 +3056,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied: None,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -3100,6 +3119,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -3171,6 +3191,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3248,6 +3269,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied: None,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3324,6 +3346,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied: None,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3390,6 +3413,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3455,6 +3479,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3529,6 +3554,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied: None,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3594,6 +3620,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3661,6 +3688,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3737,6 +3765,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     setup_future_usage_applied: None,
                     routing_approach: None,
                     connector_request_reference_id: None,
+                    triggered_velocity_rules: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3802,6 +3831,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 setup_future_usage_applied: None,
                 routing_approach: None,
                 connector_request_reference_id: None,
+                triggered_velocity_rules: None,
             },
         }
     }
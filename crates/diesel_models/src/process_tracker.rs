@@ -1,7 +1,11 @@
-pub use common_enums::{enums::ProcessTrackerRunner, ApiVersion};
+pub use common_enums::{
+    enums::{ProcessTrackerEventType, ProcessTrackerRunner},
+    ApiVersion,
+};
 use common_utils::ext_traits::Encode;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use error_stack::ResultExt;
+use router_env::logger;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -47,6 +51,71 @@ impl ProcessTracker {
     pub fn is_valid_business_status(&self, valid_statuses: &[&str]) -> bool {
         valid_statuses.iter().any(|&x| x == self.business_status)
     }
+
+    /// Returns the event history of the task with a new entry appended, describing the transition
+    /// of the task out of its current state. Only the most recent [`MAX_EVENT_HISTORY_LENGTH`]
+    /// entries are retained.
+    pub fn record_event(
+        &self,
+        event_type: ProcessTrackerEventType,
+        schedule_time: Option<PrimitiveDateTime>,
+    ) -> Vec<String> {
+        let event = ProcessTrackerEvent {
+            event_type,
+            previous_status: self.status,
+            previous_business_status: self.business_status.clone(),
+            retry_count: self.retry_count,
+            schedule_time,
+            created_at: common_utils::date_time::now(),
+        };
+
+        let mut events = self.event.clone();
+        match event.encode_to_string_of_json() {
+            Ok(event) => events.push(event),
+            Err(error) => {
+                logger::error!(?error, process_id = %self.id, "Failed to serialize process tracker event");
+            }
+        }
+
+        let excess = events.len().saturating_sub(MAX_EVENT_HISTORY_LENGTH);
+        events.drain(..excess);
+        events
+    }
+
+    /// Returns the recorded event history of the task, oldest first. Entries which cannot be
+    /// parsed are skipped.
+    pub fn get_events(&self) -> Vec<ProcessTrackerEvent> {
+        self.event
+            .iter()
+            .filter_map(|event| {
+                serde_json::from_str(event)
+                    .inspect_err(|error| {
+                        logger::warn!(?error, process_id = %self.id, "Failed to parse process tracker event");
+                    })
+                    .ok()
+            })
+            .collect()
+    }
+}
+
+/// Maximum number of entries retained in the event history of a process tracker task.
+pub const MAX_EVENT_HISTORY_LENGTH: usize = 50;
+
+/// An entry in the event history of a process tracker task, stored in the `event` column.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ProcessTrackerEvent {
+    pub event_type: ProcessTrackerEventType,
+    /// Status of the task before the event
+    pub previous_status: storage_enums::ProcessTrackerStatus,
+    /// Business status of the task before the event
+    pub previous_business_status: String,
+    /// Retry count of the task before the event
+    pub retry_count: i32,
+    /// Time at which the task was scheduled to run after the event, if it was scheduled
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -125,6 +194,13 @@ pub enum ProcessTrackerUpdate {
         status: storage_enums::ProcessTrackerStatus,
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
+        event: Vec<String>,
+    },
+    AdminUpdate {
+        status: storage_enums::ProcessTrackerStatus,
+        business_status: String,
+        schedule_time: Option<PrimitiveDateTime>,
+        event: Vec<String>,
     },
 }
 
@@ -137,6 +213,7 @@ pub struct ProcessTrackerUpdateInternal {
     tracking_data: Option<serde_json::Value>,
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
+    event: Option<Vec<String>>,
     updated_at: Option<PrimitiveDateTime>,
 }
 
@@ -149,6 +226,7 @@ impl Default for ProcessTrackerUpdateInternal {
             tracking_data: Option::default(),
             business_status: Option::default(),
             status: Option::default(),
+            event: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
         }
     }
@@ -172,6 +250,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 tracking_data,
                 business_status,
                 status,
+                event: None,
                 updated_at,
             },
             ProcessTrackerUpdate::StatusUpdate {
@@ -186,10 +265,24 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 status,
                 retry_count,
                 schedule_time,
+                event,
             } => Self {
                 status: Some(status),
                 retry_count: Some(retry_count),
                 schedule_time: Some(schedule_time),
+                event: Some(event),
+                ..Default::default()
+            },
+            ProcessTrackerUpdate::AdminUpdate {
+                status,
+                business_status,
+                schedule_time,
+                event,
+            } => Self {
+                status: Some(status),
+                business_status: Some(business_status),
+                schedule_time,
+                event: Some(event),
                 ..Default::default()
             },
         }
@@ -201,7 +294,11 @@ mod tests {
     #![allow(clippy::unwrap_used)]
     use common_utils::ext_traits::StringExt;
 
-    use super::ProcessTrackerRunner;
+    use super::{
+        business_status, ApiVersion, ProcessTracker, ProcessTrackerEventType, ProcessTrackerRunner,
+        MAX_EVENT_HISTORY_LENGTH,
+    };
+    use crate::enums::ProcessTrackerStatus;
 
     #[test]
     fn test_enum_to_string() {
@@ -210,6 +307,39 @@ mod tests {
             string_format.parse_enum("ProcessTrackerRunner").unwrap();
         assert_eq!(enum_format, ProcessTrackerRunner::PaymentsSyncWorkflow);
     }
+
+    #[test]
+    fn test_record_event_retains_latest_events() {
+        let mut process = ProcessTracker {
+            id: "test_process".to_string(),
+            name: None,
+            tag: vec![],
+            runner: Some(ProcessTrackerRunner::PaymentsSyncWorkflow.to_string()),
+            retry_count: 0,
+            schedule_time: None,
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: business_status::PENDING.to_string(),
+            status: ProcessTrackerStatus::New,
+            event: vec![],
+            created_at: common_utils::date_time::now(),
+            updated_at: common_utils::date_time::now(),
+            version: ApiVersion::V1,
+        };
+
+        for retry_count in 0..=MAX_EVENT_HISTORY_LENGTH {
+            process.retry_count = i32::try_from(retry_count).unwrap();
+            process.event = process.record_event(ProcessTrackerEventType::Retried, None);
+        }
+
+        let events = process.get_events();
+        assert_eq!(events.len(), MAX_EVENT_HISTORY_LENGTH);
+        assert_eq!(events.first().unwrap().retry_count, 1);
+        assert_eq!(
+            events.last().unwrap().event_type,
+            ProcessTrackerEventType::Retried
+        );
+    }
 }
 
 pub mod business_status {
@@ -250,6 +380,10 @@ pub mod business_status {
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 
+    /// The task was cancelled by an operator.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const CANCELLED: &str = "CANCELLED";
+
     /// For the PCR Workflow
     ///
     /// This status indicates the completion of a execute task
@@ -276,4 +410,30 @@ pub mod business_status {
 
     /// This status indicates the completion of a review task
     pub const REVIEW_WORKFLOW_COMPLETE: &str = "COMPLETED_REVIEW_TASK";
+
+    /// For the Routing Activation Workflow
+    ///
+    /// This status indicates that the scheduled routing algorithm was activated and is being
+    /// monitored for a rollback
+    pub const ROUTING_ALGORITHM_ACTIVATED: &str = "ROUTING_ALGORITHM_ACTIVATED";
+
+    /// This status indicates that the routing algorithm was rolled back to the previously active
+    /// algorithm as the success rate dropped below the configured threshold
+    pub const ROUTING_ALGORITHM_ROLLED_BACK: &str = "ROUTING_ALGORITHM_ROLLED_BACK";
+
+    /// For the Dispute Auto Response Workflow
+    ///
+    /// This status indicates that the dispute was accepted at the connector by the dispute auto
+    /// response rules
+    pub const DISPUTE_ACCEPTED: &str = "DISPUTE_ACCEPTED";
+
+    /// This status indicates that the evidence assembled by the dispute auto response rules was
+    /// submitted to the connector
+    pub const DISPUTE_EVIDENCE_SUBMITTED: &str = "DISPUTE_EVIDENCE_SUBMITTED";
+
+    /// For the Dispute Deadline Reminder Workflow
+    ///
+    /// This status indicates that the deadline to respond to the dispute passed without a response
+    /// and the dispute was marked as expired
+    pub const DISPUTE_EXPIRED: &str = "DISPUTE_EXPIRED";
 }
@@ -16,11 +16,13 @@ pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
+pub mod exchange_rate_snapshot;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod idempotency_key;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
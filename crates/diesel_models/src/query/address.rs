@@ -85,6 +85,20 @@ impl Address {
         .await
     }
 
+    pub async fn delete_by_merchant_id_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        address_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::address_id.eq(address_id.to_owned())),
+        )
+        .await
+    }
+
     pub async fn update_by_merchant_id_customer_id(
         conn: &PgPooledConn,
         customer_id: &common_utils::id_type::CustomerId,
@@ -47,6 +47,23 @@ impl Blocklist {
         .await
     }
 
+    pub async fn list_by_merchant_id_data_kinds(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::data_kind.eq_any(data_kinds)),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     pub async fn list_by_merchant_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
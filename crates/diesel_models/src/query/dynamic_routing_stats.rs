@@ -1,12 +1,16 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::{report, ResultExt};
 
 use super::generics;
 use crate::{
     dynamic_routing_stats::{
         DynamicRoutingStats, DynamicRoutingStatsNew, DynamicRoutingStatsUpdate,
     },
-    errors,
+    enums, errors,
+    query::generics::db_metrics,
     schema::dynamic_routing_stats::dsl,
     PgPooledConn, StorageResult,
 };
@@ -58,4 +62,35 @@ impl DynamicRoutingStats {
                 .attach_printable("Error while updating dynamic_routing_stats entry")
         })
     }
+
+    /// Counts the dynamic routing stats entries of the profile created within the time range,
+    /// grouped by the status of the payment.
+    pub async fn get_payment_status_with_count_by_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> StorageResult<Vec<(enums::AttemptStatus, i64)>> {
+        let mut filter = <Self as HasTable>::table()
+            .group_by(dsl::payment_status)
+            .select((dsl::payment_status, diesel::dsl::count_star()))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::profile_id.eq(profile_id.to_owned()))
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            filter = filter.filter(dsl::created_at.le(end_time));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<(enums::AttemptStatus, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting dynamic routing stats by payment status")
+    }
 }
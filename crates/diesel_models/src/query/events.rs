@@ -330,4 +330,159 @@ impl Event {
         .change_context(DatabaseError::Others)
         .attach_printable("Error counting events by constraints")
     }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_dead_letter_attempts_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::is_overall_delivery_successful.eq(false)),
+            )
+            .order(dsl::dead_lettered_at.desc())
+            .into_boxed();
+
+        query = Self::apply_dead_letter_filters(
+            query,
+            profile_id,
+            (dead_lettered_after, dead_lettered_before),
+            event_types,
+            status_code,
+        );
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering dead-lettered events by constraints")
+    }
+
+    pub async fn count_dead_letter_attempts_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> StorageResult<i64> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .count()
+            .filter(
+                dsl::event_id
+                    .nullable()
+                    .eq(dsl::initial_attempt_id) // Filter initial attempts only
+                    .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .and(dsl::is_overall_delivery_successful.eq(false)),
+            )
+            .into_boxed();
+
+        query = Self::apply_dead_letter_filters(
+            query,
+            profile_id,
+            (dead_lettered_after, dead_lettered_before),
+            event_types,
+            status_code,
+        );
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting dead-lettered events by constraints")
+    }
+
+    fn apply_dead_letter_filters<T>(
+        mut query: T,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        (dead_lettered_after, dead_lettered_before): (
+            time::PrimitiveDateTime,
+            time::PrimitiveDateTime,
+        ),
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> T
+    where
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::GtEq<dsl::dead_lettered_at, time::PrimitiveDateTime>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::LtEq<dsl::dead_lettered_at, time::PrimitiveDateTime>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<dsl::business_profile_id, common_utils::id_type::ProfileId>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::EqAny<dsl::event_type, HashSet<common_enums::EventType>>,
+            Output = T,
+        >,
+        T: diesel::query_dsl::methods::FilterDsl<
+            diesel::dsl::Eq<dsl::dead_letter_status_code, i32>,
+            Output = T,
+        >,
+    {
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::business_profile_id.eq(profile_id));
+        }
+
+        // Events which have not exhausted their automatic retries have no `dead_lettered_at`, and
+        // are excluded by the range filter
+        query = query
+            .filter(dsl::dead_lettered_at.ge(dead_lettered_after))
+            .filter(dsl::dead_lettered_at.le(dead_lettered_before));
+
+        if !event_types.is_empty() {
+            query = query.filter(dsl::event_type.eq_any(event_types));
+        }
+
+        if let Some(status_code) = status_code {
+            query = query.filter(dsl::dead_letter_status_code.eq(status_code));
+        }
+
+        query
+    }
 }
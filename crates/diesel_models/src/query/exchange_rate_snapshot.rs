@@ -0,0 +1,48 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    exchange_rate_snapshot::{
+        ExchangeRateSnapshot, ExchangeRateSnapshotNew, ExchangeRateSnapshotUpdate,
+    },
+    schema::exchange_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ExchangeRateSnapshotNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ExchangeRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ExchangeRateSnapshot {
+    pub async fn update_by_rate_date(
+        conn: &PgPooledConn,
+        rate_date: time::Date,
+        snapshot_update: ExchangeRateSnapshotUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(conn, dsl::rate_date.eq(rate_date), snapshot_update)
+        .await
+    }
+
+    /// Finds the latest snapshot taken on or before the given date
+    pub async fn find_latest_on_or_before(
+        conn: &PgPooledConn,
+        rate_date: time::Date,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::rate_date.le(rate_date),
+            Some(1),
+            None,
+            Some(dsl::rate_date.desc()),
+        )
+        .await
+        .map(|snapshots| snapshots.into_iter().next())
+    }
+}
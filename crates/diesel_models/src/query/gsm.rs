@@ -97,4 +97,68 @@ impl GatewayStatusMap {
         )
         .await
     }
+
+    /// Adds `hit_count` hits, the last of which was at `last_hit_at`, to the rule
+    #[allow(clippy::too_many_arguments)]
+    pub async fn increment_hit_count(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        hit_count: i64,
+        last_hit_at: time::PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::connector
+                .eq(connector)
+                .and(dsl::flow.eq(flow))
+                .and(dsl::sub_flow.eq(sub_flow))
+                .and(dsl::code.eq(code))
+                .and(dsl::message.eq(message)),
+            (
+                dsl::hit_count.eq(dsl::hit_count + hit_count),
+                dsl::last_hit_at.eq(last_hit_at),
+            ),
+        )
+        .await
+    }
+
+    /// Lists the rules of the connector, the most matched ones first
+    pub async fn list_by_connector(
+        conn: &PgPooledConn,
+        connector: String,
+        flow: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(dsl::connector.eq(connector))
+            .into_boxed();
+
+        if let Some(flow) = flow {
+            query = query.filter(dsl::flow.eq(flow));
+        }
+
+        let query = query
+            .order((dsl::hit_count.desc(), dsl::last_modified.desc()))
+            .limit(limit)
+            .offset(offset);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering gsm rules by connector")
+    }
 }
@@ -25,6 +25,20 @@ impl Mandate {
         .await
     }
 
+    pub async fn delete_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        mandate_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, Self>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::mandate_id.eq(mandate_id.to_owned())),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_connector_mandate_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
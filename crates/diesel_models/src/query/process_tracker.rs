@@ -41,6 +41,23 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the task only if its status is still the one it was read with, returning `None` if
+    /// the task has been picked up by the scheduler or modified since.
+    #[instrument(skip(conn))]
+    pub async fn update_if_status_unchanged(
+        self,
+        conn: &PgPooledConn,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id.eq(self.id).and(dsl::status.eq(self.status)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+        .map(|processes| processes.into_iter().next())
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
@@ -138,4 +155,76 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        runner: Option<String>,
+        status: Option<enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: i64,
+        offset: i64,
+        version: enums::ApiVersion,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = Self::table()
+            .filter(dsl::version.eq(version))
+            .order(dsl::schedule_time.desc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(runner) = runner {
+            query = query.filter(dsl::runner.eq(runner));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(business_status) = business_status {
+            query = query.filter(dsl::business_status.eq(business_status));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering process tracker tasks by constraints")
+    }
+
+    /// Moves the tasks of the runner which are waiting to be picked up and became overdue between
+    /// `overdue_since` and `schedule_time` to `schedule_time`, so that they fall within the fetch
+    /// window of the producer again.
+    #[instrument(skip(conn))]
+    pub async fn reschedule_overdue_processes_by_runner(
+        conn: &PgPooledConn,
+        runner: String,
+        overdue_since: PrimitiveDateTime,
+        schedule_time: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::runner
+                .eq(runner)
+                .and(dsl::status.eq_any(vec![
+                    enums::ProcessTrackerStatus::New,
+                    enums::ProcessTrackerStatus::Pending,
+                ]))
+                .and(dsl::schedule_time.ge(overdue_since))
+                .and(dsl::schedule_time.lt(schedule_time)),
+            (
+                dsl::schedule_time.eq(schedule_time),
+                dsl::updated_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
 }
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
 use crate::{
@@ -28,4 +28,18 @@ impl ReverseLookup {
         )
         .await
     }
+
+    pub async fn delete_by_pk_id_sk_id(
+        conn: &PgPooledConn,
+        pk_id: &str,
+        sk_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::pk_id
+                .eq(pk_id.to_owned())
+                .and(dsl::sk_id.eq(sk_id.to_owned())),
+        )
+        .await
+    }
 }
@@ -54,6 +54,7 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        restrictions -> Nullable<Jsonb>,
     }
 }
 
@@ -511,6 +512,25 @@ diesel::table! {
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
         is_overall_delivery_successful -> Nullable<Bool>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
+        dead_lettered_at -> Nullable<Timestamp>,
+        dead_letter_status_code -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    exchange_rate_snapshot (rate_date) {
+        rate_date -> Date,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        #[max_length = 64]
+        provider -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
     }
 }
 
@@ -607,6 +627,8 @@ diesel::table! {
         #[max_length = 64]
         error_category -> Nullable<Varchar>,
         clear_pan_possible -> Bool,
+        hit_count -> Int8,
+        last_hit_at -> Nullable<Timestamp>,
     }
 }
 
@@ -632,6 +654,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    idempotency_key (merchant_id, idempotency_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        idempotency_key -> Varchar,
+        #[max_length = 64]
+        request_fingerprint -> Varchar,
+        response -> Bytea,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1557,10 +1596,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     dispute,
     dynamic_routing_stats,
     events,
+    exchange_rate_snapshot,
     file_metadata,
     fraud_check,
     gateway_status_map,
     generic_link,
+    idempotency_key,
     incremental_authorization,
     locker_mock_up,
     mandate,
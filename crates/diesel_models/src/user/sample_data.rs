@@ -218,6 +218,8 @@ pub struct PaymentAttemptBatchNew {
     pub setup_future_usage_applied: Option<common_enums::FutureUsage>,
     pub routing_approach: Option<common_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
+    pub retry_details: Option<common_types::payments::PaymentRetryDetails>,
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -305,6 +307,8 @@ impl PaymentAttemptBatchNew {
             setup_future_usage_applied: self.setup_future_usage_applied,
             routing_approach: self.routing_approach,
             connector_request_reference_id: self.connector_request_reference_id,
+            retry_details: self.retry_details,
+            triggered_velocity_rules: self.triggered_velocity_rules,
         }
     }
 }
@@ -0,0 +1,705 @@
+//! Consistency check of the payment intents, payment attempts and refunds of the merchants using
+//! the `RedisKv` storage scheme.
+//!
+//! The records of such merchants are written to redis, and the database rows are only written
+//! when the drainer executes the operations pushed to the drainer stream. The check samples the
+//! payment keys present in redis, which have all been touched within the KV TTL, and compares
+//! every record stored in them against its database row field by field. Divergent and missing
+//! rows are reported, and can be repaired by writing the redis record to the database. Rows which
+//! have been modified in the database after the redis record are never overwritten.
+
+use std::{borrow::Cow, collections::HashMap, sync::Arc};
+
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::sql_types::Text;
+use diesel_models::{errors::DatabaseError, PaymentAttempt, PaymentIntent, Refund, StorageResult};
+use error_stack::{report, ResultExt};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    connection::pg_connection, errors, logger, metrics, settings::ConsistencyCheckOptions, utils,
+    Store,
+};
+
+const PAYMENT_KEY_PATTERN: &str = "mid_*_pid_*";
+
+/// Fields which are expected to differ between redis and the database, as they are set again
+/// when the drainer executes the operation
+const IGNORED_FIELDS: &[&str] = &["modified_at"];
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RecordKind {
+    PaymentIntent,
+    PaymentAttempt,
+    Refund,
+}
+
+impl RecordKind {
+    fn from_field(field: &str) -> Option<Self> {
+        if field.starts_with("pi_") {
+            Some(Self::PaymentIntent)
+        } else if field.starts_with("pa_") && field.contains("_ref_") {
+            Some(Self::Refund)
+        } else if field.starts_with("pa_") {
+            Some(Self::PaymentAttempt)
+        } else {
+            None
+        }
+    }
+
+    fn table_name(self) -> &'static str {
+        match self {
+            Self::PaymentIntent => "payment_intent",
+            Self::PaymentAttempt => "payment_attempt",
+            Self::Refund => "refund",
+        }
+    }
+
+    fn id_column(self) -> &'static str {
+        match self {
+            Self::PaymentIntent => "payment_id",
+            Self::PaymentAttempt => "attempt_id",
+            Self::Refund => "refund_id",
+        }
+    }
+}
+
+/// A record read from redis along with its database row
+struct RecordPair {
+    merchant_id: String,
+    id: String,
+    modified_at: i64,
+    redis: Value,
+    database: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum RecordStatus {
+    Consistent,
+    InFlight,
+    Diverged { fields: Vec<String> },
+    MissingInDatabase,
+    Unreadable { error: String },
+}
+
+impl RecordStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Consistent => "consistent",
+            Self::InFlight => "in_flight",
+            Self::Diverged { .. } => "diverged",
+            Self::MissingInDatabase => "missing_in_database",
+            Self::Unreadable { .. } => "unreadable",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RepairOutcome {
+    Repaired,
+    /// The database row has been modified after the redis record, so it is left as is
+    SkippedNewerInDatabase,
+    Failed(String),
+}
+
+#[derive(Debug, Serialize)]
+struct RecordReport<'a> {
+    key: &'a str,
+    field: &'a str,
+    kind: RecordKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a str>,
+    #[serde(flatten)]
+    status: &'a RecordStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repair: Option<&'a RepairOutcome>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Summary {
+    sampled_keys: usize,
+    checked_records: usize,
+    consistent: usize,
+    in_flight: usize,
+    diverged: usize,
+    missing_in_database: usize,
+    unreadable: usize,
+    repaired: usize,
+    repair_skipped: usize,
+    repair_failed: usize,
+}
+
+impl Summary {
+    fn record(&mut self, status: &RecordStatus, repair: Option<&RepairOutcome>) {
+        self.checked_records += 1;
+        match status {
+            RecordStatus::Consistent => self.consistent += 1,
+            RecordStatus::InFlight => self.in_flight += 1,
+            RecordStatus::Diverged { .. } => self.diverged += 1,
+            RecordStatus::MissingInDatabase => self.missing_in_database += 1,
+            RecordStatus::Unreadable { .. } => self.unreadable += 1,
+        }
+        match repair {
+            Some(RepairOutcome::Repaired) => self.repaired += 1,
+            Some(RepairOutcome::SkippedNewerInDatabase) => self.repair_skipped += 1,
+            Some(RepairOutcome::Failed(_)) => self.repair_failed += 1,
+            None => {}
+        }
+    }
+}
+
+#[derive(diesel::QueryableByName)]
+struct ColumnName {
+    #[diesel(sql_type = Text)]
+    column_name: String,
+}
+
+/// Checks the records stored in the sampled payment keys of the store, printing a JSON line for
+/// every record which is not consistent with the database, followed by a summary of the check
+pub(crate) async fn perform_check(
+    store: Arc<Store>,
+    options: ConsistencyCheckOptions,
+) -> errors::DrainerResult<()> {
+    let keys = store.sample_payment_keys(options.sample_size).await?;
+    let mut binary_columns = HashMap::new();
+    let mut summary = Summary {
+        sampled_keys: keys.len(),
+        ..Default::default()
+    };
+
+    for key in &keys {
+        let fields = store
+            .redis_conn
+            .get_hash_fields::<HashMap<String, String>>(&key.as_str().into())
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        for (field, value) in &fields {
+            let Some(kind) = RecordKind::from_field(field) else {
+                continue;
+            };
+
+            let (status, pair) = match store.read_record_pair(kind, value).await {
+                Ok(pair) => (pair_status(&pair, options.min_age_seconds), Some(pair)),
+                Err(error) => {
+                    logger::error!(?error, key, field, "Failed to read the record");
+                    let status = RecordStatus::Unreadable {
+                        error: error.to_string(),
+                    };
+                    (status, None)
+                }
+            };
+
+            let repair = match (&status, &pair) {
+                (RecordStatus::Diverged { .. } | RecordStatus::MissingInDatabase, Some(pair))
+                    if options.repair =>
+                {
+                    if !binary_columns.contains_key(kind.table_name()) {
+                        let columns = store.find_binary_columns(kind.table_name()).await?;
+                        binary_columns.insert(kind.table_name(), columns);
+                    }
+                    let columns = binary_columns
+                        .get(kind.table_name())
+                        .map(Vec::as_slice)
+                        .unwrap_or_default();
+
+                    Some(
+                        match store.repair_record(kind, pair, &status, columns).await {
+                            Ok(outcome) => outcome,
+                            Err(error) => {
+                                logger::error!(?error, key, field, "Failed to repair the record");
+                                RepairOutcome::Failed(error.to_string())
+                            }
+                        },
+                    )
+                }
+                _ => None,
+            };
+
+            metrics::CONSISTENCY_CHECK_RECORDS.add(
+                1,
+                router_env::metric_attributes!(
+                    ("table", kind.table_name()),
+                    ("status", status.as_str()),
+                ),
+            );
+            summary.record(&status, repair.as_ref());
+
+            if !matches!(status, RecordStatus::Consistent | RecordStatus::InFlight) {
+                utils::print_json_line(&RecordReport {
+                    key,
+                    field,
+                    kind,
+                    id: pair.as_ref().map(|pair| pair.id.as_str()),
+                    status: &status,
+                    repair: repair.as_ref(),
+                });
+            }
+        }
+    }
+
+    logger::info!(?summary, "Completed the consistency check");
+    utils::print_json_line(&serde_json::json!({ "summary": summary }));
+
+    Ok(())
+}
+
+impl Store {
+    /// Scans redis for payment keys, returning at most `sample_size` of them without the tenant
+    /// prefix
+    async fn sample_payment_keys(&self, sample_size: usize) -> errors::DrainerResult<Vec<String>> {
+        let prefix = self.redis_conn.add_prefix("");
+        let keys = self
+            .redis_conn
+            .scan(
+                &PAYMENT_KEY_PATTERN.into(),
+                u32::try_from(sample_size).ok(),
+                None,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(keys
+            .into_iter()
+            .take(sample_size)
+            .map(|key| {
+                key.strip_prefix(prefix.as_str())
+                    .map(ToOwned::to_owned)
+                    .unwrap_or(key)
+            })
+            .collect())
+    }
+
+    /// Parses the redis record and finds the database row with the same primary key
+    async fn read_record_pair(
+        &self,
+        kind: RecordKind,
+        value: &str,
+    ) -> errors::DrainerResult<RecordPair> {
+        let conn = pg_connection(&self.master_pool).await;
+
+        match kind {
+            RecordKind::PaymentIntent => {
+                let record = parse_record::<PaymentIntent>(value)?;
+                let row = PaymentIntent::find_optional_by_payment_id_merchant_id(
+                    &conn,
+                    &record.payment_id,
+                    &record.merchant_id,
+                )
+                .await;
+                RecordPair::new(
+                    &record,
+                    record.merchant_id.get_string_repr(),
+                    record.payment_id.get_string_repr(),
+                    record.modified_at.assume_utc().unix_timestamp(),
+                    row,
+                )
+            }
+            RecordKind::PaymentAttempt => {
+                let record = parse_record::<PaymentAttempt>(value)?;
+                let row = PaymentAttempt::find_by_merchant_id_attempt_id(
+                    &conn,
+                    &record.merchant_id,
+                    &record.attempt_id,
+                )
+                .await;
+                RecordPair::new(
+                    &record,
+                    record.merchant_id.get_string_repr(),
+                    &record.attempt_id,
+                    record.modified_at.assume_utc().unix_timestamp(),
+                    optional_row(row),
+                )
+            }
+            RecordKind::Refund => {
+                let record = parse_record::<Refund>(value)?;
+                let row = Refund::find_by_merchant_id_refund_id(
+                    &conn,
+                    &record.merchant_id,
+                    &record.refund_id,
+                )
+                .await;
+                RecordPair::new(
+                    &record,
+                    record.merchant_id.get_string_repr(),
+                    &record.refund_id,
+                    record.modified_at.assume_utc().unix_timestamp(),
+                    optional_row(row),
+                )
+            }
+        }
+    }
+
+    /// Finds the `bytea` columns of the table, which hold encrypted data
+    async fn find_binary_columns(
+        &self,
+        table_name: &'static str,
+    ) -> errors::DrainerResult<Vec<String>> {
+        let conn = pg_connection(&self.master_pool).await;
+
+        diesel::sql_query(
+            "SELECT column_name::text AS column_name FROM information_schema.columns \
+             WHERE table_schema = current_schema() AND table_name = $1 AND data_type = 'bytea'",
+        )
+        .bind::<Text, _>(table_name)
+        .load_async::<ColumnName>(&*conn)
+        .await
+        .map(|columns| {
+            columns
+                .into_iter()
+                .map(|column| column.column_name)
+                .collect()
+        })
+        .map_err(|error| {
+            report!(errors::DrainerError::UnexpectedError(format!(
+                "Failed to find the binary columns of `{table_name}`: {error}"
+            )))
+        })
+    }
+
+    /// Writes the divergent fields of the redis record to the database row, or inserts the redis
+    /// record when the row is missing. The row is only updated if it has not been modified after
+    /// the redis record, whose timestamps are serialized with millisecond precision.
+    async fn repair_record(
+        &self,
+        kind: RecordKind,
+        pair: &RecordPair,
+        status: &RecordStatus,
+        binary_columns: &[String],
+    ) -> errors::DrainerResult<RepairOutcome> {
+        let table_name = kind.table_name();
+        let record = to_database_record(&pair.redis, binary_columns)?;
+        let conn = pg_connection(&self.master_pool).await;
+
+        let result = match status {
+            RecordStatus::Diverged { fields } => {
+                let columns = quote_columns(fields);
+                diesel::sql_query(format!(
+                    "UPDATE {table_name} SET ({columns}) = (SELECT {columns} \
+                     FROM jsonb_populate_record(NULL::{table_name}, $1::jsonb)) \
+                     WHERE merchant_id = $2 AND {} = $3 \
+                     AND date_trunc('milliseconds', modified_at) <= (SELECT modified_at \
+                     FROM jsonb_populate_record(NULL::{table_name}, $1::jsonb))",
+                    kind.id_column()
+                ))
+                .bind::<Text, _>(Value::Object(record).to_string())
+                .bind::<Text, _>(pair.merchant_id.clone())
+                .bind::<Text, _>(pair.id.clone())
+                .execute_async(&*conn)
+                .await
+            }
+            RecordStatus::MissingInDatabase => {
+                let columns = quote_columns(record.keys());
+                diesel::sql_query(format!(
+                    "INSERT INTO {table_name} ({columns}) SELECT {columns} \
+                     FROM jsonb_populate_record(NULL::{table_name}, $1::jsonb)"
+                ))
+                .bind::<Text, _>(Value::Object(record).to_string())
+                .execute_async(&*conn)
+                .await
+            }
+            RecordStatus::Consistent | RecordStatus::InFlight | RecordStatus::Unreadable { .. } => {
+                return Ok(RepairOutcome::Repaired)
+            }
+        };
+
+        match result {
+            Ok(1) => Ok(RepairOutcome::Repaired),
+            Ok(0) if matches!(status, RecordStatus::Diverged { .. }) => {
+                Ok(RepairOutcome::SkippedNewerInDatabase)
+            }
+            Ok(written_rows) => Err(report!(errors::DrainerError::UnexpectedError(format!(
+                "Expected one row of `{table_name}` to be written, but {written_rows} were written"
+            )))),
+            Err(error) => Err(report!(errors::DrainerError::UnexpectedError(format!(
+                "Failed to write the row of `{table_name}`: {error}"
+            )))),
+        }
+    }
+}
+
+impl RecordPair {
+    fn new<T: Serialize>(
+        record: &T,
+        merchant_id: &str,
+        id: &str,
+        modified_at: i64,
+        row: StorageResult<Option<T>>,
+    ) -> errors::DrainerResult<Self> {
+        let row = row.change_context(errors::DrainerError::UnexpectedError(format!(
+            "Failed to find the database row of `{id}`"
+        )))?;
+
+        Ok(Self {
+            merchant_id: merchant_id.to_owned(),
+            id: id.to_owned(),
+            modified_at,
+            redis: to_value(record)?,
+            database: row.as_ref().map(to_value).transpose()?,
+        })
+    }
+}
+
+fn pair_status(pair: &RecordPair, min_age_seconds: i64) -> RecordStatus {
+    let age = common_utils::date_time::now_unix_timestamp() - pair.modified_at;
+    if age < min_age_seconds {
+        return RecordStatus::InFlight;
+    }
+
+    let Some(database) = &pair.database else {
+        return RecordStatus::MissingInDatabase;
+    };
+
+    let fields = diverged_fields(&pair.redis, database);
+    if fields.is_empty() {
+        RecordStatus::Consistent
+    } else {
+        RecordStatus::Diverged { fields }
+    }
+}
+
+fn diverged_fields(redis: &Value, database: &Value) -> Vec<String> {
+    let (Some(redis), Some(database)) = (redis.as_object(), database.as_object()) else {
+        return Vec::new();
+    };
+
+    redis
+        .iter()
+        .filter(|(field, value)| {
+            !IGNORED_FIELDS.contains(&field.as_str())
+                && !database
+                    .get(field.as_str())
+                    .is_some_and(|database_value| values_match(value, database_value))
+        })
+        .map(|(field, _)| field.clone())
+        .collect()
+}
+
+fn values_match(redis: &Value, database: &Value) -> bool {
+    match (redis, database) {
+        (Value::String(redis), Value::String(database)) => {
+            truncate_to_microseconds(redis) == truncate_to_microseconds(database)
+        }
+        (redis, database) => redis == database,
+    }
+}
+
+/// Truncates the fractional seconds of a timestamp to microseconds, which is the precision of the
+/// timestamps stored in the database, while the timestamps of the redis records have nanosecond
+/// precision. Trailing zeros are dropped as well, and values which are not timestamps are
+/// returned as is.
+fn truncate_to_microseconds(value: &str) -> Cow<'_, str> {
+    let Some((date_time, fraction)) = value.split_once('.') else {
+        return Cow::Borrowed(value);
+    };
+    let is_timestamp = date_time
+        .rsplit(['T', ' '])
+        .next()
+        .is_some_and(|time_of_day| {
+            time_of_day.len() < date_time.len() && is_time_of_day(time_of_day)
+        });
+    if !is_timestamp {
+        return Cow::Borrowed(value);
+    }
+
+    let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+    let (fraction, offset) = fraction.split_at(digits);
+    let microseconds = fraction.get(..6).unwrap_or(fraction).trim_end_matches('0');
+    if microseconds.is_empty() {
+        Cow::Owned(format!("{date_time}{offset}"))
+    } else {
+        Cow::Owned(format!("{date_time}.{microseconds}{offset}"))
+    }
+}
+
+fn is_time_of_day(value: &str) -> bool {
+    value.len() == 8
+        && value.bytes().enumerate().all(|(index, byte)| {
+            if index == 2 || index == 5 {
+                byte == b':'
+            } else {
+                byte.is_ascii_digit()
+            }
+        })
+}
+
+fn parse_record<T: DeserializeOwned>(value: &str) -> errors::DrainerResult<T> {
+    serde_json::from_str(value).map_err(|error| {
+        report!(errors::DrainerError::UnexpectedError(format!(
+            "Failed to parse the redis record: {error}"
+        )))
+    })
+}
+
+fn to_value(record: &impl Serialize) -> errors::DrainerResult<Value> {
+    serde_json::to_value(record).map_err(|error| {
+        report!(errors::DrainerError::UnexpectedError(format!(
+            "Failed to serialize the record: {error}"
+        )))
+    })
+}
+
+fn optional_row<T>(row: StorageResult<T>) -> StorageResult<Option<T>> {
+    match row {
+        Ok(row) => Ok(Some(row)),
+        Err(error) if matches!(error.current_context(), DatabaseError::NotFound) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn quote_columns<'a>(columns: impl IntoIterator<Item = &'a String>) -> String {
+    columns
+        .into_iter()
+        .map(|column| format!("\"{column}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Converts the serialized record into a JSON object that `jsonb_populate_record` can read. The
+/// encrypted fields are serialized as arrays of bytes, which are converted into the hex format of
+/// `bytea` values.
+fn to_database_record(
+    record: &Value,
+    binary_columns: &[String],
+) -> errors::DrainerResult<serde_json::Map<String, Value>> {
+    let mut record = record.as_object().cloned().ok_or_else(|| {
+        report!(errors::DrainerError::UnexpectedError(
+            "The redis record is not a JSON object".to_string()
+        ))
+    })?;
+
+    for column in binary_columns {
+        if let Some(value) = record.get_mut(column.as_str()) {
+            *value = to_bytea_hex(value).ok_or_else(|| {
+                report!(errors::DrainerError::UnexpectedError(format!(
+                    "The binary field `{column}` has an unexpected format"
+                )))
+            })?;
+        }
+    }
+
+    Ok(record)
+}
+
+fn to_bytea_hex(value: &Value) -> Option<Value> {
+    let bytes = match value {
+        Value::Null => return Some(Value::Null),
+        Value::Object(encryption) => encryption.get("inner")?,
+        bytes => bytes,
+    };
+
+    let hex = bytes
+        .as_array()?
+        .iter()
+        .map(|byte| {
+            byte.as_u64()
+                .and_then(|byte| u8::try_from(byte).ok())
+                .map(|byte| format!("{byte:02x}"))
+        })
+        .collect::<Option<String>>()?;
+
+    Some(Value::String(format!("\\x{hex}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_record_kind_from_field() {
+        assert!(matches!(
+            RecordKind::from_field("pi_pay_123"),
+            Some(RecordKind::PaymentIntent)
+        ));
+        assert!(matches!(
+            RecordKind::from_field("pa_pay_123_1"),
+            Some(RecordKind::PaymentAttempt)
+        ));
+        assert!(matches!(
+            RecordKind::from_field("pa_pay_123_1_ref_ref_123"),
+            Some(RecordKind::Refund)
+        ));
+        assert!(RecordKind::from_field("mandate_123").is_none());
+        assert!(RecordKind::from_field("").is_none());
+    }
+
+    #[test]
+    fn test_diverged_fields() {
+        let redis = json!({
+            "status": "charged",
+            "amount": 100,
+            "modified_at": "2024-01-01T00:00:01.000Z",
+            "connector": "stripe",
+        });
+        let database = json!({
+            "status": "authorized",
+            "amount": 100,
+            "modified_at": "2024-01-01T00:00:00.000Z",
+        });
+
+        let mut fields = diverged_fields(&redis, &database);
+        fields.sort();
+        assert_eq!(fields, vec!["connector", "status"]);
+        assert!(diverged_fields(&redis, &redis).is_empty());
+        assert!(diverged_fields(&redis, &Value::Null).is_empty());
+    }
+
+    #[test]
+    fn test_diverged_fields_timestamp_precision() {
+        let redis = json!({
+            "created_at": "2024-01-01 00:00:00.123456789",
+            "last_synced": "2024-01-01T00:00:00.000000500Z",
+            "updated_by": "2024-01-01T00:00:00.5",
+        });
+        let database = json!({
+            "created_at": "2024-01-01 00:00:00.123456",
+            "last_synced": "2024-01-01T00:00:00Z",
+            "updated_by": "2024-01-01T00:00:00.4",
+        });
+
+        assert_eq!(diverged_fields(&redis, &database), vec!["updated_by"]);
+    }
+
+    #[test]
+    fn test_truncate_to_microseconds() {
+        assert_eq!(
+            truncate_to_microseconds("2024-01-01T00:00:00.123456789Z"),
+            "2024-01-01T00:00:00.123456Z"
+        );
+        assert_eq!(
+            truncate_to_microseconds("2024-01-01 00:00:00.1234"),
+            "2024-01-01 00:00:00.1234"
+        );
+        assert_eq!(
+            truncate_to_microseconds("2024-01-01T00:00:00.000000999+05:30"),
+            "2024-01-01T00:00:00+05:30"
+        );
+        // Values which are not timestamps are not modified
+        assert_eq!(truncate_to_microseconds("1.1234567890"), "1.1234567890");
+        assert_eq!(truncate_to_microseconds("pay_123"), "pay_123");
+    }
+
+    #[test]
+    fn test_to_bytea_hex() {
+        assert_eq!(to_bytea_hex(&Value::Null), Some(Value::Null));
+        assert_eq!(
+            to_bytea_hex(&json!([0, 1, 171, 255])),
+            Some(json!("\\x0001abff"))
+        );
+        assert_eq!(
+            to_bytea_hex(&json!({ "inner": [16, 32] })),
+            Some(json!("\\x1020"))
+        );
+        assert_eq!(to_bytea_hex(&json!([])), Some(json!("\\x")));
+        assert_eq!(to_bytea_hex(&json!([256])), None);
+        assert_eq!(to_bytea_hex(&json!([-1])), None);
+        assert_eq!(to_bytea_hex(&json!("0001")), None);
+        assert_eq!(to_bytea_hex(&json!({ "other": [1] })), None);
+    }
+}
@@ -0,0 +1,204 @@
+//! Dead-letter stream of the database operations which the drainer failed to execute.
+//!
+//! The failed stream entry is appended to the dead-letter stream along with the error, before the
+//! entry is trimmed from the drainer stream, so that the operation is not lost. The operations in
+//! the dead-letter stream can be inspected, retried or discarded using the `dead-letter`
+//! subcommand of the drainer.
+
+use std::{collections::HashMap, sync::Arc};
+
+use diesel_models::errors::DatabaseError;
+use error_stack::Report;
+use redis_interface as redis;
+use serde::Serialize;
+
+use crate::{
+    errors, logger, query::ExecuteQuery, settings::DeadLetterAction, stream::StreamEntries, utils,
+    Store, StreamData,
+};
+
+const SOURCE_STREAM_FIELD: &str = "source_stream";
+const SOURCE_ENTRY_ID_FIELD: &str = "source_entry_id";
+const ERROR_FIELD: &str = "error";
+const FAILED_AT_FIELD: &str = "failed_at";
+
+#[derive(Debug, Serialize)]
+struct DeadLetterEntry<'a> {
+    id: &'a str,
+    #[serde(flatten)]
+    fields: &'a HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum RetryOutcome<'a> {
+    Succeeded { id: &'a str },
+    Failed { id: &'a str, error: String },
+    NotFound { id: &'a str },
+}
+
+impl Store {
+    #[inline(always)]
+    fn get_dead_letter_stream_name(&self) -> &str {
+        &self.config.drainer_dead_letter_stream_name
+    }
+
+    /// Appends the stream entry of the failed operation to the dead-letter stream, along with the
+    /// stream it was read from and the error it failed with
+    pub(crate) async fn push_to_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        mut entry: HashMap<String, String>,
+        error: &Report<DatabaseError>,
+    ) -> errors::DrainerResult<()> {
+        entry.insert(SOURCE_STREAM_FIELD.to_string(), stream_name.to_string());
+        entry.insert(SOURCE_ENTRY_ID_FIELD.to_string(), entry_id.to_string());
+        entry.insert(ERROR_FIELD.to_string(), format!("{error:?}"));
+        entry.insert(
+            FAILED_AT_FIELD.to_string(),
+            common_utils::date_time::now_unix_timestamp().to_string(),
+        );
+
+        self.redis_conn
+            .stream_append_entry(
+                &self.get_dead_letter_stream_name().into(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                entry.into_iter().collect::<Vec<_>>(),
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(())
+    }
+
+    /// Reads the dead-letter entries added after the entry with the given id
+    async fn read_dead_letter_entries(
+        &self,
+        after_id: &str,
+        count: u64,
+    ) -> errors::DrainerResult<StreamEntries> {
+        let stream_name = self.get_dead_letter_stream_name();
+        let read_result = match self
+            .redis_conn
+            .stream_read_entries(stream_name, after_id, Some(count))
+            .await
+        {
+            Ok(read_result) => read_result,
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    redis::errors::RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                return Ok(Vec::new())
+            }
+            Err(error) => return Err(errors::DrainerError::from(error).into()),
+        };
+
+        utils::parse_stream_entries(
+            &read_result,
+            self.redis_conn.add_prefix(stream_name).as_str(),
+        )
+        .cloned()
+    }
+
+    /// Reads the dead-letter stream until the entries with the given ids are found
+    async fn find_dead_letter_entries(
+        &self,
+        ids: &[String],
+        read_count: u64,
+    ) -> errors::DrainerResult<StreamEntries> {
+        let mut found_entries = Vec::new();
+        let mut last_read_id = String::from("0-0");
+
+        while found_entries.len() < ids.len() {
+            let entries = self
+                .read_dead_letter_entries(&last_read_id, read_count)
+                .await?;
+            let Some((last_entry_id, _)) = entries.last() else {
+                break;
+            };
+            last_read_id = last_entry_id.clone();
+
+            found_entries.extend(
+                entries
+                    .into_iter()
+                    .filter(|(entry_id, _)| ids.contains(entry_id)),
+            );
+        }
+
+        Ok(found_entries)
+    }
+
+    async fn delete_dead_letter_entries(&self, ids: Vec<String>) -> errors::DrainerResult<usize> {
+        self.redis_conn
+            .stream_delete_entries(&self.get_dead_letter_stream_name().into(), ids)
+            .await
+            .map_err(|error| errors::DrainerError::from(error).into())
+    }
+}
+
+/// Performs the dead-letter subcommand action on the dead-letter stream of the store
+pub(crate) async fn perform_action(
+    store: Arc<Store>,
+    action: DeadLetterAction,
+    read_count: u64,
+) -> errors::DrainerResult<()> {
+    match action {
+        DeadLetterAction::List { count, after } => {
+            let entries = store
+                .read_dead_letter_entries(after.as_deref().unwrap_or("0-0"), count)
+                .await?;
+            for (id, fields) in &entries {
+                utils::print_json_line(&DeadLetterEntry { id, fields });
+            }
+        }
+        DeadLetterAction::Retry { ids } => {
+            let entries = store.find_dead_letter_entries(&ids, read_count).await?;
+
+            for id in &ids {
+                let Some((_, fields)) = entries.iter().find(|(entry_id, _)| entry_id == id) else {
+                    utils::print_json_line(&RetryOutcome::NotFound { id });
+                    continue;
+                };
+
+                let outcome = match retry_operation(&store, fields.clone()).await {
+                    Ok(()) => {
+                        store.delete_dead_letter_entries(vec![id.clone()]).await?;
+                        RetryOutcome::Succeeded { id }
+                    }
+                    Err(error) => {
+                        logger::error!(dead_letter_entry_id = %id, ?error);
+                        RetryOutcome::Failed {
+                            id,
+                            error: error.to_string(),
+                        }
+                    }
+                };
+                utils::print_json_line(&outcome);
+            }
+        }
+        DeadLetterAction::Discard { ids } => {
+            let discarded = store.delete_dead_letter_entries(ids).await?;
+            logger::info!(discarded, "Discarded dead-letter entries");
+            utils::print_json_line(&serde_json::json!({ "discarded": discarded }));
+        }
+    }
+
+    Ok(())
+}
+
+async fn retry_operation(
+    store: &Arc<Store>,
+    fields: HashMap<String, String>,
+) -> errors::DrainerResult<()> {
+    let data = StreamData::from_hashmap(fields).map_err(errors::DrainerError::ParsingError)?;
+
+    match data.typed_sql.execute_query(store, data.pushed_at).await {
+        Ok(()) => Ok(()),
+        // The operation has already been written to the database
+        Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => Ok(()),
+        Err(error) => Err(errors::DrainerError::UnexpectedError(format!("{error:?}")).into()),
+    }
+}
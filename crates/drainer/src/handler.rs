@@ -3,7 +3,8 @@ use std::{
     sync::{atomic, Arc},
 };
 
-use common_utils::id_type;
+use common_utils::{errors::CustomResult, id_type};
+use error_stack::{report, ResultExt};
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
@@ -11,8 +12,9 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    errors, instrument, kv, logger, metrics,
+    query::{self, ExecuteQuery},
+    tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -82,6 +84,7 @@ impl Handler {
                             store.clone(),
                             stream_index,
                             self.conf.max_read_count,
+                            self.conf.max_insert_batch_size,
                             self.active_tasks.clone(),
                             jobs_picked.clone(),
                         )
@@ -174,6 +177,7 @@ async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
     max_read_count: u64,
+    max_insert_batch_size: u16,
     active_tasks: Arc<atomic::AtomicU64>,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -184,6 +188,7 @@ async fn drainer_handler(
     let drainer_result = Box::pin(drainer(
         store.clone(),
         max_read_count,
+        max_insert_batch_size,
         stream_name.as_str(),
         jobs_picked,
     ))
@@ -204,6 +209,7 @@ async fn drainer_handler(
 async fn drainer(
     store: Arc<Store>,
     max_read_count: u64,
+    max_insert_batch_size: u16,
     stream_name: &str,
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
@@ -242,11 +248,14 @@ async fn drainer(
 
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
 
-    let mut last_processed_id = String::new();
-
+    let mut parsed_entries = Vec::with_capacity(read_count);
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
+        match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => parsed_entries.push(DrainerEntry {
+                entry_id,
+                entry,
+                data,
+            }),
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
                 metrics::STREAM_PARSE_FAIL.add(
@@ -254,28 +263,55 @@ async fn drainer(
                     router_env::metric_attributes!(("operation", "deserialization")),
                 );
 
-                // break from the loop in case of a deser error
+                // stop reading the entries in case of a deser error
                 break;
             }
-        };
+        }
+    }
 
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-        tracing::Span::current().record("session_id", &session_id);
+    let mut last_processed_id = String::new();
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
-            }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+    'batches: for batch in group_insert_batches(
+        parsed_entries,
+        max_insert_batch_size,
+        DrainerEntry::insert_table,
+    ) {
+        if batch.len() > 1 {
+            match execute_insert_batch(&store, &batch).await {
+                Ok(()) => {
+                    if let Some(last_entry) = batch.last() {
+                        last_processed_id.clone_from(&last_entry.entry_id);
+                    }
+                    continue;
                 }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+                // The database could not be reached, so the entries are retried in the next cycle
+                Err(err)
+                    if matches!(
+                        err.current_context(),
+                        diesel_models::errors::DatabaseError::DatabaseConnectionError
+                    ) =>
+                {
+                    break;
+                }
+                // The entries are executed one by one, so that only the failing ones are moved to
+                // the dead-letter stream
+                Err(err) => {
+                    logger::warn!(?err, "Falling back to inserting the batch one by one");
+                    metrics::BATCH_INSERT_FALLBACKS.add(1, &[]);
+                }
+            }
+        }
+
+        for entry in batch {
+            tracing::Span::current().record("request_id", &entry.data.request_id);
+            tracing::Span::current().record("global_id", &entry.data.global_id);
+            tracing::Span::current().record("session_id", &session_id);
+
+            let entry_id = entry.entry_id.clone();
+            if !process_entry(&store, stream_name, entry).await {
+                break 'batches;
+            }
+            last_processed_id = entry_id;
         }
     }
 
@@ -297,3 +333,320 @@ async fn drainer(
 
     Ok(())
 }
+
+struct DrainerEntry {
+    entry_id: String,
+    entry: HashMap<String, String>,
+    data: StreamData,
+}
+
+impl DrainerEntry {
+    fn insert_table(&self) -> Option<&'static str> {
+        match &self.data.typed_sql {
+            kv::DBOperation::Insert { insertable } => Some(insertable.table()),
+            kv::DBOperation::Update { .. } | kv::DBOperation::Delete { .. } => None,
+        }
+    }
+}
+
+/// Groups the consecutive entries which insert into the same table into batches of at most
+/// `max_insert_batch_size` entries. Every other entry forms a batch of its own.
+fn group_insert_batches<T>(
+    entries: Vec<T>,
+    max_insert_batch_size: u16,
+    insert_table: impl Fn(&T) -> Option<&'static str>,
+) -> Vec<Vec<T>> {
+    let mut batches: Vec<Vec<T>> = Vec::new();
+
+    for entry in entries {
+        let table = insert_table(&entry);
+        match batches.last_mut() {
+            Some(batch)
+                if table.is_some()
+                    && batch.len() < usize::from(max_insert_batch_size)
+                    && batch.last().and_then(&insert_table) == table =>
+            {
+                batch.push(entry)
+            }
+            _ => batches.push(vec![entry]),
+        }
+    }
+
+    batches
+}
+
+/// Inserts the records of the batch using a single multi-row insert
+async fn execute_insert_batch(
+    store: &Arc<Store>,
+    batch: &[DrainerEntry],
+) -> CustomResult<(), diesel_models::errors::DatabaseError> {
+    let mut table = "";
+    let mut insertables = Vec::with_capacity(batch.len());
+    let mut pushed_at = Vec::with_capacity(batch.len());
+
+    // The entries are parsed again, as the records are consumed by the insert and are needed to
+    // fall back to inserting them one by one
+    for entry in batch {
+        let data = StreamData::from_hashmap(entry.entry.clone())
+            .change_context(diesel_models::errors::DatabaseError::QueryGenerationFailed)?;
+        match data.typed_sql {
+            kv::DBOperation::Insert { insertable } => {
+                table = insertable.table();
+                insertables.push(*insertable);
+                pushed_at.push(data.pushed_at);
+            }
+            kv::DBOperation::Update { .. } | kv::DBOperation::Delete { .. } => {
+                return Err(report!(
+                    diesel_models::errors::DatabaseError::QueryGenerationFailed
+                ))
+                .attach_printable("Only inserts can be executed in an insert batch")
+            }
+        }
+    }
+
+    query::execute_batch_insert(store, table, insertables, &pushed_at).await
+}
+
+/// Executes the operation of the entry, returning whether the entry can be trimmed from the stream
+async fn process_entry(store: &Arc<Store>, stream_name: &str, entry: DrainerEntry) -> bool {
+    let DrainerEntry {
+        entry_id,
+        entry,
+        data,
+    } = entry;
+    let operation = data.typed_sql.operation();
+    let table = data.typed_sql.table();
+
+    match data.typed_sql.execute_query(store, data.pushed_at).await {
+        Ok(_) => true,
+        Err(err) => match err.current_context() {
+            // In case of Uniqueviolation we can't really do anything to fix it so just clear
+            // it from the stream
+            diesel_models::errors::DatabaseError::UniqueViolation => true,
+            // The database could not be reached, so the entry is retried in the next cycle
+            diesel_models::errors::DatabaseError::DatabaseConnectionError => false,
+            // The failed operation is moved to the dead-letter stream so that it is not lost
+            // when the entry is trimmed from the stream
+            _ => {
+                let tags =
+                    router_env::metric_attributes!(("operation", operation), ("table", table));
+                match store
+                    .push_to_dead_letter_stream(stream_name, &entry_id, entry, &err)
+                    .await
+                {
+                    Ok(()) => {
+                        metrics::DEAD_LETTER_ENTRIES_ADDED.add(1, tags);
+                        true
+                    }
+                    Err(error) => {
+                        logger::error!(operation = "dead_letter_write", ?error);
+                        metrics::ERRORS_WHILE_DEAD_LETTER_WRITE.add(1, tags);
+                        // the entry is not trimmed, so that it is not lost
+                        false
+                    }
+                }
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::panic)]
+    use super::*;
+
+    /// Entries are represented by the table they insert into, or `None` for updates and deletes
+    fn batches(
+        entries: &[Option<&'static str>],
+        max_insert_batch_size: u16,
+    ) -> Vec<Vec<Option<&'static str>>> {
+        group_insert_batches(entries.to_vec(), max_insert_batch_size, |table| *table)
+    }
+
+    #[test]
+    fn test_group_consecutive_inserts_into_same_table() {
+        let address = Some("address");
+        let refund = Some("refund");
+
+        assert_eq!(
+            batches(&[address, address, refund, refund, address], 10),
+            vec![vec![address, address], vec![refund, refund], vec![address]]
+        );
+    }
+
+    #[test]
+    fn test_updates_and_deletes_are_not_batched() {
+        let address = Some("address");
+
+        assert_eq!(
+            batches(&[address, None, None, address, address], 10),
+            vec![
+                vec![address],
+                vec![None],
+                vec![None],
+                vec![address, address]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_size_limit() {
+        let address = Some("address");
+
+        assert_eq!(
+            batches(&[address; 5], 2),
+            vec![
+                vec![address, address],
+                vec![address, address],
+                vec![address]
+            ]
+        );
+        // A batch size of 1 executes every insert on its own
+        assert_eq!(
+            batches(&[address; 2], 1),
+            vec![vec![address], vec![address]]
+        );
+    }
+
+    #[test]
+    fn test_no_entries() {
+        assert!(batches(&[], 10).is_empty());
+    }
+
+    fn drainer_entry(deletable: kv::Deletable, pk_id: &str, sk_id: &str) -> DrainerEntry {
+        let typed_sql = kv::TypedSql {
+            op: kv::DBOperation::Delete {
+                deletable: Box::new(deletable),
+                pk_id: pk_id.to_string(),
+                sk_id: sk_id.to_string(),
+            },
+        };
+        let entry = typed_sql
+            .to_field_value_pairs("request_id".to_string(), "global_id".to_string())
+            .unwrap()
+            .into_iter()
+            .map(|(field, value)| (field.to_string(), value))
+            .collect::<HashMap<_, _>>();
+        let data = StreamData::from_hashmap(entry.clone()).unwrap();
+
+        DrainerEntry {
+            entry_id: "0-0".to_string(),
+            entry,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_mandate_delete_stream_entry() {
+        let merchant_id = id_type::MerchantId::wrap("merchant_1".to_string()).unwrap();
+        let entry = drainer_entry(
+            kv::Deletable::MandateDelete(kv::MandateDeleteMems {
+                merchant_id: merchant_id.clone(),
+                mandate_id: "man_1".to_string(),
+            }),
+            "mid_merchant_1_mandate_man_1",
+            "mandate_man_1",
+        );
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(entry.entry.get("typed_sql").unwrap())
+                .unwrap(),
+            serde_json::json!({
+                "db_op": "delete",
+                "data": {
+                    "deletable": {
+                        "table": "mandate_delete",
+                        "data": { "merchant_id": "merchant_1", "mandate_id": "man_1" },
+                    },
+                    "pk_id": "mid_merchant_1_mandate_man_1",
+                    "sk_id": "mandate_man_1",
+                },
+            })
+        );
+        assert_eq!(entry.data.typed_sql.operation(), "delete");
+        assert_eq!(entry.data.typed_sql.table(), "mandate");
+        match &entry.data.typed_sql {
+            kv::DBOperation::Delete {
+                deletable,
+                pk_id,
+                sk_id,
+            } => {
+                match deletable.as_ref() {
+                    kv::Deletable::MandateDelete(mandate) => {
+                        assert_eq!(mandate.merchant_id, merchant_id);
+                        assert_eq!(mandate.mandate_id, "man_1");
+                    }
+                    other => panic!("Unexpected deletable: {other:?}"),
+                }
+                assert_eq!(pk_id, "mid_merchant_1_mandate_man_1");
+                assert_eq!(sk_id, "mandate_man_1");
+            }
+            other => panic!("Unexpected operation: {other:?}"),
+        }
+        // Deletes are executed one by one, and never as part of an insert batch
+        assert_eq!(entry.insert_table(), None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_payment_method_delete_stream_entry() {
+        let entry = drainer_entry(
+            kv::Deletable::PaymentMethodDelete(kv::PaymentMethodDeleteMems {
+                merchant_id: id_type::MerchantId::wrap("merchant_1".to_string()).unwrap(),
+                payment_method_id: "pm_1".to_string(),
+            }),
+            "mid_merchant_1_cust_cus_1",
+            "payment_method_id_pm_1",
+        );
+
+        assert_eq!(entry.data.typed_sql.operation(), "delete");
+        assert_eq!(entry.data.typed_sql.table(), "payment_method");
+        assert_eq!(entry.insert_table(), None);
+        assert_eq!(
+            group_insert_batches(vec![entry], 10, DrainerEntry::insert_table).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_address_delete_stream_entry() {
+        let merchant_id = id_type::MerchantId::wrap("merchant_1".to_string()).unwrap();
+        let entry = drainer_entry(
+            kv::Deletable::AddressDelete(kv::AddressDeleteMems {
+                merchant_id: merchant_id.clone(),
+                address_id: "add_1".to_string(),
+            }),
+            "mid_merchant_1_pid_pay_1",
+            "add_add_1",
+        );
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(entry.entry.get("typed_sql").unwrap())
+                .unwrap(),
+            serde_json::json!({
+                "db_op": "delete",
+                "data": {
+                    "deletable": {
+                        "table": "address_delete",
+                        "data": { "merchant_id": "merchant_1", "address_id": "add_1" },
+                    },
+                    "pk_id": "mid_merchant_1_pid_pay_1",
+                    "sk_id": "add_add_1",
+                },
+            })
+        );
+        assert_eq!(entry.data.typed_sql.operation(), "delete");
+        assert_eq!(entry.data.typed_sql.table(), "address");
+        match &entry.data.typed_sql {
+            kv::DBOperation::Delete { deletable, .. } => match deletable.as_ref() {
+                kv::Deletable::AddressDelete(address) => {
+                    assert_eq!(address.merchant_id, merchant_id);
+                    assert_eq!(address.address_id, "add_1");
+                }
+                other => panic!("Unexpected deletable: {other:?}"),
+            },
+            other => panic!("Unexpected operation: {other:?}"),
+        }
+        assert_eq!(entry.insert_table(), None);
+    }
+}
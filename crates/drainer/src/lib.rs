@@ -1,4 +1,7 @@
 mod connection;
+#[cfg(feature = "v1")]
+mod consistency_check;
+mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -26,8 +29,13 @@ use tokio::sync::mpsc;
 
 pub(crate) type Settings = settings::Settings<RawSecret>;
 
+#[cfg(feature = "v1")]
+use crate::settings::ConsistencyCheckOptions;
 use crate::{
-    connection::pg_connection, services::Store, settings::DrainerSettings, types::StreamData,
+    connection::pg_connection,
+    services::Store,
+    settings::{DeadLetterAction, DrainerSettings},
+    types::StreamData,
 };
 
 pub async fn start_drainer(
@@ -60,6 +68,44 @@ pub async fn start_drainer(
     Ok(())
 }
 
+/// Performs the `dead-letter` subcommand action on the dead-letter stream of the tenant
+pub async fn run_dead_letter_command(
+    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    conf: DrainerSettings,
+    tenant: String,
+    action: DeadLetterAction,
+) -> errors::DrainerResult<()> {
+    let store = get_tenant_store(&stores, tenant)?;
+
+    dead_letter::perform_action(store, action, conf.max_read_count).await
+}
+
+/// Runs the `consistency-check` subcommand on the records of the tenant stored in redis
+#[cfg(feature = "v1")]
+pub async fn run_consistency_check(
+    stores: HashMap<id_type::TenantId, Arc<Store>>,
+    tenant: String,
+    options: ConsistencyCheckOptions,
+) -> errors::DrainerResult<()> {
+    let store = get_tenant_store(&stores, tenant)?;
+
+    consistency_check::perform_check(store, options).await
+}
+
+fn get_tenant_store(
+    stores: &HashMap<id_type::TenantId, Arc<Store>>,
+    tenant: String,
+) -> errors::DrainerResult<Arc<Store>> {
+    let tenant_id = id_type::TenantId::try_from_string(tenant.clone()).change_context(
+        errors::DrainerError::ConfigParsingError(format!("Invalid tenant id `{tenant}`")),
+    )?;
+
+    stores.get(&tenant_id).cloned().ok_or_else(|| {
+        errors::DrainerError::ConfigParsingError(format!("Tenant `{tenant}` is not configured"))
+            .into()
+    })
+}
+
 pub async fn start_web_server(
     conf: Settings,
     stores: HashMap<id_type::TenantId, Arc<Store>>,
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use drainer::{errors::DrainerResult, logger, services, settings, start_drainer, start_web_server};
+use drainer::{
+    errors::DrainerResult, logger, run_dead_letter_command, services, settings, start_drainer,
+    start_web_server,
+};
 use router_env::tracing::Instrument;
 
 #[tokio::main]
@@ -35,6 +38,17 @@ async fn main() -> DrainerResult<()> {
         [router_env::service_name!()],
     );
 
+    match cmd_line.command {
+        Some(settings::Command::DeadLetter { tenant, action }) => {
+            return run_dead_letter_command(stores, conf.drainer, tenant, action).await;
+        }
+        #[cfg(feature = "v1")]
+        Some(settings::Command::ConsistencyCheck { tenant, options }) => {
+            return drainer::run_consistency_check(stores, tenant, options).await;
+        }
+        None => {}
+    }
+
     #[allow(clippy::expect_used)]
     let web_server = Box::pin(start_web_server(
         state.conf.as_ref().clone(),
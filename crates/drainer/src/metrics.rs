@@ -12,6 +12,10 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_ADDED, DRAINER_METER);
+counter_metric!(ERRORS_WHILE_DEAD_LETTER_WRITE, DRAINER_METER);
+counter_metric!(CONSISTENCY_CHECK_RECORDS, DRAINER_METER);
+counter_metric!(BATCH_INSERT_FALLBACKS, DRAINER_METER);
 
 histogram_metric_f64!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_f64!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
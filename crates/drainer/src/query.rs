@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
+use async_bb8_diesel::AsyncConnection;
 use common_utils::errors::CustomResult;
 use diesel_models::errors::DatabaseError;
+use error_stack::report;
 
 use crate::{kv, logger, metrics, pg_connection, services::Store};
 
@@ -48,6 +50,55 @@ impl ExecuteQuery for kv::DBOperation {
     }
 }
 
+/// Inserts the records of a table using a single multi-row insert, executed in a transaction.
+/// `pushed_at` holds the time at which each of the records was pushed to the stream.
+pub(crate) async fn execute_batch_insert(
+    store: &Arc<Store>,
+    table: &str,
+    insertables: Vec<kv::Insertable>,
+    pushed_at: &[i64],
+) -> CustomResult<(), DatabaseError> {
+    let conn = pg_connection(&store.master_pool).await;
+    let operation = "batch_insert";
+    let rows = insertables.len();
+
+    let tags = router_env::metric_attributes!(("operation", operation), ("table", table));
+
+    let (result, execution_time) = Box::pin(common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                kv::Insertable::batch_insert(insertables, &conn)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Error while executing the batch insert");
+                        *error.current_context()
+                    })
+            })
+        })
+    }))
+    .await;
+
+    metrics::QUERY_EXECUTION_TIME.record(execution_time, tags);
+
+    match result {
+        Ok(()) => {
+            // The delay is recorded only when the batch succeeds, as the records of a failed
+            // batch are inserted again one by one
+            for pushed_at in pushed_at {
+                push_drainer_delay(*pushed_at, operation, table, tags);
+            }
+            logger::info!(operation = operation, table = table, rows);
+            metrics::SUCCESSFUL_QUERY_EXECUTION.add(u64::try_from(rows).unwrap_or(u64::MIN), tags);
+            Ok(())
+        }
+        Err(err) => {
+            logger::error!(operation = operation, table = table, rows, ?err);
+            metrics::ERRORS_WHILE_QUERY_EXECUTION.add(1, tags);
+            Err(report!(err))
+        }
+    }
+}
+
 #[inline(always)]
 fn push_drainer_delay(
     pushed_at: i64,
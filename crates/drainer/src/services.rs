@@ -21,6 +21,7 @@ pub struct Store {
 #[derive(Clone)]
 pub struct StoreConfig {
     pub drainer_stream_name: String,
+    pub drainer_dead_letter_stream_name: String,
     pub drainer_num_partitions: u8,
 }
 
@@ -44,6 +45,7 @@ impl Store {
             )),
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
+                drainer_dead_letter_stream_name: config.drainer.dead_letter_stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
             },
             request_id: None,
@@ -26,6 +26,70 @@ pub struct CmdLineConf {
     /// Application will look for "config/config.toml" if this option isn't specified.
     #[arg(short = 'f', long, value_name = "FILE")]
     pub config_path: Option<PathBuf>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(clap::Subcommand)]
+pub enum Command {
+    /// Inspect, retry or discard the operations which failed to be written to the database
+    DeadLetter {
+        /// Tenant whose dead-letter stream is operated on
+        #[arg(long, default_value = "public")]
+        tenant: String,
+        #[command(subcommand)]
+        action: DeadLetterAction,
+    },
+    /// Compare the payment intents, payment attempts and refunds stored in redis with the rows
+    /// written to the database, and optionally repair the rows which diverge
+    #[cfg(feature = "v1")]
+    ConsistencyCheck {
+        /// Tenant whose records are checked
+        #[arg(long, default_value = "public")]
+        tenant: String,
+        #[command(flatten)]
+        options: ConsistencyCheckOptions,
+    },
+}
+
+#[derive(clap::Subcommand)]
+pub enum DeadLetterAction {
+    /// Print the failed operations as JSON lines
+    List {
+        /// Number of operations to print
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+        /// Print the operations added after the entry with this id
+        #[arg(long)]
+        after: Option<String>,
+    },
+    /// Execute the failed operations again, removing the ones which succeed from the stream
+    Retry {
+        /// Ids of the dead-letter entries to retry
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+    /// Remove the failed operations from the stream without executing them
+    Discard {
+        /// Ids of the dead-letter entries to discard
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+}
+
+#[cfg(feature = "v1")]
+#[derive(clap::Args)]
+pub struct ConsistencyCheckOptions {
+    /// Number of payment keys to sample from redis
+    #[arg(long, default_value_t = 100)]
+    pub sample_size: usize,
+    /// Records modified in redis within these many seconds are skipped, as the drainer may not
+    /// have written them to the database yet
+    #[arg(long, default_value_t = 60)]
+    pub min_age_seconds: i64,
+    /// Write the redis record to the database when the database row diverges or is missing
+    #[arg(long)]
+    pub repair: bool,
 }
 
 #[derive(Clone)]
@@ -110,8 +174,10 @@ impl DbConnectionParams for Database {
 #[serde(default)]
 pub struct DrainerSettings {
     pub stream_name: String,
+    pub dead_letter_stream_name: String,
     pub num_partitions: u8,
     pub max_read_count: u64,
+    pub max_insert_batch_size: u16,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
 }
@@ -220,8 +286,10 @@ impl Default for DrainerSettings {
     fn default() -> Self {
         Self {
             stream_name: "DRAINER_STREAM".into(),
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
             num_partitions: 64,
             max_read_count: 100,
+            max_insert_batch_size: 50,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
         }
@@ -270,10 +338,24 @@ impl Database {
 
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead-letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_insert_batch_size == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max insert batch size must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -2,10 +2,10 @@ use std::sync::{atomic, Arc};
 
 use error_stack::report;
 use redis_interface as redis;
-use serde::de::Deserialize;
+use serde::{de::Deserialize, Serialize};
 
 use crate::{
-    errors, kv, metrics,
+    errors, kv, logger, metrics,
     stream::{StreamEntries, StreamReadResult},
 };
 
@@ -72,3 +72,13 @@ pub async fn increment_stream_index(
         index + 1
     }
 }
+
+/// Prints the value as a JSON line, as the output of the subcommands is meant to be read by the
+/// operator, and not logged
+#[allow(clippy::print_stdout)]
+pub(crate) fn print_json_line(value: &impl Serialize) {
+    match serde_json::to_string(value) {
+        Ok(line) => println!("{line}"),
+        Err(error) => logger::error!(?error, "Failed to serialize the subcommand output"),
+    }
+}
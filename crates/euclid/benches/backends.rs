@@ -59,6 +59,7 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             payment_type: None,
         },
         issuer_data: None,
+        dispute_data: None,
         acquirer_data: None,
         customer_device_data: None,
     };
@@ -52,6 +52,12 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeDataInput {
+    pub reason_code: Option<String>,
+    pub connector: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -60,5 +66,6 @@ pub struct BackendInput {
     pub acquirer_data: Option<AcquirerDataInput>,
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
+    pub dispute_data: Option<DisputeDataInput>,
     pub mandate: MandateData,
 }
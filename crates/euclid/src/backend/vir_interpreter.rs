@@ -156,6 +156,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -199,6 +200,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -243,6 +245,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -287,6 +290,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -331,6 +335,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -375,6 +380,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -419,6 +425,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -463,6 +470,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -507,6 +515,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -553,6 +562,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -597,6 +607,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -650,6 +661,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            dispute_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +677,59 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_dispute_data() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           dispute_reason_code = "4853" & dispute_connector = "stripe"
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(1000),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: None,
+                capture_method: None,
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: None,
+                payment_method_type: None,
+                card_network: Some(enums::CardNetwork::Mastercard),
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            dispute_data: Some(inputs::DisputeDataInput {
+                reason_code: Some("4853".to_string()),
+                connector: Some("stripe".to_string()),
+            }),
+        };
+        let mut inp_other_reason = inp.clone();
+        inp_other_reason.dispute_data = Some(inputs::DisputeDataInput {
+            reason_code: Some("4837".to_string()),
+            connector: Some("stripe".to_string()),
+        });
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result = backend.execute(inp).expect("Execution");
+        let result_other_reason = backend.execute(inp_other_reason).expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+        assert!(result_other_reason.rule_name.is_none());
+    }
 }
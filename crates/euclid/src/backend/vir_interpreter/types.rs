@@ -57,6 +57,7 @@ impl Context {
         let acquirer_data = input.acquirer_data;
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
+        let dispute_data = input.dispute_data;
         let payment_mandate = input.mandate;
 
         let mut enum_values: FxHashSet<EuclidValue> =
@@ -143,6 +144,18 @@ impl Context {
             }
         }
 
+        // Handle dispute data
+        if let Some(dispute) = dispute_data {
+            if let Some(reason_code) = dispute.reason_code {
+                enum_values.insert(EuclidValue::DisputeReasonCode(StrValue {
+                    value: reason_code,
+                }));
+            }
+            if let Some(connector) = dispute.connector {
+                enum_values.insert(EuclidValue::DisputeConnector(StrValue { value: connector }));
+            }
+        }
+
         let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
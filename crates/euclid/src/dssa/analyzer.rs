@@ -4,12 +4,12 @@
 //! in the Euclid Rule DSL. These include standard control flow analyses like testing
 //! conflicting assertions, to Domain Specific Analyses making use of the
 //! [`Knowledge Graph Framework`](crate::dssa::graph).
-use hyperswitch_constraint_graph::{ConstraintGraph, Memoization};
+use hyperswitch_constraint_graph::{ConstraintGraph, CycleCheck, Memoization};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     dssa::{
-        graph::CgraphExt,
+        graph::{self, CgraphExt},
         state_machine, truth,
         types::{self, EuclidAnalysable},
     },
@@ -220,10 +220,208 @@ fn perform_context_analyses(
     Ok(())
 }
 
+/// A single way through a rule, i.e. the conjunction of all the comparisons from a top level
+/// `if` statement down to one of its innermost nested statements.
+type RulePath<'a> = Vec<&'a dir::DirComparison>;
+
+fn collect_rule_paths<'a>(
+    statements: &'a [dir::DirIfStatement],
+    prefix: &mut RulePath<'a>,
+    paths: &mut Vec<RulePath<'a>>,
+) {
+    for statement in statements {
+        let prefix_len = prefix.len();
+        prefix.extend(statement.condition.iter());
+
+        match &statement.nested {
+            // An empty nested block never evaluates to `true`, so it does not contribute a path
+            Some(nested) => collect_rule_paths(nested, prefix, paths),
+            None => paths.push(prefix.clone()),
+        }
+
+        prefix.truncate(prefix_len);
+    }
+}
+
+fn value_implies(value: &dir::DirValue, implied: &dir::DirValue) -> bool {
+    match (value.get_num_value(), implied.get_num_value()) {
+        (Some(num_value), Some(implied_num_value)) => implied_num_value.fits(&num_value),
+        _ => value == implied,
+    }
+}
+
+/// Checks whether every input satisfying `comparison` also satisfies `implied`.
+///
+/// The check is conservative, it returns `false` whenever the implication cannot be established
+/// with certainty.
+fn comparison_implies(comparison: &dir::DirComparison, implied: &dir::DirComparison) -> bool {
+    let (Some(first), Some(implied_first)) = (comparison.values.first(), implied.values.first())
+    else {
+        return false;
+    };
+
+    if first.get_key() != implied_first.get_key() {
+        return false;
+    }
+
+    match (&comparison.logic, &implied.logic) {
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => comparison.values.iter().all(|value| {
+            implied
+                .values
+                .iter()
+                .any(|implied_value| value_implies(value, implied_value))
+        }),
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => implied
+            .values
+            .iter()
+            .all(|implied_value| comparison.values.contains(implied_value)),
+        (
+            dir::DirComparisonLogic::PositiveDisjunction,
+            dir::DirComparisonLogic::NegativeConjunction,
+        ) => comparison.values.iter().all(|value| {
+            value
+                .get_num_value()
+                .and_then(|num_value| num_value.refinement)
+                .is_none()
+                && !implied.values.contains(value)
+        }),
+        (
+            dir::DirComparisonLogic::NegativeConjunction,
+            dir::DirComparisonLogic::PositiveDisjunction,
+        ) => first
+            .get_key()
+            .kind
+            .get_value_set()
+            .is_some_and(|value_set| {
+                value_set
+                    .iter()
+                    .filter(|value| !comparison.values.contains(value))
+                    .all(|value| implied.values.contains(value))
+            }),
+    }
+}
+
+/// Checks whether every input that takes `path` through a rule is also matched by `covering_path`.
+fn path_covers(covering_path: &RulePath<'_>, path: &RulePath<'_>) -> bool {
+    covering_path.iter().all(|covering_comparison| {
+        path.iter()
+            .any(|comparison| comparison_implies(comparison, covering_comparison))
+    })
+}
+
+/// Analyses whether each rule in the program can ever be selected.
+///
+/// A rule is reported as unreachable when none of its statements can evaluate to `true`, and as
+/// shadowed when every input it matches is already matched by one of the rules preceding it,
+/// since rules are evaluated in order and the first matching rule wins.
+pub fn analyze_rule_reachability<O>(program: &dir::DirProgram<O>) -> Vec<types::AnalysisWarning> {
+    let mut warnings = Vec::new();
+    let mut preceding_paths: Vec<(&str, Vec<RulePath<'_>>)> = Vec::new();
+
+    for rule in &program.rules {
+        let mut paths = Vec::new();
+        collect_rule_paths(&rule.statements, &mut Vec::new(), &mut paths);
+
+        if paths.is_empty() {
+            warnings.push(types::AnalysisWarning::UnreachableRule {
+                rule_name: rule.name.clone(),
+            });
+        } else {
+            let mut shadowed_by: Vec<String> = Vec::new();
+
+            let is_shadowed = paths.iter().all(|path| {
+                let covering_rule = preceding_paths.iter().find(|(_, covering_paths)| {
+                    covering_paths
+                        .iter()
+                        .any(|covering_path| path_covers(covering_path, path))
+                });
+
+                if let Some((rule_name, _)) = covering_rule {
+                    if !shadowed_by.iter().any(|name| name.as_str() == *rule_name) {
+                        shadowed_by.push((*rule_name).to_string());
+                    }
+                }
+
+                covering_rule.is_some()
+            });
+
+            if is_shadowed {
+                warnings.push(types::AnalysisWarning::ShadowedRule {
+                    rule_name: rule.name.clone(),
+                    shadowed_by,
+                });
+            }
+        }
+
+        preceding_paths.push((rule.name.as_str(), paths));
+    }
+
+    warnings
+}
+
+/// Analyses whether the connectors selected by each rule are valid as per the knowledge graph,
+/// for example when a connector is not configured for the merchant.
+pub fn analyze_connector_selections<O: EuclidAnalysable>(
+    program: &dir::DirProgram<O>,
+    knowledge_graph: &ConstraintGraph<dir::DirValue>,
+) -> Vec<types::AnalysisWarning> {
+    let analysis_ctx = graph::AnalysisContext::from_dir_values([]);
+    let mut memo = Memoization::new();
+
+    program
+        .rules
+        .iter()
+        .flat_map(|rule| {
+            rule.connector_selection
+                .get_dir_value_for_analysis(rule.name.clone())
+                .into_iter()
+                .map(move |(value, metadata)| (rule.name.clone(), value, metadata))
+        })
+        .filter_map(|(rule_name, value, metadata)| {
+            let dir::DirValue::Connector(choice) = &value else {
+                return None;
+            };
+            let connector = choice.connector;
+
+            let is_valid = knowledge_graph
+                .check_value_validity(
+                    value,
+                    &analysis_ctx,
+                    &mut memo,
+                    &mut CycleCheck::new(),
+                    None,
+                )
+                .unwrap_or(false);
+
+            (!is_valid).then_some(types::AnalysisWarning::InvalidConnectorSelection {
+                rule_name,
+                connector,
+                metadata,
+            })
+        })
+        .collect()
+}
+
 pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
     program: ast::Program<O>,
     knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
 ) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
+    analyze_with_warnings(program, knowledge_graph).map(|(program, _)| program)
+}
+
+/// Performs the same analyses as [`analyze`], additionally returning the non fatal findings
+/// about rules that can never be selected or select connectors that are not valid as per the
+/// knowledge graph.
+pub fn analyze_with_warnings<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&ConstraintGraph<dir::DirValue>>,
+) -> Result<(vir::ValuedProgram<O>, Vec<types::AnalysisWarning>), types::AnalysisError> {
     let dir_program = ast::lowering::lower_program(program)?;
 
     let selection_data = state_machine::make_connector_selection_data(&dir_program);
@@ -235,7 +433,14 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
         perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
     }
 
-    dir::lowering::lower_program(dir_program)
+    let mut warnings = analyze_rule_reachability(&dir_program);
+    // The default analysis graph has no knowledge of connectors, so connector selections can
+    // only be validated against a merchant specific knowledge graph
+    if let Some(graph) = knowledge_graph {
+        warnings.extend(analyze_connector_selections(&dir_program, graph));
+    }
+
+    dir::lowering::lower_program(dir_program).map(|program| (program, warnings))
 }
 
 #[cfg(all(test, feature = "ast_parser"))]
@@ -456,4 +661,108 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = (card, wallet)
+                amount > 500
+            }
+
+            rule_2: ["checkout"]
+            {
+                payment_method = card & capture_method = manual
+                amount > 1000 & payment_method = pay_later
+            }
+
+            rule_3: ["stripe"]
+            {
+                payment_method = card & capture_method = automatic
+                capture_method = manual
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (_, warnings) = analyze_with_warnings(program, None).expect("Analysis");
+
+        assert_eq!(warnings.len(), 1, "Only rule_2 should be shadowed");
+        match warnings.first() {
+            Some(types::AnalysisWarning::ShadowedRule {
+                rule_name,
+                shadowed_by,
+            }) => {
+                assert_eq!(rule_name, "rule_2");
+                assert_eq!(shadowed_by, &vec!["rule_1".to_string()]);
+            }
+            _ => panic!("Expected rule_2 to be shadowed by rule_1"),
+        }
+    }
+
+    #[test]
+    fn test_shadowed_rule_detection_with_negations() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method /= card
+            }
+
+            rule_2: ["checkout"]
+            {
+                payment_method = wallet & capture_method = manual
+            }
+
+            rule_3: ["stripe"]
+            {
+                payment_method /= (card, wallet)
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let shadowed_rules: Vec<String> = analyze_rule_reachability(&dir_program)
+            .into_iter()
+            .filter_map(|warning| match warning {
+                types::AnalysisWarning::ShadowedRule { rule_name, .. } => Some(rule_name),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(shadowed_rules, vec!["rule_2", "rule_3"]);
+    }
+
+    #[test]
+    fn test_unreachable_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card {
+                }
+            }
+
+            rule_2: ["checkout"]
+            {
+                payment_method = card {
+                    capture_method = manual
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let dir_program = ast::lowering::lower_program(program).expect("Lowering");
+        let warnings = analyze_rule_reachability(&dir_program);
+
+        assert_eq!(warnings.len(), 1, "Only rule_1 should be unreachable");
+        assert!(matches!(
+            warnings.first(),
+            Some(types::AnalysisWarning::UnreachableRule { rule_name }) if rule_name == "rule_1"
+        ));
+    }
 }
@@ -154,6 +154,30 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+/// Non fatal findings of the static analysis. Unlike [`AnalysisError`], these do not make the
+/// program invalid but usually point at rules that will not behave the way they were intended to.
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarning {
+    #[error("Rule '{rule_name}' can never be selected as none of its conditions can be satisfied")]
+    UnreachableRule { rule_name: String },
+    #[error(
+        "Rule '{rule_name}' can never be selected as it is shadowed by the rules {shadowed_by:?}"
+    )]
+    ShadowedRule {
+        rule_name: String,
+        shadowed_by: Vec<String>,
+    },
+    #[error(
+        "Connector '{connector}' selected in rule '{rule_name}' is not valid for the merchant"
+    )]
+    InvalidConnectorSelection {
+        rule_name: String,
+        connector: common_enums::RoutableConnectors,
+        metadata: Metadata,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
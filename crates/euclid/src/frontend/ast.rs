@@ -1,6 +1,7 @@
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
+pub mod printer;
 
 use common_enums::RoutableConnectors;
 use common_utils::types::MinorUnit;
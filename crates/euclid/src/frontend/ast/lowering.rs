@@ -275,6 +275,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::DisputeReasonCode => lower_str!(DisputeReasonCode, value),
+        dir::DirKeyKind::DisputeConnector => lower_str!(DisputeConnector, value),
     }
 }
 
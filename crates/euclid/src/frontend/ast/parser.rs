@@ -1,4 +1,7 @@
-use common_utils::types::MinorUnit;
+use std::str::FromStr;
+
+use common_enums::RoutableConnectors;
+use common_utils::{id_type, types::MinorUnit};
 use nom::{
     branch, bytes::complete, character::complete as pchar, combinator, error, multi, sequence,
 };
@@ -11,6 +14,7 @@ pub enum EuclidError {
     InvalidConnector(String),
     InvalidOperator(String),
     InvalidNumber(String),
+    InvalidMerchantConnectorId(String),
 }
 
 pub trait EuclidParsable: Sized {
@@ -51,6 +55,80 @@ impl EuclidParsable for DummyOutput {
         )(input)
     }
 }
+
+impl EuclidParsable for ast::ConnectorSelection {
+    fn parse_output(input: &str) -> ParseResult<&str, Self> {
+        let priority = combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("[")),
+                multi::separated_list1(skip_ws(complete::tag(",")), skip_ws(connector_choice)),
+                skip_ws(complete::tag("]")),
+            ),
+            Self::Priority,
+        );
+
+        let volume_split = sequence::separated_pair(
+            skip_ws(connector_choice),
+            skip_ws(complete::tag(":")),
+            skip_ws(percentage),
+        );
+        let volume_splits = combinator::map(
+            sequence::delimited(
+                skip_ws(complete::tag("{")),
+                multi::separated_list1(skip_ws(complete::tag(",")), volume_split),
+                skip_ws(complete::tag("}")),
+            ),
+            |splits: Vec<(ast::RoutableConnectorChoice, u8)>| {
+                Self::VolumeSplit(
+                    splits
+                        .into_iter()
+                        .map(|(connector, split)| ast::ConnectorVolumeSplit { connector, split })
+                        .collect(),
+                )
+            },
+        );
+
+        error::context(
+            "connector_selection",
+            branch::alt((priority, volume_splits)),
+        )(input)
+    }
+}
+
+/// Parses a connector optionally followed by its merchant connector id, as in `stripe:mca_123`
+pub fn connector_choice(input: &str) -> ParseResult<&str, ast::RoutableConnectorChoice> {
+    let connector = combinator::map_res(enum_value_string, |connector: String| {
+        RoutableConnectors::from_str(&connector)
+            .map_err(|_| EuclidError::InvalidConnector(connector))
+    });
+    let merchant_connector_id = combinator::map_res(
+        sequence::preceded(
+            complete::tag(":"),
+            complete::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-'),
+        ),
+        |id: &str| {
+            id_type::MerchantConnectorAccountId::wrap(id.to_string())
+                .map_err(|_| EuclidError::InvalidMerchantConnectorId(id.to_string()))
+        },
+    );
+
+    error::context(
+        "connector_choice",
+        combinator::map(
+            sequence::pair(connector, combinator::opt(merchant_connector_id)),
+            |(connector, merchant_connector_id)| ast::RoutableConnectorChoice {
+                choice_kind: if merchant_connector_id.is_some() {
+                    ast::RoutableChoiceKind::FullStruct
+                } else {
+                    ast::RoutableChoiceKind::OnlyConnector
+                },
+                connector,
+                merchant_connector_id,
+            },
+        ),
+    )(input)
+}
+
 pub fn skip_ws<'a, F, O>(inner: F) -> impl FnMut(&'a str) -> ParseResult<&'a str, O>
 where
     F: FnMut(&'a str) -> ParseResult<&'a str, O> + 'a,
@@ -71,16 +149,22 @@ pub fn num_i64(input: &str) -> ParseResult<&str, i64> {
     )(input)
 }
 
+/// Parses a non-empty double quoted string, in which `\"` and `\\` escape a quote and a backslash
 pub fn string_str(input: &str) -> ParseResult<&str, String> {
+    let escaped = complete::escaped_transform(
+        complete::is_not("\"\\"),
+        '\\',
+        branch::alt((
+            combinator::value("\\", complete::tag("\\")),
+            combinator::value("\"", complete::tag("\"")),
+        )),
+    );
+
     error::context(
         "String",
-        combinator::map(
-            sequence::delimited(
-                complete::tag("\""),
-                complete::take_while1(|c: char| c != '"'),
-                complete::tag("\""),
-            ),
-            |val: &str| val.to_string(),
+        combinator::verify(
+            sequence::delimited(complete::tag("\""), escaped, complete::tag("\"")),
+            |val: &String| !val.is_empty(),
         ),
     )(input)
 }
@@ -102,12 +186,14 @@ pub fn percentage(input: &str) -> ParseResult<&str, u8> {
         "volume_split_percentage",
         combinator::map_res(
             sequence::terminated(
-                complete::take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+                complete::take_while_m_n(1, 3, |c: char| c.is_ascii_digit()),
                 complete::tag("%"),
             ),
             |o: &str| {
                 o.parse::<u8>()
-                    .map_err(|_| EuclidError::InvalidPercentage(o.to_string()))
+                    .ok()
+                    .filter(|percentage| *percentage <= 100)
+                    .ok_or_else(|| EuclidError::InvalidPercentage(o.to_string()))
             },
         ),
     )(input)
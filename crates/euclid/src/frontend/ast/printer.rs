@@ -0,0 +1,508 @@
+//! Renders a [`ast::Program`] back into the textual DSL understood by the parser.
+//!
+//! The output is canonical: the same AST always produces the same text, with one `if`
+//! statement per line and four spaces of indentation per nesting level, which keeps the
+//! rendered programs stable and easy to diff. Frontend metadata attached to the program or
+//! to individual comparisons has no textual representation and is not rendered.
+//!
+//! Printing a program accepted by the parser and parsing the output gives back the same program.
+//! Programs built outside the parser may hold constructs the grammar does not accept, such as
+//! `=` and `/=` within number comparison arrays or metadata comparisons after the first
+//! comparison of a condition, which are rendered as is.
+
+use crate::{frontend::ast, types::DummyOutput};
+
+const INDENT: &str = "    ";
+
+pub trait EuclidPrintable {
+    fn print_output(&self) -> String;
+}
+
+impl EuclidPrintable for DummyOutput {
+    fn print_output(&self) -> String {
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| format!("\"{output}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("[{outputs}]")
+    }
+}
+
+impl EuclidPrintable for ast::ConnectorSelection {
+    fn print_output(&self) -> String {
+        match self {
+            Self::Priority(connectors) => {
+                let connectors = connectors
+                    .iter()
+                    .map(routable_connector_choice)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("[{connectors}]")
+            }
+            Self::VolumeSplit(splits) => {
+                let splits = splits
+                    .iter()
+                    .map(|split| {
+                        format!(
+                            "{}: {}%",
+                            routable_connector_choice(&split.connector),
+                            split.split
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!("{{{splits}}}")
+            }
+        }
+    }
+}
+
+fn routable_connector_choice(choice: &ast::RoutableConnectorChoice) -> String {
+    match &choice.merchant_connector_id {
+        Some(mca_id) => format!("{}:{}", choice.connector, mca_id.get_string_repr()),
+        None => choice.connector.to_string(),
+    }
+}
+
+pub fn comparison_type(comparison_type: &ast::ComparisonType) -> &'static str {
+    match comparison_type {
+        ast::ComparisonType::Equal => "=",
+        ast::ComparisonType::NotEqual => "/=",
+        ast::ComparisonType::LessThan => "<",
+        ast::ComparisonType::LessThanEqual => "<=",
+        ast::ComparisonType::GreaterThan => ">",
+        ast::ComparisonType::GreaterThanEqual => ">=",
+    }
+}
+
+/// Quotes the string, escaping the quotes and backslashes in it as expected by the parser
+fn string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn list<T>(items: &[T], print_item: impl Fn(&T) -> String) -> String {
+    let items = items.iter().map(print_item).collect::<Vec<_>>().join(", ");
+    format!("({items})")
+}
+
+pub fn value_type(value: &ast::ValueType) -> String {
+    match value {
+        ast::ValueType::Number(number) => number.to_string(),
+        ast::ValueType::EnumVariant(variant) => variant.clone(),
+        ast::ValueType::MetadataVariant(metadata) => string(&metadata.value),
+        ast::ValueType::StrValue(value) => string(value),
+        ast::ValueType::NumberArray(numbers) => list(numbers, ToString::to_string),
+        ast::ValueType::EnumVariantArray(variants) => list(variants, Clone::clone),
+        ast::ValueType::NumberComparisonArray(comparisons) => list(comparisons, |comparison| {
+            format!(
+                "{}{}",
+                comparison_type(&comparison.comparison_type),
+                comparison.number
+            )
+        }),
+    }
+}
+
+pub fn comparison(comparison: &ast::Comparison) -> String {
+    let lhs = match &comparison.value {
+        ast::ValueType::MetadataVariant(metadata) => string(&metadata.key),
+        _ => comparison.lhs.clone(),
+    };
+
+    format!(
+        "{lhs} {} {}",
+        comparison_type(&comparison.comparison),
+        value_type(&comparison.value)
+    )
+}
+
+pub fn if_statement(statement: &ast::IfStatement, depth: usize) -> String {
+    let indent = INDENT.repeat(depth);
+    let condition = statement
+        .condition
+        .iter()
+        .map(comparison)
+        .collect::<Vec<_>>()
+        .join(" & ");
+
+    match &statement.nested {
+        Some(nested) => {
+            let nested = nested
+                .iter()
+                .map(|statement| if_statement(statement, depth + 1))
+                .collect::<String>();
+
+            format!("{indent}{condition} {{\n{nested}{indent}}}\n")
+        }
+        None => format!("{indent}{condition}\n"),
+    }
+}
+
+pub fn rule<O: EuclidPrintable>(rule: &ast::Rule<O>) -> String {
+    let statements = rule
+        .statements
+        .iter()
+        .map(|statement| if_statement(statement, 1))
+        .collect::<String>();
+
+    format!(
+        "{}: {}\n{{\n{statements}}}\n",
+        rule.name,
+        rule.connector_selection.print_output()
+    )
+}
+
+pub fn program<O: EuclidPrintable>(program: &ast::Program<O>) -> String {
+    let rules = program
+        .rules
+        .iter()
+        .map(|rule| format!("\n{}", self::rule(rule)))
+        .collect::<String>();
+
+    format!(
+        "default: {}\n{rules}",
+        program.default_selection.print_output()
+    )
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::frontend::ast::parser;
+
+    fn assert_round_trip(program_str: &str) {
+        let (_, parsed) = parser::program::<DummyOutput>(program_str).expect("Program");
+        let printed = program(&parsed);
+
+        let (remaining, reparsed) =
+            parser::program::<DummyOutput>(&printed).expect("Printed program");
+        assert!(
+            remaining.trim().is_empty(),
+            "Printed program was not fully consumed: {remaining}"
+        );
+
+        assert_eq!(
+            serde_json::to_value(&parsed.rules).expect("Rules"),
+            serde_json::to_value(&reparsed.rules).expect("Rules"),
+        );
+        assert_eq!(
+            parsed.default_selection.outputs,
+            reparsed.default_selection.outputs
+        );
+        assert_eq!(printed, program(&reparsed), "Printing is not idempotent");
+    }
+
+    #[test]
+    fn test_canonical_output() {
+        let program_str = r#"
+            default: ["stripe",   "adyen"]
+            rule_1: ["stripe"]{ payment_method = card&amount>100 {card_type = (credit,debit)} }
+        "#;
+
+        let (_, parsed) = parser::program::<DummyOutput>(program_str).expect("Program");
+
+        let expected = r#"default: ["stripe", "adyen"]
+
+rule_1: ["stripe"]
+{
+    payment_method = card & amount > 100 {
+        card_type = (credit, debit)
+    }
+}
+"#;
+
+        assert_eq!(program(&parsed), expected);
+    }
+
+    #[test]
+    fn test_round_trip_value_types() {
+        assert_round_trip(
+            r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen", "checkout"]
+            {
+                amount = 500
+                amount /= 200 & currency = USD
+                amount = (100, 200, 300)
+                card_type = (credit, debit)
+                amount = (>100, <=1000)
+                amount = (>=10, <40)
+                card_bin = "424242"
+                card_holder_name = "quote \" and backslash \\"
+                "key" = "value" & payment_method = card
+                "other_key" /= "other \"value\""
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn test_round_trip_nested_statements() {
+        assert_round_trip(
+            r#"
+            default: ["stripe"]
+
+            rule_1: ["adyen"]
+            {
+                payment_method = card {
+                    card_type = credit {
+                        card_network = (visa, mastercard)
+                        amount >= 1000
+                    }
+                    card_type = debit {
+                    }
+                }
+                payment_method = pay_later & pay_later = klarna
+            }
+
+            rule_2: ["checkout"]
+            {
+                capture_method = manual
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        let statement = ast::IfStatement {
+            condition: vec![ast::Comparison {
+                lhs: "card_holder_name".to_string(),
+                comparison: ast::ComparisonType::Equal,
+                value: ast::ValueType::StrValue(r#"a "b" \c"#.to_string()),
+                metadata: std::collections::HashMap::new(),
+            }],
+            nested: None,
+        };
+
+        let printed = if_statement(&statement, 0);
+        assert_eq!(printed, "card_holder_name = \"a \\\"b\\\" \\\\c\"\n");
+
+        let (_, parsed) = parser::if_statement(&printed).expect("If statement");
+        assert_eq!(
+            serde_json::to_value(&parsed).expect("Statement"),
+            serde_json::to_value(&statement).expect("Statement"),
+        );
+    }
+
+    #[test]
+    fn test_round_trip_connector_selection() {
+        let program_str = r#"
+            default: [stripe, adyen:mca_123]
+
+            rule_1: {stripe: 70%, adyen:mca_123: 30%}
+            {
+                payment_method = card
+            }
+
+            rule_2: {checkout: 100%}
+            {
+                amount > 100
+            }
+            "#;
+
+        let (_, parsed) = parser::program::<ast::ConnectorSelection>(program_str).expect("Program");
+        let printed = program(&parsed);
+        assert_eq!(
+            printed,
+            r#"default: [stripe, adyen:mca_123]
+
+rule_1: {stripe: 70%, adyen:mca_123: 30%}
+{
+    payment_method = card
+}
+
+rule_2: {checkout: 100%}
+{
+    amount > 100
+}
+"#
+        );
+
+        let (_, reparsed) =
+            parser::program::<ast::ConnectorSelection>(&printed).expect("Printed program");
+        assert_eq!(
+            serde_json::to_value(&parsed).expect("Program"),
+            serde_json::to_value(&reparsed).expect("Program"),
+        );
+    }
+
+    mod proptests {
+        use common_enums::RoutableConnectors;
+        use common_utils::{id_type, types::MinorUnit};
+        use proptest::{collection::vec, option, prelude::*, sample};
+        use strum::IntoEnumIterator;
+
+        use super::*;
+
+        fn connector_choice() -> impl Strategy<Value = ast::RoutableConnectorChoice> {
+            (
+                sample::select(RoutableConnectors::iter().collect::<Vec<_>>()),
+                option::of("[a-z0-9_-]{1,16}"),
+            )
+                .prop_map(|(connector, merchant_connector_id)| {
+                    ast::RoutableConnectorChoice {
+                        choice_kind: ast::RoutableChoiceKind::FullStruct,
+                        connector,
+                        merchant_connector_id: merchant_connector_id.map(|id| {
+                            id_type::MerchantConnectorAccountId::wrap(id).expect("Valid id")
+                        }),
+                    }
+                })
+        }
+
+        fn connector_selection() -> impl Strategy<Value = ast::ConnectorSelection> {
+            prop_oneof![
+                vec(connector_choice(), 1..4).prop_map(ast::ConnectorSelection::Priority),
+                vec((connector_choice(), 0..=100u8), 1..4).prop_map(|splits| {
+                    ast::ConnectorSelection::VolumeSplit(
+                        splits
+                            .into_iter()
+                            .map(|(connector, split)| ast::ConnectorVolumeSplit {
+                                connector,
+                                split,
+                            })
+                            .collect(),
+                    )
+                }),
+            ]
+        }
+
+        fn number() -> impl Strategy<Value = MinorUnit> {
+            (0..i64::MAX).prop_map(MinorUnit::new)
+        }
+
+        fn comparison_type() -> impl Strategy<Value = ast::ComparisonType> {
+            prop_oneof![
+                Just(ast::ComparisonType::Equal),
+                Just(ast::ComparisonType::NotEqual),
+                Just(ast::ComparisonType::LessThan),
+                Just(ast::ComparisonType::LessThanEqual),
+                Just(ast::ComparisonType::GreaterThan),
+                Just(ast::ComparisonType::GreaterThanEqual),
+            ]
+        }
+
+        fn value_type() -> impl Strategy<Value = ast::ValueType> {
+            let identifier = "[a-z_][a-z0-9_]{0,12}";
+            // The parser only accepts ordering operators within number comparison arrays
+            let number_comparison = (
+                prop_oneof![
+                    Just(ast::ComparisonType::LessThan),
+                    Just(ast::ComparisonType::LessThanEqual),
+                    Just(ast::ComparisonType::GreaterThan),
+                    Just(ast::ComparisonType::GreaterThanEqual),
+                ],
+                number(),
+            )
+                .prop_map(|(comparison_type, number)| ast::NumberComparison {
+                    comparison_type,
+                    number,
+                });
+
+            prop_oneof![
+                number().prop_map(ast::ValueType::Number),
+                identifier.prop_map(ast::ValueType::EnumVariant),
+                "\\PC{1,12}".prop_map(ast::ValueType::StrValue),
+                vec(number(), 1..4).prop_map(ast::ValueType::NumberArray),
+                vec(identifier, 1..4).prop_map(ast::ValueType::EnumVariantArray),
+                vec(number_comparison, 1..4).prop_map(ast::ValueType::NumberComparisonArray),
+            ]
+        }
+
+        fn comparison() -> impl Strategy<Value = ast::Comparison> {
+            ("[a-z_][a-z_.]{0,12}", comparison_type(), value_type()).prop_map(
+                |(lhs, comparison, value)| ast::Comparison {
+                    lhs,
+                    comparison,
+                    value,
+                    metadata: std::collections::HashMap::new(),
+                },
+            )
+        }
+
+        fn metadata_comparison() -> impl Strategy<Value = ast::Comparison> {
+            ("\\PC{1,12}", comparison_type(), "\\PC{1,12}").prop_map(|(key, comparison, value)| {
+                ast::Comparison {
+                    lhs: "metadata".to_string(),
+                    comparison,
+                    value: ast::ValueType::MetadataVariant(ast::MetadataValue { key, value }),
+                    metadata: std::collections::HashMap::new(),
+                }
+            })
+        }
+
+        fn condition() -> impl Strategy<Value = ast::IfCondition> {
+            // Metadata comparisons are only accepted as the first comparison of a condition
+            (
+                prop_oneof![comparison(), metadata_comparison()],
+                vec(comparison(), 0..3),
+            )
+                .prop_map(|(first, mut rest)| {
+                    rest.insert(0, first);
+                    rest
+                })
+        }
+
+        fn if_statement() -> impl Strategy<Value = ast::IfStatement> {
+            let leaf = (condition(), any::<bool>()).prop_map(|(condition, empty_block)| {
+                ast::IfStatement {
+                    condition,
+                    nested: empty_block.then(Vec::new),
+                }
+            });
+
+            leaf.prop_recursive(3, 16, 3, |inner| {
+                (condition(), vec(inner, 0..3)).prop_map(|(condition, nested)| ast::IfStatement {
+                    condition,
+                    nested: Some(nested),
+                })
+            })
+        }
+
+        fn program() -> impl Strategy<Value = ast::Program<ast::ConnectorSelection>> {
+            let rule = (
+                "[a-z_][a-z0-9_]{0,12}",
+                connector_selection(),
+                vec(if_statement(), 1..4),
+            )
+                .prop_map(|(name, connector_selection, statements)| ast::Rule {
+                    name,
+                    connector_selection,
+                    statements,
+                });
+
+            (connector_selection(), vec(rule, 1..4)).prop_map(|(default_selection, rules)| {
+                ast::Program {
+                    default_selection,
+                    rules,
+                    metadata: std::collections::HashMap::new(),
+                }
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn proptest_print_parse_round_trip(program in program()) {
+                let printed = super::program(&program);
+                let (remaining, parsed) =
+                    parser::program::<ast::ConnectorSelection>(&printed).expect("Printed program");
+
+                prop_assert!(remaining.trim().is_empty());
+                prop_assert_eq!(
+                    serde_json::to_value(&parsed).expect("Program"),
+                    serde_json::to_value(&program).expect("Program"),
+                );
+                prop_assert_eq!(super::program(&parsed), printed);
+            }
+        }
+    }
+}
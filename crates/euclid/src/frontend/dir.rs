@@ -334,6 +334,20 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "dispute_reason_code",
+        detailed_message = "Reason code of the dispute as sent by the connector",
+        props(Category = "Dispute Auto Response")
+    )]
+    #[serde(rename = "dispute_reason_code")]
+    DisputeReasonCode,
+    #[strum(
+        serialize = "dispute_connector",
+        detailed_message = "Connector through which the dispute was raised",
+        props(Category = "Dispute Auto Response")
+    )]
+    #[serde(rename = "dispute_connector")]
+    DisputeConnector,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -391,6 +405,8 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::DisputeReasonCode => types::DataType::StrValue,
+            Self::DisputeConnector => types::DataType::StrValue,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -555,6 +571,8 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::DisputeReasonCode => None,
+            Self::DisputeConnector => None,
         }
     }
 }
@@ -640,6 +658,10 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "dispute_reason_code")]
+    DisputeReasonCode(types::StrValue),
+    #[serde(rename = "dispute_connector")]
+    DisputeConnector(types::StrValue),
 }
 
 impl DirValue {
@@ -683,6 +705,8 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::DisputeReasonCode(_) => (DirKeyKind::DisputeReasonCode, None),
+            Self::DisputeConnector(_) => (DirKeyKind::DisputeConnector, None),
         };
 
         DirKey::new(kind, data)
@@ -727,6 +751,8 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::DisputeReasonCode(_) => None,
+            Self::DisputeConnector(_) => None,
         }
     }
 
@@ -734,6 +760,8 @@ impl DirValue {
         match self {
             Self::CardBin(val) => Some(val.clone()),
             Self::IssuerName(val) => Some(val.clone()),
+            Self::DisputeReasonCode(val) => Some(val.clone()),
+            Self::DisputeConnector(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -782,6 +810,8 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::DisputeReasonCode(c1), Self::DisputeReasonCode(c2)) => c1 == c2,
+            (Self::DisputeConnector(c1), Self::DisputeConnector(c2)) => c1 == c2,
             _ => false,
         }
     }
@@ -282,6 +282,8 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::DisputeReasonCode(str_value) => EuclidValue::DisputeReasonCode(str_value),
+        dir::DirValue::DisputeConnector(str_value) => EuclidValue::DisputeConnector(str_value),
     })
 }
 
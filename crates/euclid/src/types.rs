@@ -77,6 +77,10 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "dispute_reason_code")]
+    DisputeReasonCode,
+    #[strum(serialize = "dispute_connector")]
+    DisputeConnector,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -163,6 +167,8 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::DisputeReasonCode => DataType::StrValue,
+            Self::DisputeConnector => DataType::StrValue,
         }
     }
 }
@@ -285,6 +291,8 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    DisputeReasonCode(StrValue),
+    DisputeConnector(StrValue),
 }
 
 impl EuclidValue {
@@ -320,6 +328,8 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::DisputeReasonCode(_) => EuclidKey::DisputeReasonCode,
+            Self::DisputeConnector(_) => EuclidKey::DisputeConnector,
         }
     }
 }
@@ -219,8 +219,10 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.cgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let (_, warnings) =
+        analyzer::analyze_with_warnings(program, SEED_DATA.get().map(|sd| &sd.cgraph))
+            .err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -253,6 +255,9 @@ pub fn get_all_keys() -> JsResult {
         "customer_device_display_size",
         "acquirer_country",
         "acquirer_fraud_rate",
+        // Dispute Auto Response Rule Keys should not be included in the payment routing keys
+        "dispute_reason_code",
+        "dispute_connector",
     ];
 
     let keys: Vec<&'static str> = dir::DirKeyKind::VARIANTS
@@ -293,6 +298,16 @@ pub fn parser(val: String) -> String {
     ron_parser::my_parse(val)
 }
 
+/// Renders a routing program back into the textual DSL, producing the same text for the same
+/// program so that rules can be displayed and diffed in a stable format.
+#[wasm_bindgen(js_name = printProgram)]
+pub fn print_program(js_program: JsValue) -> JsResult {
+    let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
+    Ok(serde_wasm_bindgen::to_value(&ast::printer::program(
+        &program,
+    ))?)
+}
+
 #[wasm_bindgen(js_name = getVariantValues)]
 pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
     let key = dir::DirKeyKind::from_str(key).map_err(|_| "Invalid key received".to_string())?;
@@ -338,7 +353,9 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         | dir::DirKeyKind::BusinessLabel
         | dir::DirKeyKind::MetaData
         | dir::DirKeyKind::IssuerName
-        | dir::DirKeyKind::AcquirerFraudRate => Err("Key does not have variants".to_string())?,
+        | dir::DirKeyKind::AcquirerFraudRate
+        | dir::DirKeyKind::DisputeReasonCode
+        | dir::DirKeyKind::DisputeConnector => Err("Key does not have variants".to_string())?,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
@@ -1,3 +1,4 @@
+use common_types::payments::TriggeredVelocityRule;
 use serde::{self, Deserialize, Serialize};
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -9,4 +10,16 @@ pub struct CardTestingGuardData {
     pub is_customer_id_blocking_enabled: bool,
     pub customer_id_blocking_cache_key: String,
     pub card_testing_guard_expiry: i32,
+    /// Velocity rules exceeded by the payment whose action does not block it, which are recorded on
+    /// the payment attempt
+    #[serde(default)]
+    pub triggered_velocity_rules: Vec<TriggeredVelocityRule>,
+}
+
+impl CardTestingGuardData {
+    pub fn should_force_three_ds(&self) -> bool {
+        self.triggered_velocity_rules
+            .iter()
+            .any(|rule| rule.action == common_enums::VelocityRuleAction::ForceThreeDs)
+    }
 }
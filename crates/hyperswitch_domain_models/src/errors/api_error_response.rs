@@ -301,6 +301,14 @@ pub enum ApiErrorResponse {
         max_length: usize,
         received_length: usize,
     },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "A request with the same Idempotency-Key is currently being processed")]
+    IdempotencyKeyInUse,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_49", message = "Idempotency-Key has already been used with a different request")]
+    IdempotencyKeyReused,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_50", message = "Too many requests, retry after {retry_after_seconds} seconds")]
+    RateLimitExceeded { retry_after_seconds: u64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_51", message = "{message}")]
+    ConcurrentModification { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -668,6 +676,18 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::MaxFieldLengthViolated { connector, field_name,  max_length, received_length} => {
                 AER::BadRequest(ApiError::new("IR", 47, format!("Connector '{connector}' rejected field '{field_name}': length {received_length} exceeds maximum of {max_length}"), Some(Extra {connector: Some(connector.to_string()), ..Default::default()})))
             }
+            Self::IdempotencyKeyInUse => {
+                AER::Conflict(ApiError::new("IR", 48, "A request with the same Idempotency-Key is currently being processed", None))
+            }
+            Self::IdempotencyKeyReused => {
+                AER::Unprocessable(ApiError::new("IR", 49, "Idempotency-Key has already been used with a different request", None))
+            }
+            Self::RateLimitExceeded { retry_after_seconds } => {
+                AER::TooManyRequests(ApiError::new("IR", 50, format!("Too many requests, retry after {retry_after_seconds} seconds"), None), *retry_after_seconds)
+            }
+            Self::ConcurrentModification { message } => {
+                AER::Conflict(ApiError::new("IR", 51, message.to_string(), None))
+            }
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
             }
@@ -918,6 +918,10 @@ pub struct PaymentAttempt {
     pub routing_approach: Option<storage_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
     pub debit_routing_savings: Option<MinorUnit>,
+    /// The decision of the retry engine, if the attempt was created by retrying a failed attempt
+    pub retry_details: Option<common_types::payments::PaymentRetryDetails>,
+    /// The velocity rules of the card testing guard exceeded by the attempt, which did not block it
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -1197,6 +1201,8 @@ pub struct PaymentAttemptNew {
     pub setup_future_usage_applied: Option<storage_enums::FutureUsage>,
     pub routing_approach: Option<storage_enums::RoutingApproach>,
     pub connector_request_reference_id: Option<String>,
+    pub retry_details: Option<common_types::payments::PaymentRetryDetails>,
+    pub triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
 }
 
 #[cfg(feature = "v1")]
@@ -1267,6 +1273,7 @@ pub enum PaymentAttemptUpdate {
         card_discovery: Option<common_enums::CardDiscovery>,
         routing_approach: Option<storage_enums::RoutingApproach>,
         connector_request_reference_id: Option<String>,
+        triggered_velocity_rules: Option<common_types::payments::TriggeredVelocityRules>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1537,6 +1544,7 @@ impl PaymentAttemptUpdate {
                 card_discovery,
                 routing_approach,
                 connector_request_reference_id,
+                triggered_velocity_rules,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1581,6 +1589,7 @@ impl PaymentAttemptUpdate {
                     _ => approach,
                 }),
                 connector_request_reference_id,
+                triggered_velocity_rules,
             },
             Self::VoidUpdate {
                 status,
@@ -2016,6 +2025,8 @@ impl behaviour::Conversion for PaymentAttempt {
             created_by: self.created_by.map(|cb| cb.to_string()),
             routing_approach: self.routing_approach,
             connector_request_reference_id: self.connector_request_reference_id,
+            retry_details: self.retry_details,
+            triggered_velocity_rules: self.triggered_velocity_rules,
         })
     }
 
@@ -2114,6 +2125,8 @@ impl behaviour::Conversion for PaymentAttempt {
                 routing_approach: storage_model.routing_approach,
                 connector_request_reference_id: storage_model.connector_request_reference_id,
                 debit_routing_savings: None,
+                retry_details: storage_model.retry_details,
+                triggered_velocity_rules: storage_model.triggered_velocity_rules,
             })
         }
         .await
@@ -2204,6 +2217,8 @@ impl behaviour::Conversion for PaymentAttempt {
             setup_future_usage_applied: self.setup_future_usage_applied,
             routing_approach: self.routing_approach,
             connector_request_reference_id: self.connector_request_reference_id,
+            retry_details: self.retry_details,
+            triggered_velocity_rules: self.triggered_velocity_rules,
         })
     }
 }
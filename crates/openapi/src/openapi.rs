@@ -120,6 +120,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::get_gsm_rule,
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
+        routes::gsm::evaluate_gsm_rule,
+        routes::gsm::list_gsm_rules,
 
         // Routes for mandates
         routes::mandates::get_mandate,
@@ -156,6 +158,7 @@ Never share your secret api keys. Keep them guarded and secure.
 
         // Routes for routing
         routes::routing::routing_create_config,
+        routes::routing::routing_simulate_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
         routes::routing::list_routing_configs,
@@ -200,6 +203,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_dead_letter_webhook_events,
+        routes::webhook_events::replay_dead_letter_webhook_events,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
@@ -243,6 +248,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::payments::XenditSplitRequest,
         common_types::payments::XenditSplitRoute,
         common_types::payments::XenditChargeResponseData,
+        common_types::payments::TriggeredVelocityRule,
         common_types::payments::XenditMultipleSplitResponse,
         common_types::payments::XenditMultipleSplitRequest,
         common_types::domain::XenditSplitSubMerchantData,
@@ -282,6 +288,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        api_models::admin::VelocityRule,
+        api_models::enums::VelocityRuleKey,
+        api_models::enums::VelocityAggregation,
+        api_models::enums::VelocityRuleAction,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
@@ -398,6 +408,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmEvaluateRequest,
+        api_models::gsm::GsmEvaluateResponse,
+        api_models::gsm::GsmRetryAction,
+        api_models::gsm::GsmListRequest,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
         api_models::payments::AliPayQr,
@@ -582,6 +596,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PollConfigResponse,
         api_models::payments::PollConfig,
         api_models::payments::ExternalAuthenticationDetailsResponse,
+        api_models::payments::DccOffer,
+        api_models::payments::DccDecision,
         api_models::payments::ExtendedCardInfo,
         api_models::payment_methods::RequiredFieldInfo,
         api_models::payment_methods::DefaultPaymentMethod,
@@ -665,7 +681,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookEndpoint,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::api_keys::ApiKeyRestrictions,
+        api_models::api_keys::ApiKeyPermission,
+        api_models::enums::Resource,
+        api_models::enums::PermissionScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -680,6 +701,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationResponse,
+        api_models::routing::RoutingSimulationSummary,
+        api_models::routing::RoutingSimulationConnectorCount,
+        api_models::routing::RoutingSimulationResult,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::DynamicRoutingFeatures,
@@ -725,6 +752,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::CardBinRange,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
@@ -736,6 +764,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::DeadLetterEventListConstraints,
+        api_models::webhook_events::DeadLetterEventResponse,
+        api_models::webhook_events::TotalDeadLetterEventsResponse,
+        api_models::webhook_events::DeadLetterEventReplayRequest,
+        api_models::webhook_events::DeadLetterEventReplayStatus,
+        api_models::webhook_events::DeadLetterEventReplayResult,
+        api_models::webhook_events::DeadLetterEventReplayResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -325,6 +325,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AdditionalMerchantData,
         api_models::admin::CardTestingGuardConfig,
         api_models::admin::CardTestingGuardStatus,
+        api_models::admin::VelocityRule,
+        api_models::enums::VelocityRuleKey,
+        api_models::enums::VelocityAggregation,
+        api_models::enums::VelocityRuleAction,
         api_models::admin::ConnectorWalletDetails,
         api_models::admin::MerchantRecipientData,
         api_models::admin::MerchantAccountData,
@@ -353,6 +357,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
         api_models::gsm::GsmDecision,
+        api_models::gsm::GsmEvaluateRequest,
+        api_models::gsm::GsmEvaluateResponse,
+        api_models::gsm::GsmRetryAction,
+        api_models::gsm::GsmListRequest,
         api_models::payments::NullObject,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
@@ -647,7 +655,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::WebhookEndpoint,
         api_models::api_keys::ApiKeyExpiration,
+        api_models::api_keys::ApiKeyRestrictions,
+        api_models::api_keys::ApiKeyPermission,
+        api_models::enums::Resource,
+        api_models::enums::PermissionScope,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
@@ -662,6 +675,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::GooglePayAssuranceDetails,
         api_models::routing::RoutingConfigRequest,
         api_models::routing::RoutingDictionaryRecord,
+        api_models::routing::RoutingAnalysisWarning,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
@@ -708,6 +722,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::payments::PaymentLinkStatus,
         api_models::blocklist::BlocklistRequest,
+        api_models::blocklist::CardBinRange,
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
@@ -73,3 +73,41 @@ pub async fn update_gsm_rule() {}
     security(("admin_api_key" = [])),
 )]
 pub async fn delete_gsm_rule() {}
+
+/// Gsm - Evaluate
+///
+/// Evaluates a connector error against the Gsm Rules, returning the matched rule and the retry decision
+#[utoipa::path(
+    post,
+    path = "/gsm/evaluate",
+    request_body(
+        content = GsmEvaluateRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm evaluated", body = GsmEvaluateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Evaluate Gsm Rule",
+    security(("admin_api_key" = [])),
+)]
+pub async fn evaluate_gsm_rule() {}
+
+/// Gsm - List
+///
+/// Lists the Gsm Rules of a connector, the most matched ones first
+#[utoipa::path(
+    post,
+    path = "/gsm/list",
+    request_body(
+        content = GsmListRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules listed", body = Vec<GsmResponse>),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "List Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+pub async fn list_gsm_rules() {}
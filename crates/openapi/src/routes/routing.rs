@@ -42,6 +42,28 @@ pub async fn routing_create_config() {}
 )]
 pub async fn routing_create_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Simulate config
+///
+/// Replay a candidate routing algorithm against the stored payments of a profile and compare the connectors it would have chosen with the ones that were actually used
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing config simulated", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Activate config
 ///
@@ -115,3 +115,65 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Dead-Letter List
+///
+/// List the Events whose automatic delivery retries were exhausted without a successful delivery.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/dead_letter",
+    params(
+        (
+            "merchant_id" = String,
+            Path,
+            description = "The unique identifier for the Merchant Account."
+        ),
+    ),
+    request_body(
+        content = DeadLetterEventListConstraints,
+        description = "The constraints that can be applied when listing dead-lettered Events.",
+        examples (
+            ("example" = (
+                value = json!({
+                    "dead_lettered_after": "2023-01-01T00:00:00",
+                    "dead_lettered_before": "2023-01-31T23:59:59",
+                    "limit": 5,
+                    "offset": 0,
+                    "profile_id": "{{profile_id}}",
+                    "event_types": ["payment_succeeded"],
+                    "status_code": 503
+                })
+            )),
+        )
+    ),
+    responses(
+        (status = 200, description = "List of dead-lettered Events retrieved successfully", body = TotalDeadLetterEventsResponse),
+    ),
+    tag = "Event",
+    operation_id = "List all dead-lettered Events associated with a Merchant Account or Profile",
+    security(("admin_api_key" = []))
+)]
+pub fn list_dead_letter_webhook_events() {}
+
+/// Events - Dead-Letter Replay
+///
+/// Replay the delivery of the specified dead-lettered Events through the automatic retry schedule.
+/// The deliveries are spread out to at most `max_events_per_minute` Events per minute.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id}/dead_letter/replay",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    request_body(
+        content = DeadLetterEventReplayRequest,
+        description = "The dead-lettered Events to be replayed.",
+    ),
+    responses(
+        (status = 200, description = "The delivery of the Events was scheduled", body = DeadLetterEventReplayResponse),
+    ),
+    tag = "Event",
+    operation_id = "Replay the delivery of dead-lettered Events",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_dead_letter_webhook_events() {}
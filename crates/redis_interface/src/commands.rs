@@ -378,6 +378,22 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_hash_fields_without_modifying_ttl<V>(
+        &self,
+        key: &RedisKey,
+        values: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .hset(key.tenant_aware_key(self), values)
+            .await
+            .change_context(errors::RedisError::SetHashFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_field_if_not_exist<V>(
         &self,
@@ -548,6 +564,18 @@ impl super::RedisConnectionPool {
             .collect())
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_fields(
+        &self,
+        key: &RedisKey,
+        fields: Vec<String>,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field<V>(
         &self,
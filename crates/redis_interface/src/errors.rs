@@ -52,8 +52,12 @@ pub enum RedisError {
     SetAddMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to delete hash field in Redis")]
+    DeleteHashFieldFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
+    #[error("The requested value has been deleted")]
+    ValueDeleted,
     #[error("Invalid RedisEntryId provided")]
     InvalidRedisEntryId,
     #[error("Failed to establish Redis connection")]
@@ -2444,6 +2444,7 @@ pub mod routes {
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Some(Permission::ProfileAnalyticsRead),
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfileAnalyticsRead,
@@ -324,6 +324,18 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => {
                     Ok(Box::new(workflows::revenue_recovery::ExecutePcrWorkflow))
                 }
+                storage::ProcessTrackerRunner::RoutingActivationWorkflow => Ok(Box::new(
+                    workflows::routing_activation::RoutingActivationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ForexRefreshWorkflow => {
+                    Ok(Box::new(workflows::forex_refresh::ForexRefreshWorkflow))
+                }
+                storage::ProcessTrackerRunner::DisputeAutoResponseWorkflow => Ok(Box::new(
+                    workflows::dispute_auto_response::DisputeAutoResponseWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow => Ok(Box::new(
+                    workflows::dispute_deadline_reminder::DisputeDeadlineReminderWorkflow,
+                )),
             }
         };
 
@@ -1,6 +1,10 @@
 pub mod app;
+#[cfg(feature = "v1")]
+pub mod charges;
 pub mod customers;
 pub mod payment_intents;
+#[cfg(feature = "v1")]
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -24,7 +28,8 @@ impl StripeApis {
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
+            .service(app::Charges::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state))
     }
 }
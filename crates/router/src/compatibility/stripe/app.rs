@@ -1,9 +1,9 @@
 use actix_web::{web, Scope};
 
 #[cfg(feature = "v1")]
-use super::{customers::*, payment_intents::*, setup_intents::*};
+use super::{charges::*, customers::*, payment_intents::*, payment_methods::*, setup_intents::*};
 use super::{refunds::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -117,12 +117,34 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct Charges;
 
-impl Mandates {
+#[cfg(feature = "v1")]
+impl Charges {
+    pub fn server(state: routes::AppState) -> Scope {
+        let mut charges = web::resource("").route(web::post().to(charges_create));
+        #[cfg(feature = "olap")]
+        {
+            charges = charges.route(web::get().to(charges_list))
+        }
+        web::scope("/charges")
+            .app_data(web::Data::new(state))
+            .service(charges)
+            .service(web::resource("/{charge_id}").route(web::get().to(charges_retrieve)))
+            .service(web::resource("/{charge_id}/capture").route(web::post().to(charges_capture)))
+    }
+}
+
+pub struct PaymentMethods;
+
+#[cfg(feature = "v1")]
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(web::resource("").route(web::get().to(payment_methods_list)))
+            .service(web::resource("/{id}").route(web::get().to(payment_methods_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_methods_attach)))
+            .service(web::resource("/{id}/detach").route(web::post().to(payment_methods_detach)))
     }
 }
@@ -0,0 +1,275 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+#[cfg(feature = "olap")]
+use crate::compatibility::stripe::payment_intents::types as stripe_payment_types;
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking::GetLockingInput, payments},
+    logger,
+    routes::{self, payments::get_or_generate_payment_id},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::{api as api_types, domain},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+pub async fn charges_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeChargeRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let mut create_payment_req: payment_types::PaymentsRequest = match payload.try_into() {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    if let Err(err) = get_or_generate_payment_id(&mut create_payment_req) {
+        return api::log_and_return_error_response(err);
+    }
+
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::payments_core::<
+                api_types::Authorize,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Authorize>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentWrite),
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieveForceSync))]
+pub async fn charges_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(path.into_inner()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsRetrieveForceSync;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::payments_core::<
+                api_types::PSync,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::PSync>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentStatus,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentRead),
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCapture, payment_id))]
+pub async fn charges_capture(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let stripe_payload: types::StripeChargeCaptureRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?stripe_payload);
+
+    let payload = payment_types::PaymentsCaptureRequest {
+        payment_id,
+        ..stripe_payload.into()
+    };
+
+    let flow = Flow::PaymentsCapture;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::payments_core::<
+                api_types::Capture,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Capture>,
+            >(
+                state,
+                req_state,
+                merchant_context,
+                None,
+                payments::PaymentCapture,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentWrite),
+        }),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
+#[cfg(feature = "olap")]
+pub async fn charges_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    payload: web::Query<stripe_payment_types::StripePaymentListConstraints>,
+) -> HttpResponse {
+    let payload = match payment_types::PaymentListConstraints::try_from(payload.into_inner()) {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    use crate::core::api_locking;
+    let flow = Flow::PaymentsList;
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payments::list_payments(state, merchant_context, None, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentRead),
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
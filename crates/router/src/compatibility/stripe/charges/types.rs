@@ -0,0 +1,374 @@
+use api_models::{mandates::RecurringDetails, payments, refunds};
+use common_utils::{ext_traits::StringExt, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::{
+        payment_intents::types::{Shipping, StripePaymentMethodData},
+        refunds::types as stripe_refunds,
+    },
+    core::errors,
+    pii::Email,
+    types::api::enums as api_enums,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeChargeRequest {
+    pub amount: i64, // amount in cents, hence passed as integer
+    pub currency: String,
+    pub customer: Option<id_type::CustomerId>,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub receipt_email: Option<Email>,
+    pub shipping: Option<Shipping>,
+    pub statement_descriptor: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    /// Authorizes the charge without capturing it when set to false
+    pub capture: Option<bool>,
+    /// Payment token of a saved payment method of the customer
+    pub source: Option<String>,
+    /// Id of a saved payment method of the customer
+    pub payment_method: Option<String>,
+    pub payment_method_data: Option<StripePaymentMethodData>,
+    pub return_url: Option<url::Url>,
+}
+
+impl TryFrom<StripeChargeRequest> for payments::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeChargeRequest) -> errors::RouterResult<Self> {
+        let capture_method = match item.capture {
+            Some(false) => api_enums::CaptureMethod::Manual,
+            _ => api_enums::CaptureMethod::Automatic,
+        };
+
+        let payment_method_data =
+            item.payment_method_data
+                .as_ref()
+                .map(|pmd| payments::PaymentMethodDataRequest {
+                    payment_method_data: pmd
+                        .payment_method_details
+                        .clone()
+                        .map(payments::PaymentMethodData::from),
+                    billing: pmd.billing_details.clone().map(payments::Address::from),
+                });
+
+        // Charging a saved payment method by its id is an off session payment
+        let recurring_details = item.payment_method.map(RecurringDetails::PaymentMethodId);
+
+        Ok(Self {
+            amount: Some(MinorUnit::new(item.amount).into()),
+            currency: Some(
+                item.currency
+                    .to_uppercase()
+                    .parse_enum("currency")
+                    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "currency",
+                    })?,
+            ),
+            capture_method: Some(capture_method),
+            confirm: Some(true),
+            customer_id: item.customer,
+            email: item.receipt_email,
+            phone: item.shipping.as_ref().and_then(|s| s.phone.clone()),
+            description: item.description,
+            return_url: item.return_url,
+            payment_method: item
+                .payment_method_data
+                .as_ref()
+                .map(|pmd| api_enums::PaymentMethod::from(pmd.stype.to_owned())),
+            billing: item
+                .payment_method_data
+                .and_then(|pmd| pmd.billing_details.map(payments::Address::from)),
+            payment_method_data,
+            shipping: item.shipping.map(payments::Address::from),
+            statement_descriptor_name: item.statement_descriptor,
+            statement_descriptor_suffix: item.statement_descriptor_suffix,
+            metadata: item.metadata,
+            payment_token: item.source,
+            off_session: recurring_details.as_ref().map(|_| true),
+            recurring_details,
+            ..Self::default()
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeChargeCaptureRequest {
+    pub amount: Option<i64>,
+    pub statement_descriptor: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+}
+
+impl From<StripeChargeCaptureRequest> for payments::PaymentsCaptureRequest {
+    fn from(item: StripeChargeCaptureRequest) -> Self {
+        Self {
+            amount_to_capture: item.amount.map(MinorUnit::new),
+            statement_descriptor_prefix: item.statement_descriptor,
+            statement_descriptor_suffix: item.statement_descriptor_suffix,
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Default, Eq, PartialEq, Serialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargeStatus {
+    Succeeded,
+    #[default]
+    Pending,
+    Failed,
+}
+
+impl From<api_enums::IntentStatus> for StripeChargeStatus {
+    fn from(item: api_enums::IntentStatus) -> Self {
+        match item {
+            // Authorized but uncaptured charges are reported as succeeded with `captured: false`
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable => Self::Succeeded,
+            api_enums::IntentStatus::Failed | api_enums::IntentStatus::Cancelled => Self::Failed,
+            api_enums::IntentStatus::Processing
+            | api_enums::IntentStatus::RequiresCustomerAction
+            | api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresPaymentMethod
+            | api_enums::IntentStatus::RequiresConfirmation
+            | api_enums::IntentStatus::Conflicted => Self::Pending,
+        }
+    }
+}
+
+/// A charge is captured once any amount of it has been captured, even if the rest of the
+/// authorized amount can still be captured
+fn is_captured(status: api_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+#[derive(Default, Eq, PartialEq, Serialize, Debug)]
+pub struct StripeChargeResponse {
+    pub id: id_type::PaymentId,
+    pub object: &'static str,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub amount_refunded: i64,
+    pub captured: bool,
+    pub paid: bool,
+    pub refunded: bool,
+    pub currency: String,
+    pub status: StripeChargeStatus,
+    pub created: Option<i64>,
+    pub customer: Option<id_type::CustomerId>,
+    pub description: Option<String>,
+    pub metadata: Option<serde_json::Value>,
+    pub payment_intent: id_type::PaymentId,
+    pub payment_method: Option<String>,
+    pub receipt_email: Option<Email>,
+    pub refunds: Vec<stripe_refunds::StripeRefundResponse>,
+    pub statement_descriptor: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub connector: Option<String>,
+    pub connector_transaction_id: Option<String>,
+}
+
+impl From<payments::PaymentsResponse> for StripeChargeResponse {
+    fn from(resp: payments::PaymentsResponse) -> Self {
+        let amount = resp.amount.get_amount_as_i64();
+        let amount_captured = resp
+            .amount_received
+            .map(|amount| amount.get_amount_as_i64())
+            .unwrap_or_default();
+        let amount_refunded = resp
+            .refunds
+            .iter()
+            .flatten()
+            .filter(|refund| refund.status == refunds::RefundStatus::Succeeded)
+            .map(|refund| refund.amount.get_amount_as_i64())
+            .sum::<i64>();
+        let status = StripeChargeStatus::from(resp.status);
+
+        Self {
+            id: resp.payment_id.clone(),
+            object: "charge",
+            amount,
+            amount_captured,
+            amount_refunded,
+            captured: is_captured(resp.status),
+            paid: status == StripeChargeStatus::Succeeded,
+            refunded: amount_refunded > 0 && amount_refunded >= amount_captured,
+            currency: resp.currency.to_lowercase(),
+            status,
+            created: resp.created.map(|t| t.assume_utc().unix_timestamp()),
+            customer: resp.customer_id,
+            description: resp.description,
+            metadata: resp.metadata,
+            payment_intent: resp.payment_id,
+            payment_method: resp.payment_method_id,
+            receipt_email: resp.email.map(|inner| inner.into()),
+            refunds: resp
+                .refunds
+                .map(|refunds| refunds.into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
+            statement_descriptor: resp.statement_descriptor_name,
+            statement_descriptor_suffix: resp.statement_descriptor_suffix,
+            failure_code: resp.error_code,
+            failure_message: resp.error_message,
+            connector: resp.connector,
+            connector_transaction_id: resp.connector_transaction_id,
+        }
+    }
+}
+
+#[derive(Default, Eq, PartialEq, Serialize)]
+pub struct StripeChargeListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeChargeResponse>,
+}
+
+impl From<payments::PaymentListResponse> for StripeChargeListResponse {
+    fn from(it: payments::PaymentListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/charges",
+            has_more: false,
+            data: it.data.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn charge_request(value: serde_json::Value) -> StripeChargeRequest {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_charge_status_and_captured() {
+        let cases = [
+            (
+                api_enums::IntentStatus::Succeeded,
+                StripeChargeStatus::Succeeded,
+                true,
+            ),
+            (
+                api_enums::IntentStatus::PartiallyCaptured,
+                StripeChargeStatus::Succeeded,
+                true,
+            ),
+            (
+                api_enums::IntentStatus::PartiallyCapturedAndCapturable,
+                StripeChargeStatus::Succeeded,
+                true,
+            ),
+            (
+                api_enums::IntentStatus::RequiresCapture,
+                StripeChargeStatus::Succeeded,
+                false,
+            ),
+            (
+                api_enums::IntentStatus::Processing,
+                StripeChargeStatus::Pending,
+                false,
+            ),
+            (
+                api_enums::IntentStatus::Failed,
+                StripeChargeStatus::Failed,
+                false,
+            ),
+            (
+                api_enums::IntentStatus::Cancelled,
+                StripeChargeStatus::Failed,
+                false,
+            ),
+        ];
+        for (intent_status, charge_status, captured) in cases {
+            assert_eq!(StripeChargeStatus::from(intent_status), charge_status);
+            assert_eq!(is_captured(intent_status), captured, "{intent_status:?}");
+        }
+    }
+
+    #[test]
+    fn test_charge_request_capture_method() {
+        let request = payments::PaymentsRequest::try_from(charge_request(serde_json::json!({
+            "amount": 1000,
+            "currency": "usd",
+        })))
+        .unwrap();
+        assert_eq!(
+            request.capture_method,
+            Some(api_enums::CaptureMethod::Automatic)
+        );
+        assert_eq!(request.currency, Some(api_enums::Currency::USD));
+        assert_eq!(request.confirm, Some(true));
+
+        let request = payments::PaymentsRequest::try_from(charge_request(serde_json::json!({
+            "amount": 1000,
+            "currency": "usd",
+            "capture": false,
+        })))
+        .unwrap();
+        assert_eq!(
+            request.capture_method,
+            Some(api_enums::CaptureMethod::Manual)
+        );
+    }
+
+    #[test]
+    fn test_charge_request_saved_payment_method_is_off_session() {
+        let request = payments::PaymentsRequest::try_from(charge_request(serde_json::json!({
+            "amount": 1000,
+            "currency": "usd",
+            "payment_method": "pm_123",
+        })))
+        .unwrap();
+        assert_eq!(request.off_session, Some(true));
+        assert!(matches!(
+            request.recurring_details,
+            Some(RecurringDetails::PaymentMethodId(ref id)) if id == "pm_123"
+        ));
+
+        let request = payments::PaymentsRequest::try_from(charge_request(serde_json::json!({
+            "amount": 1000,
+            "currency": "usd",
+            "source": "token_123",
+        })))
+        .unwrap();
+        assert_eq!(request.off_session, None);
+        assert_eq!(request.payment_token, Some("token_123".to_string()));
+    }
+
+    #[test]
+    fn test_charge_request_invalid_currency() {
+        let result = payments::PaymentsRequest::try_from(charge_request(serde_json::json!({
+            "amount": 1000,
+            "currency": "not_a_currency",
+        })));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_charge_capture_request() {
+        let request = payments::PaymentsCaptureRequest::from(StripeChargeCaptureRequest {
+            amount: Some(500),
+            statement_descriptor: Some("prefix".to_string()),
+            statement_descriptor_suffix: None,
+        });
+        assert_eq!(request.amount_to_capture, Some(MinorUnit::new(500)));
+        assert_eq!(
+            request.statement_descriptor_prefix,
+            Some("prefix".to_string())
+        );
+    }
+}
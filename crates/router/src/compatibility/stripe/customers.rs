@@ -13,7 +13,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::{customers as customer_types, payment_methods},
         domain,
@@ -62,6 +62,7 @@ pub async fn customer_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -102,6 +103,7 @@ pub async fn customer_retrieve(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -156,6 +158,7 @@ pub async fn customer_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -196,6 +199,7 @@ pub async fn customer_delete(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -243,6 +247,7 @@ pub async fn list_customer_payment_method_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -285,6 +285,12 @@ pub enum StripeErrorCode {
     PlatformUnauthorizedRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Profile Acquirer not found")]
     ProfileAcquirerNotFound,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_key_in_use", message = "There is currently another in-progress request using this Idempotent Key")]
+    IdempotencyKeyInUse,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "idempotency_error", message = "Keys for idempotent requests can only be used with the same parameters they were first used with")]
+    IdempotencyKeyReused,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests hit the API too quickly")]
+    RateLimit { retry_after_seconds: u64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -327,7 +333,6 @@ pub enum StripeErrorCode {
         DebitNotAuthorized,
         EmailInvalid,
         ExpiredCard,
-        IdempotencyKeyInUse,
         IncorrectAddress,
         IncorrectCvc,
         IncorrectNumber,
@@ -393,7 +398,6 @@ pub enum StripeErrorCode {
         PostalCodeInvalid,
         ProcessingError,
         ProductInactive,
-        RateLimit,
         ReferToCustomer,
         RefundDisputedPayment,
         ResourceAlreadyExists,
@@ -693,6 +697,16 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ProfileAcquirerNotFound { .. } => {
                 Self::ProfileAcquirerNotFound
             }
+            errors::ApiErrorResponse::IdempotencyKeyInUse => Self::IdempotencyKeyInUse,
+            errors::ApiErrorResponse::IdempotencyKeyReused => Self::IdempotencyKeyReused,
+            errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_seconds,
+            } => Self::RateLimit {
+                retry_after_seconds,
+            },
+            errors::ApiErrorResponse::ConcurrentModification { message } => {
+                Self::PreconditionFailed { message }
+            }
         }
     }
 }
@@ -788,15 +802,24 @@ impl actix_web::ResponseError for StripeErrorCode {
             }
             Self::LockTimeout => StatusCode::LOCKED,
             Self::ProfileAcquirerNotFound => StatusCode::NOT_FOUND,
+            Self::IdempotencyKeyInUse => StatusCode::CONFLICT,
+            Self::IdempotencyKeyReused => StatusCode::BAD_REQUEST,
+            Self::RateLimit { .. } => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+        if let Self::RateLimit {
+            retry_after_seconds,
+        } = self
+        {
+            response.insert_header((header::RETRY_AFTER, *retry_after_seconds));
+        }
+        response.body(self.to_string())
     }
 }
 
@@ -0,0 +1,289 @@
+pub mod types;
+
+use ::payment_methods::controller::PaymentMethodsController;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse},
+        payment_methods::cards,
+    },
+    routes::{self, mandates},
+    services::{self, api, authentication as auth, authorization::permissions::Permission},
+    types::{
+        api::{enums as api_enums, payment_methods as payment_method_types},
+        domain,
+    },
+};
+
+/// Prefix of the mandate ids, which were accepted by the detach endpoint before payment methods
+/// could be detached
+const MANDATE_ID_PREFIX: &str = "man_";
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
+pub async fn payment_methods_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<types::StripePaymentMethodListConstraints>,
+) -> HttpResponse {
+    let payload = query_payload.into_inner();
+    let flow = Flow::CustomerPaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| async move {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            let list_request = payment_method_types::PaymentMethodListRequest {
+                limit: req.limit,
+                ..Default::default()
+            };
+            let response = cards::do_list_customer_pm_fetch_customer_if_not_passed(
+                state,
+                merchant_context,
+                Some(list_request),
+                Some(&req.customer),
+                None,
+            )
+            .await?;
+
+            let mut list = response
+                .get_json_body()
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable("Unexpected response from payment methods core")?;
+            if let Some(payment_method) =
+                req.payment_method_type.map(api_enums::PaymentMethod::from)
+            {
+                list.customer_payment_methods
+                    .retain(|customer_payment_method| {
+                        customer_payment_method.payment_method == payment_method
+                    });
+            }
+
+            Ok(services::ApplicationResponse::Json(list))
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_methods_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| async move {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            cards::PmCards {
+                state: &state,
+                merchant_context: &merchant_context,
+            }
+            .retrieve_payment_method(req)
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn payment_methods_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let mut payload: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+    payload.payment_method_id = path.into_inner();
+    let flow = Flow::PaymentMethodsUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            attach_payment_method(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_methods_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    if path.starts_with(MANDATE_ID_PREFIX) {
+        return mandates::revoke_mandate(state, req, path).await;
+    }
+
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            detach_payment_method(state, merchant_context, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment methods are always saved against a customer and cannot be moved to another one, so
+/// attaching only succeeds when the payment method already belongs to the requested customer
+async fn attach_payment_method(
+    state: routes::SessionState,
+    merchant_context: domain::MerchantContext,
+    req: types::StripePaymentMethodAttachRequest,
+) -> RouterResponse<payment_method_types::PaymentMethodResponse> {
+    let controller = cards::PmCards {
+        state: &state,
+        merchant_context: &merchant_context,
+    };
+    let payment_method = controller
+        .retrieve_payment_method(payment_method_types::PaymentMethodId {
+            payment_method_id: req.payment_method_id,
+        })
+        .await?
+        .get_json_body()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unexpected response from payment methods core")?;
+
+    if payment_method.customer_id.as_ref() != Some(&req.customer) {
+        return Err(ApiErrorResponse::PreconditionFailed {
+            message: "Attaching a payment method to a different customer is not supported, \
+                      the payment method has to be saved against the customer it belongs to"
+                .to_string(),
+        }
+        .into());
+    }
+
+    Ok(services::ApplicationResponse::Json(payment_method))
+}
+
+/// Deletes the payment method and returns it without a customer, the way a detached payment
+/// method is returned by Stripe
+async fn detach_payment_method(
+    state: routes::SessionState,
+    merchant_context: domain::MerchantContext,
+    req: payment_method_types::PaymentMethodId,
+) -> RouterResponse<payment_method_types::PaymentMethodResponse> {
+    let controller = cards::PmCards {
+        state: &state,
+        merchant_context: &merchant_context,
+    };
+    let payment_method = controller
+        .retrieve_payment_method(req.clone())
+        .await?
+        .get_json_body()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unexpected response from payment methods core")?;
+    controller.delete_payment_method(req).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_method_types::PaymentMethodResponse {
+            customer_id: None,
+            ..payment_method
+        },
+    ))
+}
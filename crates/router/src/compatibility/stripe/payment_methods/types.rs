@@ -0,0 +1,115 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::{
+        customers::types::CardDetails, payment_intents::types::StripePaymentMethodType,
+    },
+    types::api::{enums as api_enums, payment_methods as api_types},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripePaymentMethodListConstraints {
+    pub customer: id_type::CustomerId,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<StripePaymentMethodType>,
+    pub limit: Option<i64>,
+}
+
+impl ApiEventMetric for StripePaymentMethodListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripePaymentMethodAttachRequest {
+    #[serde(skip)]
+    pub payment_method_id: String,
+    pub customer: id_type::CustomerId,
+}
+
+impl ApiEventMetric for StripePaymentMethodAttachRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: None,
+            payment_method_type: None,
+        })
+    }
+}
+
+#[derive(Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<api_enums::PaymentMethod>,
+    pub customer: Option<id_type::CustomerId>,
+    pub card: Option<CardDetails>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub livemode: bool,
+}
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: item.payment_method,
+            customer: item.customer_id,
+            card: item.card.map(From::from),
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+            livemode: false,
+        }
+    }
+}
+
+impl From<api_types::CustomerPaymentMethod> for StripePaymentMethodResponse {
+    fn from(item: api_types::CustomerPaymentMethod) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: Some(item.payment_method),
+            customer: Some(item.customer_id),
+            card: item.card.map(From::from),
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+            livemode: false,
+        }
+    }
+}
+
+#[derive(Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePaymentMethodResponse>,
+}
+
+impl From<api_types::CustomerPaymentMethodsListResponse> for StripePaymentMethodListResponse {
+    fn from(item: api_types::CustomerPaymentMethodsListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/payment_methods",
+            has_more: false,
+            data: item
+                .customer_payment_methods
+                .into_iter()
+                .map(From::from)
+                .collect(),
+        }
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     core::{api_locking, refunds},
     db::domain,
     logger, routes,
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds as refund_types,
 };
 
@@ -59,6 +59,7 @@ pub async fn refund_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -115,6 +116,7 @@ pub async fn refund_retrieve_with_gateway_creds(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -163,6 +165,7 @@ pub async fn refund_retrieve(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -203,6 +206,7 @@ pub async fn refund_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
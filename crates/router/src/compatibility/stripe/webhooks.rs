@@ -79,6 +79,29 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             signature.into(),
         ))
     }
+
+    fn add_previous_webhook_signature_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        // Stripe sends the signatures generated with each active secret as separate `v1` entries
+        // of the same header
+        let previous_signatures = signature
+            .split(',')
+            .filter(|element| element.starts_with("v1="))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        match header
+            .iter_mut()
+            .find(|(name, _)| name == headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE)
+        {
+            Some((_, value)) => {
+                *value = format!("{},{previous_signatures}", value.clone().into_inner()).into()
+            }
+            None => Self::add_webhook_header(header, signature),
+        }
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -283,6 +306,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
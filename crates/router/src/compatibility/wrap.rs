@@ -10,14 +10,14 @@ use crate::{
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
-        AppState, SessionState,
+        lock_utils, AppState, SessionState,
     },
     services::{self, api, authentication as auth, logger},
 };
 
 #[instrument(skip(request, payload, state, func, api_authentication))]
 pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + Into<lock_utils::ApiIdentifier>,
     state: Arc<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -95,6 +95,16 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::DisputeDeadlineReminderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reminder_offsets_in_hours: vec![72, 24, 6],
+            email_enabled: false,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -546,6 +546,8 @@ pub(crate) async fn fetch_raw_secrets(
         debit_routing_config: conf.debit_routing_config,
         clone_connector_allowlist: conf.clone_connector_allowlist,
         merchant_id_auth: conf.merchant_id_auth,
+        rate_limit: conf.rate_limit,
+        dispute_deadline_reminder: conf.dispute_deadline_reminder,
         infra_values: conf.infra_values,
     }
 }
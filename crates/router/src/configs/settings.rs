@@ -161,6 +161,10 @@ pub struct Settings<S: SecretState> {
     pub clone_connector_allowlist: Option<CloneConnectorAllowlistConfig>,
     pub merchant_id_auth: MerchantIdAuthSettings,
     #[serde(default)]
+    pub rate_limit: RateLimitSettings,
+    #[serde(default)]
+    pub dispute_deadline_reminder: DisputeDeadlineReminderSettings,
+    #[serde(default)]
     pub infra_values: Option<HashMap<String, String>>,
 }
 
@@ -480,6 +484,17 @@ pub struct ForexApi {
     pub data_expiration_delay_in_seconds: u32,
     pub redis_lock_timeout_in_seconds: u32,
     pub redis_ttl_in_seconds: u32,
+    pub provider: ForexProviderConfig,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ForexProviderConfig {
+    /// Fetch the rates from the primary forex api, falling back to the secondary one
+    #[default]
+    Remote,
+    /// Read the rates from a JSON file, for environments without access to the forex apis
+    StaticFile { path: String },
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -835,6 +850,44 @@ pub struct MerchantIdAuthSettings {
     pub merchant_id_auth_enabled: bool,
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Limits applied to the route groups which are not configured in `route_groups`
+    pub default: RateLimitConfig,
+    /// Limits of specific route groups, keyed by the route group name (such as `payments`)
+    pub route_groups: HashMap<String, RateLimitConfig>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RateLimitConfig {
+    /// Bucket shared by all the requests made by a merchant to the route group
+    pub merchant: Option<TokenBucketConfig>,
+    /// Bucket for the requests made to the route group using a single API key
+    pub api_key: Option<TokenBucketConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct DisputeDeadlineReminderSettings {
+    pub enabled: bool,
+    /// Number of hours before the deadline to respond to a dispute at which the merchant is
+    /// reminded about the dispute
+    pub reminder_offsets_in_hours: Vec<u16>,
+    /// Whether the reminders are also sent to the primary email of the merchant
+    pub email_enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct TokenBucketConfig {
+    /// Maximum number of requests that can be made in a burst
+    pub capacity: u32,
+    /// Number of requests added back to the bucket every second
+    pub refill_rate_per_second: u32,
+}
+
 #[derive(Debug, Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct WebhooksSettings {
@@ -1045,6 +1098,8 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
+        self.dispute_deadline_reminder.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -181,6 +181,69 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        self.default.validate("default")?;
+
+        self.route_groups
+            .iter()
+            .try_for_each(|(route_group, config)| {
+                when(
+                    crate::routes::lock_utils::ApiIdentifier::from_str(route_group).is_err(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "rate limit route group `{route_group}` is not a valid route group"
+                        )))
+                    },
+                )?;
+                config.validate(route_group)
+            })
+    }
+}
+
+impl super::settings::RateLimitConfig {
+    fn validate(&self, route_group: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        [self.merchant, self.api_key]
+            .into_iter()
+            .flatten()
+            .try_for_each(|bucket| {
+                when(
+                    bucket.capacity == 0 || bucket.refill_rate_per_second == 0,
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "rate limit capacity and refill_rate_per_second of `{route_group}` must not be 0"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::DisputeDeadlineReminderSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.reminder_offsets_in_hours.is_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "dispute deadline reminder offsets must not be empty".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::WebhooksSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -355,3 +418,83 @@ impl super::settings::ChatSettings {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::super::settings::{RateLimitConfig, RateLimitSettings, TokenBucketConfig};
+
+    fn bucket(capacity: u32, refill_rate_per_second: u32) -> Option<TokenBucketConfig> {
+        Some(TokenBucketConfig {
+            capacity,
+            refill_rate_per_second,
+        })
+    }
+
+    fn settings(route_groups: HashMap<String, RateLimitConfig>) -> RateLimitSettings {
+        RateLimitSettings {
+            enabled: true,
+            default: RateLimitConfig {
+                merchant: bucket(100, 10),
+                api_key: bucket(50, 5),
+            },
+            route_groups,
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_settings_valid() {
+        assert!(RateLimitSettings::default().validate().is_ok());
+        assert!(settings(HashMap::new()).validate().is_ok());
+        assert!(settings(HashMap::from([(
+            "payments".to_string(),
+            RateLimitConfig {
+                merchant: bucket(20, 2),
+                api_key: None,
+            },
+        )]))
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_settings_zero_bucket() {
+        let mut zero_capacity = settings(HashMap::new());
+        zero_capacity.default.merchant = bucket(0, 10);
+        assert!(zero_capacity.validate().is_err());
+
+        let mut zero_refill_rate = settings(HashMap::new());
+        zero_refill_rate.default.api_key = bucket(10, 0);
+        assert!(zero_refill_rate.validate().is_err());
+
+        let route_group_zero_capacity = settings(HashMap::from([(
+            "refunds".to_string(),
+            RateLimitConfig {
+                merchant: None,
+                api_key: bucket(0, 1),
+            },
+        )]));
+        assert!(route_group_zero_capacity.validate().is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_settings_unknown_route_group() {
+        let unknown_route_group = settings(HashMap::from([(
+            "not_a_route_group".to_string(),
+            RateLimitConfig::default(),
+        )]));
+        assert!(unknown_route_group.validate().is_err());
+    }
+
+    #[test]
+    fn test_rate_limit_settings_disabled_are_not_validated() {
+        let mut disabled = settings(HashMap::from([(
+            "not_a_route_group".to_string(),
+            RateLimitConfig::default(),
+        )]));
+        disabled.default.merchant = bucket(0, 0);
+        disabled.enabled = false;
+        assert!(disabled.validate().is_ok());
+    }
+}
@@ -40,6 +40,9 @@ pub const DEFAULT_SESSION_EXPIRY: i64 = 15 * 60;
 /// The length of a merchant fingerprint secret
 pub const FINGERPRINT_SECRET_LENGTH: usize = 64;
 
+/// Maximum length of the fingerprint id stored against a blocklist entry
+pub const BLOCKLIST_FINGERPRINT_ID_MAX_LENGTH: usize = 64;
+
 pub const DEFAULT_LIST_API_LIMIT: u16 = 10;
 
 // String literals
@@ -79,6 +82,7 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_DISPUTE_DEADLINE_REMINDER: &str = "Dispute Response Deadline Reminder";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -91,6 +95,14 @@ pub const GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX: &str = "GUEST_USER_CARD_BLO
 
 pub const CUSTOMER_ID_BLOCKING_PREFIX: &str = "CUSTOMER_ID_BLOCKING";
 
+pub const VELOCITY_RULE_CACHE_KEY_PREFIX: &str = "VELOCITY_RULE";
+
+/// Maximum number of velocity rules in the card testing guard config of a profile
+pub const MAX_VELOCITY_RULES_PER_PROFILE: usize = 20;
+
+/// Maximum length of the sliding window of a velocity rule (30 days)
+pub const MAX_VELOCITY_RULE_WINDOW_IN_SECS: u32 = 2_592_000;
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
@@ -322,3 +334,6 @@ pub const UCS_AUTH_BODY_KEY: &str = "body-key";
 
 /// Header value indicating that header-key-based authentication is used.
 pub const UCS_AUTH_HEADER_KEY: &str = "header-key";
+
+/// Default time for which webhooks continue to be signed with a signing secret after it is replaced
+pub const DEFAULT_WEBHOOK_SIGNING_SECRET_ROTATION_GRACE_PERIOD_IN_SECS: u32 = 86400;
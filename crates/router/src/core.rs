@@ -26,6 +26,7 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
@@ -39,6 +40,9 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod process_tracker;
+pub mod rate_limit;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
@@ -28,6 +28,7 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     consts,
     core::{
+        card_testing_guard::utils as card_testing_guard_utils,
         connector_validation::ConnectorAuthTypeAndMetadataValidation,
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
@@ -3284,6 +3285,12 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(card_testing_guard_config) = &self.card_testing_guard_config {
+            card_testing_guard_utils::validate_velocity_rules(
+                &card_testing_guard_config.velocity_rules,
+            )?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3512,6 +3519,12 @@ impl ProfileCreateBridge for api::ProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(card_testing_guard_config) = &self.card_testing_guard_config {
+            card_testing_guard_utils::validate_velocity_rules(
+                &card_testing_guard_config.velocity_rules,
+            )?;
+        }
+
         // Generate a unique profile id
         // TODO: the profile_id should be generated from the profile_name
         let profile_id = common_utils::generate_profile_id_of_default_length();
@@ -3785,6 +3798,87 @@ pub async fn delete_profile(
     Ok(service_api::ApplicationResponse::Json(delete_result))
 }
 
+/// Retains the webhook signing secrets replaced by a profile update for the rotation grace period,
+/// so that webhooks are signed with both the previous and the new secrets in the meantime.
+#[cfg(feature = "olap")]
+fn get_webhook_details_with_signing_secret_rotation(
+    business_profile: &domain::Profile,
+    webhook_details: Option<diesel_models::business_profile::WebhookDetails>,
+    payment_response_hash_key: Option<&String>,
+) -> Option<diesel_models::business_profile::WebhookDetails> {
+    use diesel_models::business_profile::WebhookSigningSecretRotation;
+
+    let existing_webhook_details = business_profile.webhook_details.as_ref();
+    let is_payment_response_hash_key_replaced = payment_response_hash_key.is_some_and(|key| {
+        business_profile
+            .payment_response_hash_key
+            .as_ref()
+            .is_some_and(|existing_key| existing_key != key)
+    });
+
+    // Webhook details are left untouched unless updated or the signing secret is rotated
+    let mut webhook_details = match webhook_details {
+        Some(webhook_details) => webhook_details,
+        None if is_payment_response_hash_key_replaced => existing_webhook_details.cloned()?,
+        None => return None,
+    };
+
+    let now = date_time::now();
+    let grace_period = webhook_details
+        .signing_secret_rotation_grace_period_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SIGNING_SECRET_ROTATION_GRACE_PERIOD_IN_SECS);
+    let get_rotation =
+        |previous_signing_secret: Option<&Secret<String>>,
+         signing_secret: Option<&Secret<String>>,
+         existing_rotation: Option<&WebhookSigningSecretRotation>| {
+            match (previous_signing_secret, signing_secret) {
+                (Some(previous_signing_secret), Some(signing_secret))
+                    if previous_signing_secret.peek() != signing_secret.peek() =>
+                {
+                    Some(WebhookSigningSecretRotation {
+                        previous_signing_secret: previous_signing_secret.clone(),
+                        expires_at: now
+                            .saturating_add(time::Duration::seconds(i64::from(grace_period))),
+                    })
+                }
+                _ => existing_rotation
+                    .filter(|rotation| rotation.is_active(now))
+                    .cloned(),
+            }
+        };
+
+    webhook_details.payment_response_hash_key_rotation = get_rotation(
+        business_profile
+            .payment_response_hash_key
+            .clone()
+            .map(Secret::new)
+            .as_ref(),
+        payment_response_hash_key.cloned().map(Secret::new).as_ref(),
+        existing_webhook_details
+            .and_then(|details| details.payment_response_hash_key_rotation.as_ref()),
+    );
+
+    if let Some(webhook_endpoints) = webhook_details.webhook_endpoints.as_mut() {
+        for endpoint in webhook_endpoints.iter_mut() {
+            let existing_endpoint = existing_webhook_details
+                .and_then(|details| details.webhook_endpoints.as_ref())
+                .and_then(|endpoints| {
+                    endpoints
+                        .iter()
+                        .find(|existing| existing.endpoint_id == endpoint.endpoint_id)
+                });
+
+            endpoint.signing_secret_rotation = get_rotation(
+                existing_endpoint.and_then(|existing| existing.signing_secret.as_ref()),
+                endpoint.signing_secret.as_ref(),
+                existing_endpoint.and_then(|existing| existing.signing_secret_rotation.as_ref()),
+            );
+        }
+    }
+
+    Some(webhook_details)
+}
+
 #[cfg(feature = "olap")]
 #[async_trait::async_trait]
 trait ProfileUpdateBridge {
@@ -3809,11 +3903,21 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(card_testing_guard_config) = &self.card_testing_guard_config {
+            card_testing_guard_utils::validate_velocity_rules(
+                &card_testing_guard_config.velocity_rules,
+            )?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        let webhook_details = get_webhook_details_with_signing_secret_rotation(
+            business_profile,
+            self.webhook_details.map(ForeignInto::foreign_into),
+            self.payment_response_hash_key.as_ref(),
+        );
 
         if let Some(ref routing_algorithm) = self.routing_algorithm {
             let _: api_models::routing::StaticRoutingAlgorithm = routing_algorithm
@@ -4009,7 +4113,17 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
-        let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
+        if let Some(card_testing_guard_config) = &self.card_testing_guard_config {
+            card_testing_guard_utils::validate_velocity_rules(
+                &card_testing_guard_config.velocity_rules,
+            )?;
+        }
+
+        let webhook_details = get_webhook_details_with_signing_secret_rotation(
+            business_profile,
+            self.webhook_details.map(ForeignInto::foreign_into),
+            self.payment_response_hash_key.as_ref(),
+        );
 
         let payment_link_config = self
             .payment_link_config
@@ -108,6 +108,34 @@ impl PlaintextApiKey {
     }
 }
 
+fn validate_api_key_restrictions(
+    restrictions: &api::ApiKeyRestrictions,
+) -> errors::RouterResult<()> {
+    let error_message = if restrictions.permissions.is_empty() {
+        Some("A restricted API key must be granted at least one permission")
+    } else if restrictions
+        .profile_ids
+        .as_ref()
+        .is_some_and(|profile_ids| profile_ids.is_empty())
+    {
+        Some("`profile_ids` must not be empty when provided")
+    } else if restrictions
+        .allowed_ips
+        .as_ref()
+        .is_some_and(|allowed_ips| allowed_ips.is_empty())
+    {
+        Some("`allowed_ips` must not be empty when provided")
+    } else {
+        None
+    };
+
+    error_message.map_or(Ok(()), |message| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn create_api_key(
     state: SessionState,
@@ -119,6 +147,10 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    if let Some(restrictions) = api_key.restrictions.as_ref() {
+        validate_api_key_restrictions(restrictions)?;
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +163,7 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        restrictions: api_key.restrictions,
     };
 
     let api_key = store
@@ -276,6 +309,10 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    if let Some(restrictions) = api_key.restrictions.as_ref() {
+        validate_api_key_restrictions(restrictions)?;
+    }
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -1,9 +1,15 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+};
+
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
+use storage_impl::redis::cache;
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -24,20 +30,87 @@ pub async fn delete_entry_from_blocklist(
 ) -> RouterResult<api_blocklist::DeleteFromBlocklistResponse> {
     let blocklist_entry = match request {
         api_blocklist::DeleteFromBlocklistRequest::CardBin(bin) => {
-            delete_card_bin_blocklist_entry(state, &bin, merchant_id).await?
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &bin,
+                merchant_id,
+                common_enums::BlocklistDataKind::CardBin,
+            )
+            .await?
         }
 
         api_blocklist::DeleteFromBlocklistRequest::ExtendedCardBin(xbin) => {
-            delete_card_bin_blocklist_entry(state, &xbin, merchant_id).await?
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &xbin,
+                merchant_id,
+                common_enums::BlocklistDataKind::ExtendedCardBin,
+            )
+            .await?
         }
 
-        api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => state
-            .store
-            .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-                message: "no blocklist record for the given fingerprint id was found".to_string(),
-            })?,
+        api_blocklist::DeleteFromBlocklistRequest::BinRange(range) => {
+            let fingerprint_id = get_card_bin_range_fingerprint_id(&range)?;
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &fingerprint_id,
+                merchant_id,
+                common_enums::BlocklistDataKind::BinRange,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IssuerCountry(country) => {
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &country.to_string(),
+                merchant_id,
+                common_enums::BlocklistDataKind::IssuerCountry,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::CardNetwork(network) => {
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &network.to_string(),
+                merchant_id,
+                common_enums::BlocklistDataKind::CardNetwork,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::EmailDomain(domain) => {
+            let fingerprint_id = get_email_domain_fingerprint_id(&domain)?;
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &fingerprint_id,
+                merchant_id,
+                common_enums::BlocklistDataKind::EmailDomain,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpCidr(cidr) => {
+            let fingerprint_id = get_ip_cidr_fingerprint_id(&cidr)?;
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &fingerprint_id,
+                merchant_id,
+                common_enums::BlocklistDataKind::IpCidr,
+            )
+            .await?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => {
+            delete_blocklist_entry_by_fingerprint_id(
+                state,
+                &fingerprint_id,
+                merchant_id,
+                common_enums::BlocklistDataKind::PaymentMethod,
+            )
+            .await?
+        }
     };
 
     Ok(blocklist_entry.foreign_into())
@@ -131,6 +204,129 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+fn get_card_bin_range_fingerprint_id(range: &api_blocklist::CardBinRange) -> RouterResult<String> {
+    let is_valid_bin =
+        |bin: &str| matches!(bin.len(), 6 | 8) && bin.chars().all(|c| c.is_ascii_digit());
+
+    // Both ends being digits of the same length allows comparing them lexicographically
+    if is_valid_bin(&range.start)
+        && range.start.len() == range.end.len()
+        && is_valid_bin(&range.end)
+    {
+        utils::when(range.start > range.end, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "start of the bin range cannot be greater than its end".to_string(),
+            })
+        })?;
+
+        Ok(format!("{}-{}", range.start, range.end))
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "start and end of the range as 6 or 8 digit numbers of equal length"
+                .to_string(),
+        }
+        .into())
+    }
+}
+
+fn get_email_domain_fingerprint_id(domain: &str) -> RouterResult<String> {
+    let domain = domain.trim().trim_start_matches('@').to_lowercase();
+
+    let is_valid_domain = domain.len() <= consts::BLOCKLIST_FINGERPRINT_ID_MAX_LENGTH
+        && domain.split('.').count() > 1
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if is_valid_domain {
+        Ok(domain)
+    } else {
+        Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a domain name such as example.com".to_string(),
+        }
+        .into())
+    }
+}
+
+fn get_ip_cidr_fingerprint_id(cidr: &str) -> RouterResult<String> {
+    IpNetwork::from_str(cidr.trim())
+        .map(|network| network.to_string())
+        .map_err(|_| {
+            errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "data".to_string(),
+                expected_format: "an IPv4 or IPv6 network in CIDR notation such as 10.0.0.0/8"
+                    .to_string(),
+            }
+            .into()
+        })
+}
+
+/// An IP network in CIDR notation, stored with the host bits of the address cleared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IpNetwork {
+    address: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpNetwork {
+    fn contains(&self, ip_address: IpAddr) -> bool {
+        match (self.address, ip_address) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix_length))
+                    .unwrap_or(0);
+                u32::from(network) == u32::from(address) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix_length))
+                    .unwrap_or(0);
+                u128::from(network) == u128::from(address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (address, prefix_length) = value.split_once('/').ok_or(())?;
+        let address = IpAddr::from_str(address).map_err(|_| ())?;
+        let prefix_length = u8::from_str(prefix_length).map_err(|_| ())?;
+
+        let address = match address {
+            IpAddr::V4(address) if prefix_length <= 32 => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(prefix_length))
+                    .unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(address) & mask))
+            }
+            IpAddr::V6(address) if prefix_length <= 128 => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(prefix_length))
+                    .unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(address) & mask))
+            }
+            _ => return Err(()),
+        };
+
+        Ok(Self {
+            address,
+            prefix_length,
+        })
+    }
+}
+
+impl std::fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_length)
+    }
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -139,7 +335,7 @@ pub async fn insert_entry_into_blocklist(
     let blocklist_entry = match &to_block {
         api_blocklist::AddToBlocklistRequest::CardBin(bin) => {
             validate_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 merchant_id,
@@ -150,7 +346,7 @@ pub async fn insert_entry_into_blocklist(
 
         api_blocklist::AddToBlocklistRequest::ExtendedCardBin(bin) => {
             validate_extended_card_bin(bin)?;
-            duplicate_check_insert_bin(
+            duplicate_check_insert_entry(
                 bin,
                 state,
                 merchant_id,
@@ -159,6 +355,59 @@ pub async fn insert_entry_into_blocklist(
             .await?
         }
 
+        api_blocklist::AddToBlocklistRequest::BinRange(range) => {
+            let fingerprint_id = get_card_bin_range_fingerprint_id(range)?;
+            duplicate_check_insert_entry(
+                &fingerprint_id,
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::BinRange,
+            )
+            .await?
+        }
+
+        api_blocklist::AddToBlocklistRequest::IssuerCountry(country) => {
+            duplicate_check_insert_entry(
+                &country.to_string(),
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::IssuerCountry,
+            )
+            .await?
+        }
+
+        api_blocklist::AddToBlocklistRequest::CardNetwork(network) => {
+            duplicate_check_insert_entry(
+                &network.to_string(),
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::CardNetwork,
+            )
+            .await?
+        }
+
+        api_blocklist::AddToBlocklistRequest::EmailDomain(domain) => {
+            let fingerprint_id = get_email_domain_fingerprint_id(domain)?;
+            duplicate_check_insert_entry(
+                &fingerprint_id,
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::EmailDomain,
+            )
+            .await?
+        }
+
+        api_blocklist::AddToBlocklistRequest::IpCidr(cidr) => {
+            let fingerprint_id = get_ip_cidr_fingerprint_id(cidr)?;
+            duplicate_check_insert_entry(
+                &fingerprint_id,
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::IpCidr,
+            )
+            .await?
+        }
+
         api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
@@ -234,21 +483,21 @@ pub async fn get_merchant_fingerprint_secret(
     }
 }
 
-async fn duplicate_check_insert_bin(
-    bin: &str,
+async fn duplicate_check_insert_entry(
+    fingerprint_id: &str,
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     data_kind: common_enums::BlocklistDataKind,
 ) -> RouterResult<storage::Blocklist> {
     let blocklist_entry_result = state
         .store
-        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await;
 
     match blocklist_entry_result {
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: "provided data is already blocked".to_string(),
             }
             .into());
         }
@@ -262,32 +511,111 @@ async fn duplicate_check_insert_bin(
         }
     }
 
-    state
+    let is_blocklist_rule = BLOCKLIST_RULE_DATA_KINDS.contains(&data_kind);
+    let blocklist_entry = state
         .store
         .insert_blocklist_entry(storage::BlocklistNew {
             merchant_id: merchant_id.to_owned(),
-            fingerprint_id: bin.to_string(),
+            fingerprint_id: fingerprint_id.to_string(),
             data_kind,
             metadata: None,
             created_at: common_utils::date_time::now(),
         })
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error inserting pm blocklist item")
+        .attach_printable("error inserting pm blocklist item")?;
+
+    if is_blocklist_rule {
+        invalidate_blocklist_rules_cache(state, merchant_id).await?;
+    }
+
+    Ok(blocklist_entry)
 }
 
-async fn delete_card_bin_blocklist_entry(
+/// Deletes the blocklist entry only if it is of the given kind, so that the entry of one kind is
+/// not deleted by a request to unblock data of another kind sharing its fingerprint id.
+async fn delete_blocklist_entry_by_fingerprint_id(
     state: &SessionState,
-    bin: &str,
+    fingerprint_id: &str,
     merchant_id: &common_utils::id_type::MerchantId,
+    data_kind: common_enums::BlocklistDataKind,
 ) -> RouterResult<storage::Blocklist> {
-    state
+    let not_found_error = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "could not find a blocklist entry for the given data".to_string(),
+    };
+
+    let blocklist_entry = state
         .store
-        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, bin)
+        .find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
-            message: "could not find a blocklist entry for the given bin".to_string(),
-        })
+        .to_not_found_response(not_found_error())?;
+    utils::when(blocklist_entry.data_kind != data_kind, || {
+        Err(not_found_error())
+    })?;
+
+    let blocklist_entry = state
+        .store
+        .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id)
+        .await
+        .to_not_found_response(not_found_error())?;
+
+    if BLOCKLIST_RULE_DATA_KINDS.contains(&data_kind) {
+        invalidate_blocklist_rules_cache(state, merchant_id).await?;
+    }
+
+    Ok(blocklist_entry)
+}
+
+/// The blocklist data kinds evaluated as rules against the payment, rather than being looked up
+/// by the fingerprints generated from the payment
+const BLOCKLIST_RULE_DATA_KINDS: [common_enums::BlocklistDataKind; 5] = [
+    common_enums::BlocklistDataKind::BinRange,
+    common_enums::BlocklistDataKind::IssuerCountry,
+    common_enums::BlocklistDataKind::CardNetwork,
+    common_enums::BlocklistDataKind::EmailDomain,
+    common_enums::BlocklistDataKind::IpCidr,
+];
+
+fn get_blocklist_rules_cache_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!("blocklist_rules_{}", merchant_id.get_string_repr())
+}
+
+/// Fetches the blocklist rules of the merchant, which are cached as they are evaluated on every
+/// payment confirmation
+async fn get_blocklist_rules(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+    let db = &*state.store;
+    cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &get_blocklist_rules_cache_key(merchant_id),
+        || {
+            db.list_blocklist_entries_by_merchant_id_data_kinds(
+                merchant_id,
+                BLOCKLIST_RULE_DATA_KINDS.to_vec(),
+            )
+        },
+        &cache::CONFIG_CACHE,
+    )
+    .await
+}
+
+async fn invalidate_blocklist_rules_cache(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<()> {
+    cache::redact_from_redis_and_publish(
+        state.store.get_cache_store().as_ref(),
+        [cache::CacheKind::Config(
+            get_blocklist_rules_cache_key(merchant_id).into(),
+        )],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to invalidate the blocklist rules cache")?;
+
+    Ok(())
 }
 
 pub async fn validate_data_for_blocklist<F>(
@@ -374,7 +702,13 @@ where
 
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
-    let mut should_payment_be_blocked = false;
+    let mut should_payment_be_blocked =
+        is_payment_blocked_by_rules(state, payment_data, merchant_id)
+            .await
+            .unwrap_or_else(|e| {
+                logger::error!(blocklist_db_error=?e, "failed db operations for blocklist rules");
+                false
+            });
     for lookup in blocklist_lookups {
         match lookup {
             Ok(_) => {
@@ -446,6 +780,135 @@ where
     }
 }
 
+/// Evaluates the range and attribute based blocklist entries (bin ranges, issuer countries,
+/// card networks, email domains and ip networks) of the merchant against the payment.
+async fn is_payment_blocked_by_rules<F>(
+    state: &SessionState,
+    payment_data: &PaymentData<F>,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<bool, errors::StorageError>
+where
+    F: Send + Clone,
+{
+    let blocklist_rules = get_blocklist_rules(state, merchant_id).await?;
+
+    if blocklist_rules.is_empty() {
+        return Ok(false);
+    }
+
+    let card = match payment_data.payment_method_data.as_ref() {
+        Some(domain::PaymentMethodData::Card(card)) => Some(card),
+        _ => None,
+    };
+
+    let email_domain = payment_data.email.as_ref().and_then(|email| {
+        email
+            .peek()
+            .rsplit_once('@')
+            .map(|(_, domain)| domain.to_lowercase())
+    });
+
+    let ip_address = get_payment_ip_address(payment_data);
+
+    let is_blocked = blocklist_rules.iter().any(|rule| match rule.data_kind {
+        common_enums::BlocklistDataKind::BinRange => card.is_some_and(|card| {
+            rule.fingerprint_id
+                .split_once('-')
+                .is_some_and(|(start, end)| {
+                    // Compare the range against a bin of the same length as its bounds
+                    let bin = if start.len() == 8 {
+                        card.card_number.get_extended_card_bin()
+                    } else {
+                        card.card_number.get_card_isin()
+                    };
+                    bin.as_str() >= start && bin.as_str() <= end
+                })
+        }),
+        common_enums::BlocklistDataKind::IssuerCountry => card
+            .and_then(|card| card.card_issuing_country.as_deref())
+            .is_some_and(|issuing_country| {
+                is_issuing_country_blocked(issuing_country, &rule.fingerprint_id)
+            }),
+        common_enums::BlocklistDataKind::CardNetwork => card
+            .and_then(|card| card.card_network.as_ref())
+            .is_some_and(|card_network| {
+                card_network
+                    .to_string()
+                    .eq_ignore_ascii_case(&rule.fingerprint_id)
+            }),
+        common_enums::BlocklistDataKind::EmailDomain => {
+            email_domain.as_ref().is_some_and(|email_domain| {
+                email_domain == &rule.fingerprint_id
+                    || email_domain.ends_with(&format!(".{}", rule.fingerprint_id))
+            })
+        }
+        common_enums::BlocklistDataKind::IpCidr => ip_address.is_some_and(|ip_address| {
+            IpNetwork::from_str(&rule.fingerprint_id)
+                .map(|network| network.contains(ip_address))
+                .unwrap_or(false)
+        }),
+        common_enums::BlocklistDataKind::PaymentMethod
+        | common_enums::BlocklistDataKind::CardBin
+        | common_enums::BlocklistDataKind::ExtendedCardBin => false,
+    });
+
+    Ok(is_blocked)
+}
+
+/// The issuing country of a card is not normalized across card info sources, so it is compared
+/// against the alpha-2 code, the alpha-3 code and the name of the blocked country.
+fn is_issuing_country_blocked(issuing_country: &str, blocked_country: &str) -> bool {
+    let Ok(blocked_country) = common_enums::CountryAlpha2::from_str(blocked_country) else {
+        return false;
+    };
+    let country = common_enums::Country::from_alpha2(blocked_country);
+    let normalize = |value: &str| {
+        value
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .collect::<String>()
+            .to_uppercase()
+    };
+    let issuing_country = normalize(issuing_country);
+
+    [
+        blocked_country.to_string(),
+        country.to_alpha3().to_string(),
+        country.to_string(),
+    ]
+    .iter()
+    .any(|candidate| normalize(candidate) == issuing_country)
+}
+
+#[cfg(feature = "v1")]
+fn get_payment_ip_address<F>(payment_data: &PaymentData<F>) -> Option<IpAddr>
+where
+    F: Send + Clone,
+{
+    payment_data
+        .payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            browser_info
+                .parse_value::<common_utils::types::BrowserInformation>("BrowserInformation")
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address)
+}
+
+#[cfg(feature = "v2")]
+fn get_payment_ip_address<F>(payment_data: &PaymentData<F>) -> Option<IpAddr>
+where
+    F: Send + Clone,
+{
+    payment_data
+        .payment_attempt
+        .browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_address)
+}
+
 pub async fn generate_payment_fingerprint(
     state: &SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -477,3 +940,116 @@ pub async fn generate_payment_fingerprint(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_ip_network_from_str() {
+        let network = IpNetwork::from_str("192.168.12.34/16").unwrap();
+        assert_eq!(network.to_string(), "192.168.0.0/16");
+
+        let network = IpNetwork::from_str("2001:db8::1/32").unwrap();
+        assert_eq!(network.to_string(), "2001:db8::/32");
+
+        assert_eq!(
+            IpNetwork::from_str("0.0.0.0/0").unwrap().to_string(),
+            "0.0.0.0/0"
+        );
+        assert_eq!(
+            IpNetwork::from_str("10.1.2.3/32").unwrap().to_string(),
+            "10.1.2.3/32"
+        );
+
+        assert!(IpNetwork::from_str("10.0.0.0").is_err());
+        assert!(IpNetwork::from_str("10.0.0.0/33").is_err());
+        assert!(IpNetwork::from_str("2001:db8::/129").is_err());
+        assert!(IpNetwork::from_str("10.0.0/8").is_err());
+        assert!(IpNetwork::from_str("10.0.0.0/abc").is_err());
+    }
+
+    #[test]
+    fn test_ip_network_contains() {
+        let network = IpNetwork::from_str("10.20.0.0/16").unwrap();
+        assert!(network.contains(IpAddr::from_str("10.20.255.1").unwrap()));
+        assert!(!network.contains(IpAddr::from_str("10.21.0.1").unwrap()));
+        assert!(!network.contains(IpAddr::from_str("::ffff:10.20.0.1").unwrap()));
+
+        let network = IpNetwork::from_str("2001:db8::/32").unwrap();
+        assert!(network.contains(IpAddr::from_str("2001:db8:ffff::1").unwrap()));
+        assert!(!network.contains(IpAddr::from_str("2001:db9::1").unwrap()));
+
+        let network = IpNetwork::from_str("0.0.0.0/0").unwrap();
+        assert!(network.contains(IpAddr::from_str("203.0.113.7").unwrap()));
+
+        let network = IpNetwork::from_str("203.0.113.7/32").unwrap();
+        assert!(network.contains(IpAddr::from_str("203.0.113.7").unwrap()));
+        assert!(!network.contains(IpAddr::from_str("203.0.113.8").unwrap()));
+    }
+
+    #[test]
+    fn test_get_card_bin_range_fingerprint_id() {
+        let range = |start: &str, end: &str| api_blocklist::CardBinRange {
+            start: start.to_string(),
+            end: end.to_string(),
+        };
+
+        assert_eq!(
+            get_card_bin_range_fingerprint_id(&range("411111", "411199")).unwrap(),
+            "411111-411199"
+        );
+        assert_eq!(
+            get_card_bin_range_fingerprint_id(&range("41111111", "41111111")).unwrap(),
+            "41111111-41111111"
+        );
+
+        assert!(get_card_bin_range_fingerprint_id(&range("411199", "411111")).is_err());
+        assert!(get_card_bin_range_fingerprint_id(&range("411111", "41119999")).is_err());
+        assert!(get_card_bin_range_fingerprint_id(&range("4111", "4199")).is_err());
+        assert!(get_card_bin_range_fingerprint_id(&range("41111a", "411199")).is_err());
+    }
+
+    #[test]
+    fn test_get_email_domain_fingerprint_id() {
+        assert_eq!(
+            get_email_domain_fingerprint_id(" @Example.COM ").unwrap(),
+            "example.com"
+        );
+        assert_eq!(
+            get_email_domain_fingerprint_id("mail.example-domain.co.uk").unwrap(),
+            "mail.example-domain.co.uk"
+        );
+
+        assert!(get_email_domain_fingerprint_id("localhost").is_err());
+        assert!(get_email_domain_fingerprint_id("example..com").is_err());
+        assert!(get_email_domain_fingerprint_id("exa_mple.com").is_err());
+        assert!(get_email_domain_fingerprint_id(&format!("{}.com", "a".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn test_get_ip_cidr_fingerprint_id() {
+        assert_eq!(
+            get_ip_cidr_fingerprint_id(" 172.16.5.4/12 ").unwrap(),
+            "172.16.0.0/12"
+        );
+        assert_eq!(
+            get_ip_cidr_fingerprint_id("2001:DB8::/48").unwrap(),
+            "2001:db8::/48"
+        );
+
+        assert!(get_ip_cidr_fingerprint_id("172.16.5.4").is_err());
+        assert!(get_ip_cidr_fingerprint_id("example.com/8").is_err());
+    }
+
+    #[test]
+    fn test_is_issuing_country_blocked() {
+        assert!(is_issuing_country_blocked("US", "US"));
+        assert!(is_issuing_country_blocked("usa", "US"));
+        assert!(is_issuing_country_blocked("United States of America", "US"));
+        assert!(!is_issuing_country_blocked("CA", "US"));
+        assert!(!is_issuing_country_blocked("US", "not_a_country"));
+    }
+}
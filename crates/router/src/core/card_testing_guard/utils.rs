@@ -1,3 +1,7 @@
+use std::{collections::HashSet, net::IpAddr};
+
+use common_types::payments::TriggeredVelocityRule;
+use common_utils::{pii, types::MinorUnit};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     card_testing_guard_data::CardTestingGuardData, router_request_types::BrowserInformation,
@@ -7,23 +11,60 @@ use router_env::logger;
 
 use super::errors;
 use crate::{
+    consts,
     core::{errors::RouterResult, payments::helpers},
-    routes::SessionState,
+    routes::{metrics, SessionState},
     services,
-    types::{api, domain},
+    types::{api, domain, storage::enums},
     utils::crypto::{self, SignMessage},
 };
 
+/// Records the attempt in the sliding window of a velocity rule and returns the aggregate of the
+/// attempts recorded in the window before it. Every member of the sorted set is an attempt scored
+/// by the time it was made, holding the value it adds to the aggregate. Attempts are counted with
+/// `ZCARD`, while amounts are summed into a separate key as they are recorded, so that only the
+/// attempts sliding out of the window have to be read.
+const VELOCITY_WINDOW_SCRIPT: &str = r#"
+local window = tonumber(ARGV[1])
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local aggregate
+if ARGV[4] == "count" then
+    redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", now - window)
+    aggregate = redis.call("ZCARD", KEYS[1])
+else
+    aggregate = tonumber(redis.call("GET", KEYS[2]) or "0")
+    for _, member in ipairs(redis.call("ZRANGEBYSCORE", KEYS[1], "-inf", now - window)) do
+        aggregate = aggregate - tonumber(string.match(member, "^(%d+):"))
+    end
+    redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", now - window)
+    aggregate = math.max(aggregate, 0)
+    redis.call("SET", KEYS[2], aggregate + tonumber(ARGV[2]), "PX", window)
+end
+redis.call("ZADD", KEYS[1], now, ARGV[2] .. ":" .. ARGV[3])
+redis.call("PEXPIRE", KEYS[1], window)
+return aggregate
+"#;
+
+/// Details of the payment that the velocity rules aggregate over
+pub struct PaymentVelocityDetails<'a> {
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub email: Option<&'a pii::Email>,
+}
+
 pub async fn validate_card_testing_guard_checks(
     state: &SessionState,
     request: &api::PaymentsRequest,
     payment_method_data: Option<&api_models::payments::PaymentMethodData>,
     customer_id: &Option<common_utils::id_type::CustomerId>,
     business_profile: &domain::Profile,
+    payment_details: PaymentVelocityDetails<'_>,
 ) -> RouterResult<Option<CardTestingGuardData>> {
     match &business_profile.card_testing_guard_config {
         Some(card_testing_guard_config) => {
             let fingerprint = generate_fingerprint(payment_method_data, business_profile).await?;
+            let browser_info = get_browser_info(request)?;
 
             let card_testing_guard_expiry = card_testing_guard_config.card_testing_guard_expiry;
 
@@ -32,39 +73,18 @@ pub async fn validate_card_testing_guard_checks(
             let mut customer_id_blocking_cache_key = String::new();
 
             if card_testing_guard_config.is_card_ip_blocking_enabled {
-                if let Some(browser_info) = &request.browser_info {
-                    #[cfg(feature = "v1")]
-                    {
-                        let browser_info =
-                            serde_json::from_value::<BrowserInformation>(browser_info.clone())
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("could not parse browser_info")?;
-
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
-
-                    #[cfg(feature = "v2")]
-                    {
-                        if let Some(browser_info_ip) = browser_info.ip_address {
-                            card_ip_blocking_cache_key =
-                                helpers::validate_card_ip_blocking_for_business_profile(
-                                    state,
-                                    browser_info_ip,
-                                    fingerprint.clone(),
-                                    card_testing_guard_config,
-                                )
-                                .await?;
-                        }
-                    }
+                if let Some(browser_info_ip) = browser_info
+                    .as_ref()
+                    .and_then(|browser_info| browser_info.ip_address)
+                {
+                    card_ip_blocking_cache_key =
+                        helpers::validate_card_ip_blocking_for_business_profile(
+                            state,
+                            browser_info_ip,
+                            fingerprint.clone(),
+                            card_testing_guard_config,
+                        )
+                        .await?;
                 }
             }
 
@@ -92,6 +112,35 @@ pub async fn validate_card_testing_guard_checks(
                 }
             }
 
+            let triggered_velocity_rules = if card_testing_guard_config.velocity_rules.is_empty() {
+                Vec::new()
+            } else {
+                let attributes = VelocityRuleAttributes {
+                    fingerprint: &fingerprint,
+                    card_bin: payment_method_data.and_then(|pm_data| match pm_data {
+                        api_models::payments::PaymentMethodData::Card(card) => {
+                            Some(card.card_number.get_card_isin())
+                        }
+                        _ => None,
+                    }),
+                    ip_address: browser_info
+                        .as_ref()
+                        .and_then(|browser_info| browser_info.ip_address),
+                    email: payment_details.email,
+                    customer_id: customer_id.as_ref(),
+                    device_id: browser_info.as_ref().and_then(get_device_id),
+                };
+
+                evaluate_velocity_rules(
+                    state,
+                    &card_testing_guard_config.velocity_rules,
+                    business_profile,
+                    &attributes,
+                    &payment_details,
+                )
+                .await?
+            };
+
             Ok(Some(CardTestingGuardData {
                 is_card_ip_blocking_enabled: card_testing_guard_config.is_card_ip_blocking_enabled,
                 card_ip_blocking_cache_key,
@@ -102,12 +151,277 @@ pub async fn validate_card_testing_guard_checks(
                     .is_customer_id_blocking_enabled,
                 customer_id_blocking_cache_key,
                 card_testing_guard_expiry,
+                triggered_velocity_rules,
             }))
         }
         None => Ok(None),
     }
 }
 
+#[cfg(feature = "v1")]
+fn get_browser_info(request: &api::PaymentsRequest) -> RouterResult<Option<BrowserInformation>> {
+    request
+        .browser_info
+        .clone()
+        .map(serde_json::from_value::<BrowserInformation>)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("could not parse browser_info")
+}
+
+#[cfg(feature = "v2")]
+fn get_browser_info(request: &api::PaymentsRequest) -> RouterResult<Option<BrowserInformation>> {
+    Ok(request.browser_info.clone().map(BrowserInformation::from))
+}
+
+/// Identifies the device by the attributes of the browser information that do not change
+/// between the payments made from it. Payments without a user agent are not attributed to any
+/// device.
+fn get_device_id(browser_info: &BrowserInformation) -> Option<String> {
+    browser_info.user_agent.as_ref().map(|user_agent| {
+        [
+            Some(user_agent.clone()),
+            browser_info.accept_language.clone(),
+            browser_info.language.clone(),
+            browser_info.screen_height.map(|height| height.to_string()),
+            browser_info.screen_width.map(|width| width.to_string()),
+            browser_info.color_depth.map(|depth| depth.to_string()),
+            browser_info
+                .time_zone
+                .map(|time_zone| time_zone.to_string()),
+            browser_info.os_type.clone(),
+            browser_info.os_version.clone(),
+            browser_info.device_model.clone(),
+        ]
+        .into_iter()
+        .map(Option::unwrap_or_default)
+        .collect::<Vec<_>>()
+        .join(",")
+    })
+}
+
+struct VelocityRuleAttributes<'a> {
+    fingerprint: &'a Secret<String>,
+    card_bin: Option<String>,
+    ip_address: Option<IpAddr>,
+    email: Option<&'a pii::Email>,
+    customer_id: Option<&'a common_utils::id_type::CustomerId>,
+    device_id: Option<String>,
+}
+
+impl VelocityRuleAttributes<'_> {
+    fn get_value(&self, key: enums::VelocityRuleKey) -> Option<String> {
+        match key {
+            enums::VelocityRuleKey::CardFingerprint => Some(self.fingerprint.peek().clone()),
+            enums::VelocityRuleKey::IpAddress => self.ip_address.map(|ip| ip.to_string()),
+            enums::VelocityRuleKey::Email => self.email.map(|email| email.peek().clone()),
+            enums::VelocityRuleKey::CustomerId => self
+                .customer_id
+                .map(|customer_id| customer_id.get_string_repr().to_owned()),
+            enums::VelocityRuleKey::CardBin => self.card_bin.clone(),
+            enums::VelocityRuleKey::DeviceId => self.device_id.clone(),
+        }
+    }
+}
+
+/// Records the payment in the window of every velocity rule applicable to it, failing with
+/// [`errors::ApiErrorResponse::PreconditionFailed`] when it exceeds a rule that blocks payments.
+/// Rules whose keys are not all available for the payment are skipped, and rules whose window
+/// cannot be read from redis are not enforced.
+async fn evaluate_velocity_rules(
+    state: &SessionState,
+    velocity_rules: &[diesel_models::business_profile::VelocityRule],
+    business_profile: &domain::Profile,
+    attributes: &VelocityRuleAttributes<'_>,
+    payment_details: &PaymentVelocityDetails<'_>,
+) -> RouterResult<Vec<TriggeredVelocityRule>> {
+    let card_testing_secret_key = business_profile
+        .card_testing_secret_key
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("card testing secret key not configured")?;
+
+    let mut triggered_velocity_rules = Vec::new();
+    for velocity_rule in velocity_rules {
+        let Some(mut identifier) = velocity_rule
+            .keys
+            .iter()
+            .map(|key| {
+                attributes
+                    .get_value(*key)
+                    .map(|value| format!("{key}={value}"))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            logger::debug!(
+                velocity_rule = %velocity_rule.name,
+                "Skipping velocity rule as the payment does not have all of its keys"
+            );
+            continue;
+        };
+
+        // Amounts in different currencies are aggregated separately
+        let value = match velocity_rule.aggregation {
+            enums::VelocityAggregation::Count => 1,
+            enums::VelocityAggregation::SumOfAmount => {
+                identifier.push(format!("currency={}", payment_details.currency));
+                payment_details.amount.get_amount_as_i64()
+            }
+        };
+
+        // The identifier is hashed so that the values of the keys are not stored in redis
+        let hashed_identifier = crypto::HmacSha512::sign_message(
+            &crypto::HmacSha512,
+            card_testing_secret_key.get_inner().peek().as_bytes(),
+            identifier.join("|").as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error in velocity rule identifier creation")?;
+
+        let cache_key = format!(
+            "{}_{}_{}_{}",
+            consts::VELOCITY_RULE_CACHE_KEY_PREFIX,
+            business_profile.get_id().get_string_repr(),
+            velocity_rule.name,
+            hex::encode(hashed_identifier)
+        );
+
+        let aggregate = match record_attempt_in_velocity_window(
+            state,
+            &cache_key,
+            velocity_rule.window_in_secs,
+            velocity_rule.aggregation,
+            value,
+        )
+        .await
+        {
+            Ok(aggregate) => aggregate,
+            Err(error) => {
+                metrics::VELOCITY_RULE_EVALUATION_FAILURES.add(1, &[]);
+                logger::error!(
+                    ?error,
+                    velocity_rule = %velocity_rule.name,
+                    "Failed to evaluate velocity rule"
+                );
+                continue;
+            }
+        };
+
+        if aggregate.saturating_add(value) > velocity_rule.threshold {
+            metrics::VELOCITY_RULE_TRIGGERED.add(
+                1,
+                router_env::metric_attributes!(("action", velocity_rule.action.to_string())),
+            );
+            logger::warn!(
+                velocity_rule = %velocity_rule.name,
+                action = %velocity_rule.action,
+                "Payment exceeded velocity rule"
+            );
+            triggered_velocity_rules.push(TriggeredVelocityRule {
+                name: velocity_rule.name.clone(),
+                action: velocity_rule.action,
+            });
+        }
+    }
+
+    // The payment is recorded in the windows of all the rules before being blocked, so that a
+    // client retrying the payment stays blocked until its attempts slide out of the window
+    if triggered_velocity_rules
+        .iter()
+        .any(|rule| rule.action == enums::VelocityRuleAction::Block)
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Blocked due to suspicious activity".to_string(),
+        })?
+    }
+
+    Ok(triggered_velocity_rules)
+}
+
+async fn record_attempt_in_velocity_window(
+    state: &SessionState,
+    cache_key: &str,
+    window_in_secs: u32,
+    aggregation: enums::VelocityAggregation,
+    value: i64,
+) -> RouterResult<i64> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The keys share a hash tag so that they are stored on the same node of a redis cluster
+    redis_conn
+        .evaluate_redis_script::<_, i64>(
+            VELOCITY_WINDOW_SCRIPT,
+            vec![
+                redis_conn.add_prefix(&format!("{{{cache_key}}}")),
+                redis_conn.add_prefix(&format!("{{{cache_key}}}_sum")),
+            ],
+            vec![
+                (u64::from(window_in_secs) * 1000).to_string(),
+                value.to_string(),
+                uuid::Uuid::new_v4().to_string(),
+                aggregation.to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate the velocity window script")
+}
+
+pub fn validate_velocity_rules(
+    velocity_rules: &[api_models::admin::VelocityRule],
+) -> RouterResult<()> {
+    let invalid_request = |message: String| -> RouterResult<()> {
+        Err(errors::ApiErrorResponse::InvalidRequestData { message }.into())
+    };
+
+    if velocity_rules.len() > consts::MAX_VELOCITY_RULES_PER_PROFILE {
+        return invalid_request(format!(
+            "at most {} velocity rules can be configured",
+            consts::MAX_VELOCITY_RULES_PER_PROFILE
+        ));
+    }
+
+    let mut rule_names = HashSet::new();
+    for velocity_rule in velocity_rules {
+        if velocity_rule.name.trim().is_empty() {
+            return invalid_request("velocity rule `name` must not be empty".to_string());
+        }
+        if !rule_names.insert(velocity_rule.name.as_str()) {
+            return invalid_request(format!(
+                "velocity rule `{}` is configured more than once",
+                velocity_rule.name
+            ));
+        }
+
+        let unique_keys = velocity_rule.keys.iter().collect::<HashSet<_>>();
+        if unique_keys.is_empty() || unique_keys.len() != velocity_rule.keys.len() {
+            return invalid_request(format!(
+                "`keys` of velocity rule `{}` must be non empty and unique",
+                velocity_rule.name
+            ));
+        }
+        if velocity_rule.threshold <= 0 {
+            return invalid_request(format!(
+                "`threshold` of velocity rule `{}` must be greater than 0",
+                velocity_rule.name
+            ));
+        }
+        if !(1..=consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS).contains(&velocity_rule.window_in_secs) {
+            return invalid_request(format!(
+                "`window_in_secs` of velocity rule `{}` must be between 1 and {}",
+                velocity_rule.name,
+                consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn generate_fingerprint(
     payment_method_data: Option<&api_models::payments::PaymentMethodData>,
     business_profile: &domain::Profile,
@@ -194,3 +508,130 @@ pub async fn increment_blocked_count_in_cache(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn velocity_rule(name: &str) -> api_models::admin::VelocityRule {
+        api_models::admin::VelocityRule {
+            name: name.to_string(),
+            keys: vec![
+                enums::VelocityRuleKey::CardFingerprint,
+                enums::VelocityRuleKey::IpAddress,
+            ],
+            aggregation: enums::VelocityAggregation::Count,
+            threshold: 5,
+            window_in_secs: 3600,
+            action: enums::VelocityRuleAction::Block,
+        }
+    }
+
+    fn get_error_message(velocity_rules: &[api_models::admin::VelocityRule]) -> String {
+        match validate_velocity_rules(velocity_rules)
+            .unwrap_err()
+            .current_context()
+        {
+            errors::ApiErrorResponse::InvalidRequestData { message } => message.clone(),
+            error => format!("unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_velocity_rules_accepts_valid_rules() {
+        assert!(validate_velocity_rules(&[]).is_ok());
+        assert!(
+            validate_velocity_rules(&[velocity_rule("hourly"), velocity_rule("daily")]).is_ok()
+        );
+
+        let mut rule = velocity_rule("monthly");
+        rule.window_in_secs = consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS;
+        assert!(validate_velocity_rules(&[rule]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_velocity_rules_rejects_too_many_rules() {
+        let velocity_rules = (0..=consts::MAX_VELOCITY_RULES_PER_PROFILE)
+            .map(|index| velocity_rule(&format!("rule_{index}")))
+            .collect::<Vec<_>>();
+
+        assert!(get_error_message(&velocity_rules).starts_with("at most"));
+    }
+
+    #[test]
+    fn test_validate_velocity_rules_rejects_invalid_names() {
+        assert_eq!(
+            get_error_message(&[velocity_rule("  ")]),
+            "velocity rule `name` must not be empty"
+        );
+        assert_eq!(
+            get_error_message(&[velocity_rule("hourly"), velocity_rule("hourly")]),
+            "velocity rule `hourly` is configured more than once"
+        );
+    }
+
+    #[test]
+    fn test_validate_velocity_rules_rejects_invalid_keys() {
+        let mut empty_keys = velocity_rule("hourly");
+        empty_keys.keys = vec![];
+        let mut duplicate_keys = velocity_rule("hourly");
+        duplicate_keys.keys = vec![enums::VelocityRuleKey::Email, enums::VelocityRuleKey::Email];
+
+        for rule in [empty_keys, duplicate_keys] {
+            assert_eq!(
+                get_error_message(&[rule]),
+                "`keys` of velocity rule `hourly` must be non empty and unique"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_velocity_rules_rejects_invalid_threshold_and_window() {
+        let mut zero_threshold = velocity_rule("hourly");
+        zero_threshold.threshold = 0;
+        assert_eq!(
+            get_error_message(&[zero_threshold]),
+            "`threshold` of velocity rule `hourly` must be greater than 0"
+        );
+
+        for window_in_secs in [0, consts::MAX_VELOCITY_RULE_WINDOW_IN_SECS + 1] {
+            let mut rule = velocity_rule("hourly");
+            rule.window_in_secs = window_in_secs;
+            assert!(get_error_message(&[rule])
+                .starts_with("`window_in_secs` of velocity rule `hourly` must be between 1 and"));
+        }
+    }
+
+    #[test]
+    fn test_get_device_id() {
+        assert_eq!(get_device_id(&BrowserInformation::default()), None);
+
+        let browser_info = BrowserInformation {
+            user_agent: Some("Mozilla/5.0".to_string()),
+            accept_language: Some("en-US".to_string()),
+            screen_height: Some(1080),
+            screen_width: Some(1920),
+            time_zone: Some(-330),
+            ..Default::default()
+        };
+        assert_eq!(
+            get_device_id(&browser_info).unwrap(),
+            "Mozilla/5.0,en-US,,1080,1920,,-330,,,"
+        );
+
+        // The IP address does not identify the device, as it changes between networks
+        let other_network = BrowserInformation {
+            ip_address: Some(std::net::Ipv4Addr::LOCALHOST.into()),
+            ..browser_info.clone()
+        };
+        assert_eq!(get_device_id(&other_network), get_device_id(&browser_info));
+
+        let other_device = BrowserInformation {
+            screen_width: Some(1280),
+            ..browser_info.clone()
+        };
+        assert_ne!(get_device_id(&other_device), get_device_id(&browser_info));
+    }
+}
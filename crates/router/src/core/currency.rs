@@ -30,6 +30,7 @@ pub async fn convert_forex(
     amount: i64,
     to_currency: String,
     from_currency: String,
+    as_of: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<
     ApplicationResponse<api_models::currency::CurrencyConversionResponse>,
     ApiErrorResponse,
@@ -40,6 +41,7 @@ pub async fn convert_forex(
             amount,
             to_currency,
             from_currency,
+            as_of,
         ))
         .await
         .change_context(ApiErrorResponse::InternalServerError)?,
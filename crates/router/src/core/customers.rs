@@ -834,7 +834,7 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             .await
             .switch()?;
 
-        for mandate in customer_mandates.into_iter() {
+        for mandate in customer_mandates.iter() {
             if mandate.mandate_status == enums::MandateStatus::Active {
                 Err(errors::CustomersErrorResponse::MandateActive)?
             }
@@ -887,6 +887,7 @@ impl CustomerDeleteBridge for id_type::CustomerId {
                         merchant_context.get_merchant_key_store(),
                         merchant_context.get_merchant_account().get_id(),
                         &pm.payment_method_id,
+                        merchant_context.get_merchant_account().storage_scheme,
                     )
                     .await
                     .change_context(errors::CustomersErrorResponse::InternalServerError)
@@ -908,6 +909,19 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             }
         };
 
+        // The remaining mandates of the customer are inactive, and refer to the deleted payment
+        // methods
+        for mandate in customer_mandates {
+            db.delete_mandate_by_merchant_id_mandate_id(
+                merchant_context.get_merchant_account().get_id(),
+                &mandate.mandate_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("failed to delete mandate while redacting customer details")?;
+        }
+
         let key = merchant_context
             .get_merchant_key_store()
             .key
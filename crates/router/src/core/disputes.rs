@@ -5,8 +5,14 @@ use api_models::{
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use router_env::logger;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod auto_response;
+#[cfg(feature = "v1")]
+pub mod deadline_reminder;
 pub mod transformers;
 
 use super::{
@@ -26,6 +32,8 @@ use crate::{
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
 };
+#[cfg(feature = "v1")]
+use crate::{db::StorageInterface, types::storage};
 
 #[instrument(skip(state))]
 pub async fn retrieve_dispute(
@@ -600,3 +608,52 @@ pub async fn get_aggregates_for_disputes(
         },
     ))
 }
+
+/// Schedules a process tracker task for the dispute. The dispute id is used in the task id so that
+/// repeated webhooks for the same dispute do not schedule the task more than once.
+#[cfg(feature = "v1")]
+pub async fn add_dispute_process_tracker_task<T>(
+    db: &dyn StorageInterface,
+    runner: storage::ProcessTrackerRunner,
+    task: &'static str,
+    tag: &'static str,
+    dispute_id: &str,
+    tracking_data: T,
+    schedule_time: time::PrimitiveDateTime,
+) -> errors::RouterResult<()>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    let process_tracker_id = format!("{runner}_{task}_{dispute_id}");
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        [tag],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to construct {task} process tracker task"))?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!(
+                %dispute_id,
+                "{task} task has already been scheduled for the dispute"
+            );
+            Ok(())
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while inserting {task} task to process_tracker for dispute_id: {dispute_id}"
+                )
+            }),
+    }
+}
@@ -0,0 +1,528 @@
+use api_models::disputes::{
+    self as dispute_models, DisputeAutoResponseAction, DisputeAutoResponseConfigRecord,
+    DisputeAutoResponseConfigRequest, DisputeAutoResponseOutput, DisputeEvidenceTemplate,
+};
+use common_utils::ext_traits::{Encode, OptionExt, StringExt, ValueExt};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    frontend::ast,
+};
+use hyperswitch_domain_models::payments::payment_intent::CustomerData;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        metrics,
+        payments::helpers as payments_helpers,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        self, api, domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const DISPUTE_AUTO_RESPONSE_TASK: &str = "DISPUTE_AUTO_RESPONSE";
+pub const DISPUTE_AUTO_RESPONSE_TAG: &str = "DISPUTE";
+
+#[instrument(skip(state))]
+pub async fn upsert_dispute_auto_response_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: DisputeAutoResponseConfigRequest,
+) -> RouterResponse<DisputeAutoResponseConfigRecord> {
+    let db = state.store.as_ref();
+    let program = request
+        .algorithm
+        .get_required_value("algorithm")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "algorithm",
+        })
+        .attach_printable("Program for config not given")?;
+
+    ast::lowering::lower_program(program.clone())
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid Request Data".to_string(),
+        })
+        .attach_printable("The Request has an Invalid Comparison")?;
+    validate_evidence_templates(&program, &request.evidence_templates)?;
+
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_dispute_auto_response_config_key();
+
+    match db.find_config_by_key(&key).await {
+        Ok(config) => {
+            let previous_record: DisputeAutoResponseConfigRecord = config
+                .config
+                .parse_struct("DisputeAutoResponseConfigRecord")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to parse the dispute auto response config")?;
+
+            let record = DisputeAutoResponseConfigRecord {
+                name: request.name.unwrap_or(previous_record.name),
+                algorithm: program,
+                evidence_templates: request.evidence_templates,
+                evidence_submission_buffer_in_secs: request
+                    .evidence_submission_buffer_in_secs
+                    .unwrap_or(previous_record.evidence_submission_buffer_in_secs),
+                created_at: previous_record.created_at,
+                modified_at: timestamp,
+            };
+
+            let serialized_record = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize the dispute auto response config")?;
+
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_record),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the dispute auto response config")?;
+
+            Ok(services::ApplicationResponse::Json(record))
+        }
+        Err(e) if e.current_context().is_db_not_found() => {
+            let record = DisputeAutoResponseConfigRecord {
+                name: request
+                    .name
+                    .get_required_value("name")
+                    .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "name",
+                    })
+                    .attach_printable("name of the config not found")?,
+                algorithm: program,
+                evidence_templates: request.evidence_templates,
+                evidence_submission_buffer_in_secs: request
+                    .evidence_submission_buffer_in_secs
+                    .unwrap_or(dispute_models::DEFAULT_EVIDENCE_SUBMISSION_BUFFER_IN_SECS),
+                created_at: timestamp,
+                modified_at: timestamp,
+            };
+
+            let serialized_record = record
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize the dispute auto response config")?;
+
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_record,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the dispute auto response config")?;
+
+            Ok(services::ApplicationResponse::Json(record))
+        }
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dispute auto response config"),
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_dispute_auto_response_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<DisputeAutoResponseConfigRecord> {
+    let record = find_dispute_auto_response_config(
+        state.store.as_ref(),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+    .attach_printable("The dispute auto response config was not found in the DB")?;
+
+    Ok(services::ApplicationResponse::Json(record))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_dispute_auto_response_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<()> {
+    let key = merchant_context
+        .get_merchant_account()
+        .get_id()
+        .get_dispute_auto_response_config_key();
+
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+        .attach_printable("Failed to delete the dispute auto response config")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Evaluates the dispute auto response rules of the merchant for a newly opened dispute and
+/// schedules the resulting action to be executed by the scheduler.
+#[instrument(skip_all)]
+pub async fn trigger_dispute_auto_response(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &storage::Dispute,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened)
+    {
+        return Ok(());
+    }
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let Some(config) = find_dispute_auto_response_config(db, merchant_id).await? else {
+        return Ok(());
+    };
+
+    let action = get_dispute_auto_response_action(&config, dispute, payment_attempt)?;
+    let now = common_utils::date_time::now();
+    let schedule_time = match &action {
+        DisputeAutoResponseAction::ManualReview => {
+            logger::info!(
+                dispute_id = %dispute.dispute_id,
+                "Dispute left for manual review by the dispute auto response rules"
+            );
+            return Ok(());
+        }
+        DisputeAutoResponseAction::Accept => now,
+        // Submitting the evidence closer to the deadline lets the merchant attach additional
+        // evidence for the dispute in the meantime
+        DisputeAutoResponseAction::SubmitEvidence { .. } => dispute
+            .challenge_required_by
+            .map(|challenge_required_by| {
+                challenge_required_by.saturating_sub(time::Duration::seconds(i64::from(
+                    config.evidence_submission_buffer_in_secs,
+                )))
+            })
+            .filter(|submission_time| *submission_time > now)
+            .unwrap_or(now),
+    };
+
+    super::add_dispute_process_tracker_task(
+        db,
+        storage::ProcessTrackerRunner::DisputeAutoResponseWorkflow,
+        DISPUTE_AUTO_RESPONSE_TASK,
+        DISPUTE_AUTO_RESPONSE_TAG,
+        &dispute.dispute_id,
+        storage::DisputeAutoResponseTrackingData {
+            merchant_id: merchant_id.clone(),
+            dispute_id: dispute.dispute_id.clone(),
+            action,
+        },
+        schedule_time,
+    )
+    .await?;
+    metrics::DISPUTE_AUTO_RESPONSE_SCHEDULED_METRIC.add(1, &[]);
+
+    Ok(())
+}
+
+pub async fn find_dispute_auto_response_config(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<DisputeAutoResponseConfigRecord>> {
+    let key = merchant_id.get_dispute_auto_response_config_key();
+    match db.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputeAutoResponseConfigRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to parse the dispute auto response config")
+            .map(Some),
+        Err(e) if e.current_context().is_db_not_found() => Ok(None),
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching the dispute auto response config"),
+    }
+}
+
+fn get_dispute_auto_response_action(
+    config: &DisputeAutoResponseConfigRecord,
+    dispute: &storage::Dispute,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<DisputeAutoResponseAction> {
+    let currency = match dispute.dispute_currency {
+        Some(currency) => currency,
+        None => dispute
+            .currency
+            .clone()
+            .parse_enum("Currency")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to parse the currency of the dispute")?,
+    };
+
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| match data {
+            serde_json::Value::Null => None,
+            _ => Some(
+                data.parse_value::<api_models::payments::AdditionalPaymentData>(
+                    "AdditionalPaymentData",
+                ),
+            ),
+        })
+        .transpose()
+        .map_err(|err| logger::error!("Failed to parse AdditionalPaymentData {err:?}"))
+        .ok()
+        .flatten()
+        .and_then(|additional_data| match additional_data {
+            api_models::payments::AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        });
+
+    let backend_input = dsl_inputs::BackendInput {
+        metadata: None,
+        payment: dsl_inputs::PaymentInput {
+            amount: dispute.dispute_amount,
+            currency,
+            authentication_type: None,
+            card_bin: None,
+            capture_method: None,
+            business_country: None,
+            billing_country: None,
+            business_label: None,
+            setup_future_usage: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: None,
+            payment_method_type: None,
+            card_network,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: None,
+        },
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        dispute_data: Some(dsl_inputs::DisputeDataInput {
+            reason_code: dispute.connector_reason_code.clone(),
+            connector: Some(dispute.connector.clone()),
+        }),
+    };
+
+    let interpreter = backend::VirInterpreterBackend::with_program(config.algorithm.clone())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error initializing DSL interpreter backend")?;
+    let result = interpreter
+        .execute(backend_input)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error executing the dispute auto response rules")?;
+    logger::debug!(
+        dispute_id = %dispute.dispute_id,
+        rule_name = ?result.rule_name,
+        "Evaluated dispute auto response rules"
+    );
+
+    Ok(result.get_output().action.clone())
+}
+
+/// Assembles the evidence to be submitted for the dispute. Evidence already attached to the
+/// dispute takes precedence over the evidence template, which in turn takes precedence over the
+/// evidence derived from the payment.
+pub async fn get_evidence_request_from_template(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &storage::Dispute,
+    template: &DisputeEvidenceTemplate,
+) -> RouterResult<dispute_models::SubmitEvidenceRequest> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let attached_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let customer_details = payment_intent
+        .customer_details
+        .clone()
+        .map(|details| {
+            details
+                .into_inner()
+                .expose()
+                .parse_value::<CustomerData>("CustomerData")
+        })
+        .transpose()
+        .map_err(|err| logger::error!("Failed to parse customer details {err:?}"))
+        .ok()
+        .flatten();
+
+    let browser_info = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| {
+            browser_info.parse_value::<types::BrowserInformation>("BrowserInformation")
+        })
+        .transpose()
+        .map_err(|err| logger::error!("Failed to parse browser info {err:?}"))
+        .ok()
+        .flatten();
+
+    let billing_address = payments_helpers::get_address_by_id(
+        state,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+    let shipping_address = payments_helpers::get_address_by_id(
+        state,
+        payment_intent.shipping_address_id.clone(),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    Ok(dispute_models::SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        access_activity_log: template.access_activity_log.clone(),
+        billing_address: billing_address.and_then(format_address),
+        cancellation_policy: attached_evidence
+            .cancellation_policy
+            .or_else(|| template.cancellation_policy.clone()),
+        cancellation_policy_disclosure: template.cancellation_policy_disclosure.clone(),
+        cancellation_rebuttal: template.cancellation_rebuttal.clone(),
+        customer_communication: attached_evidence
+            .customer_communication
+            .or_else(|| template.customer_communication.clone()),
+        customer_email_address: customer_details
+            .as_ref()
+            .and_then(|details| details.email.as_ref())
+            .map(|email| email.peek().clone()),
+        customer_name: customer_details
+            .and_then(|details| details.name)
+            .map(ExposeInterface::expose),
+        customer_purchase_ip: browser_info
+            .and_then(|browser_info| browser_info.ip_address)
+            .map(|ip_address| ip_address.to_string()),
+        customer_signature: attached_evidence.customer_signature,
+        product_description: template
+            .product_description
+            .clone()
+            .or(payment_intent.description),
+        receipt: attached_evidence
+            .receipt
+            .or_else(|| template.receipt.clone()),
+        refund_policy: attached_evidence
+            .refund_policy
+            .or_else(|| template.refund_policy.clone()),
+        refund_policy_disclosure: template.refund_policy_disclosure.clone(),
+        refund_refusal_explanation: template.refund_refusal_explanation.clone(),
+        service_date: Some(payment_attempt.created_at.date().to_string()),
+        service_documentation: attached_evidence
+            .service_documentation
+            .or_else(|| template.service_documentation.clone()),
+        shipping_address: shipping_address.and_then(format_address),
+        shipping_carrier: template.shipping_carrier.clone(),
+        shipping_date: None,
+        shipping_documentation: attached_evidence
+            .shipping_documentation
+            .or_else(|| template.shipping_documentation.clone()),
+        shipping_tracking_number: None,
+        invoice_showing_distinct_transactions: attached_evidence
+            .invoice_showing_distinct_transactions,
+        recurring_transaction_agreement: attached_evidence
+            .recurring_transaction_agreement
+            .or_else(|| template.recurring_transaction_agreement.clone()),
+        uncategorized_file: attached_evidence
+            .uncategorized_file
+            .or_else(|| template.uncategorized_file.clone()),
+        uncategorized_text: template.uncategorized_text.clone(),
+    })
+}
+
+fn validate_evidence_templates(
+    program: &ast::Program<DisputeAutoResponseOutput>,
+    evidence_templates: &[DisputeEvidenceTemplate],
+) -> RouterResult<()> {
+    program
+        .rules
+        .iter()
+        .map(|rule| &rule.connector_selection)
+        .chain(std::iter::once(&program.default_selection))
+        .try_for_each(|output| match &output.action {
+            DisputeAutoResponseAction::SubmitEvidence { evidence_template }
+                if !evidence_templates
+                    .iter()
+                    .any(|template| &template.name == evidence_template) =>
+            {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Evidence template `{evidence_template}` does not exist"),
+                }))
+            }
+            _ => Ok(()),
+        })
+}
+
+fn format_address(address: domain::Address) -> Option<String> {
+    let address = api_models::payments::AddressDetails::from(address);
+    let address_lines = [
+        address.line1.map(ExposeInterface::expose),
+        address.line2.map(ExposeInterface::expose),
+        address.line3.map(ExposeInterface::expose),
+        address.city,
+        address.state.map(ExposeInterface::expose),
+        address.zip.map(ExposeInterface::expose),
+        address.country.map(|country| country.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|line| !line.is_empty())
+    .collect::<Vec<_>>();
+
+    (!address_lines.is_empty()).then(|| address_lines.join(", "))
+}
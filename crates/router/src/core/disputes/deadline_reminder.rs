@@ -0,0 +1,293 @@
+#[cfg(feature = "email")]
+use common_utils::{
+    ext_traits::{StringExt, ValueExt},
+    types::AmountConvertor,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::DisputeDeadlineReminderSettings,
+    core::{
+        errors::{self, RouterResult},
+        metrics, webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+#[cfg(feature = "email")]
+use crate::{consts, services::email::types as email_types, utils::user as user_utils};
+
+pub const DISPUTE_DEADLINE_REMINDER_TASK: &str = "DISPUTE_DEADLINE_REMINDER";
+pub const DISPUTE_DEADLINE_REMINDER_TAG: &str = "DISPUTE";
+
+/// Schedules the reminders for a newly opened dispute which has a deadline to respond to it. The
+/// same task marks the dispute as expired once the deadline passes without a response.
+#[instrument(skip_all)]
+pub async fn schedule_dispute_deadline_reminder(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let settings = &state.conf.dispute_deadline_reminder;
+    if !settings.enabled || dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened {
+        return Ok(());
+    }
+    let Some(challenge_required_by) = dispute.challenge_required_by else {
+        return Ok(());
+    };
+
+    // If the deadline is closer than all the reminder offsets, the merchant is reminded right away
+    let now = common_utils::date_time::now();
+    let schedule_time = get_next_reminder_time(settings, challenge_required_by, now).unwrap_or(now);
+
+    super::add_dispute_process_tracker_task(
+        state.store.as_ref(),
+        storage::ProcessTrackerRunner::DisputeDeadlineReminderWorkflow,
+        DISPUTE_DEADLINE_REMINDER_TASK,
+        DISPUTE_DEADLINE_REMINDER_TAG,
+        &dispute.dispute_id,
+        storage::DisputeDeadlineReminderTrackingData {
+            merchant_id: merchant_id.clone(),
+            dispute_id: dispute.dispute_id.clone(),
+        },
+        schedule_time,
+    )
+    .await
+}
+
+/// Returns the earliest reminder time after `now`, or `None` if all the reminders are due.
+pub fn get_next_reminder_time(
+    settings: &DisputeDeadlineReminderSettings,
+    challenge_required_by: time::PrimitiveDateTime,
+    now: time::PrimitiveDateTime,
+) -> Option<time::PrimitiveDateTime> {
+    settings
+        .reminder_offsets_in_hours
+        .iter()
+        .map(|offset| {
+            challenge_required_by.saturating_sub(time::Duration::hours(i64::from(*offset)))
+        })
+        .filter(|reminder_time| *reminder_time > now)
+        .min()
+}
+
+/// Notifies the merchant that the deadline to respond to the dispute is approaching, through an
+/// outgoing webhook and, if enabled, an email to the primary email of the merchant.
+#[instrument(skip_all)]
+pub async fn send_dispute_deadline_reminder(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    dispute: &storage::Dispute,
+    occurrence_id: String,
+) -> RouterResult<()> {
+    Box::pin(
+        webhooks_core::create_repeatable_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_context.clone(),
+            business_profile,
+            storage_enums::EventType::DisputeDeadlineApproaching,
+            storage_enums::EventClass::Disputes,
+            dispute.dispute_id.clone(),
+            storage_enums::EventObjectType::DisputeDetails,
+            api::OutgoingWebhookContent::DisputeDetails(Box::new(
+                api_models::disputes::DisputeResponse::foreign_from(dispute.clone()),
+            )),
+            Some(dispute.created_at),
+            Some(occurrence_id),
+        ),
+    )
+    .await?;
+
+    // The webhook has already been sent, so a failure to send the email does not fail the reminder
+    #[cfg(feature = "email")]
+    if state.conf.dispute_deadline_reminder.email_enabled {
+        send_dispute_deadline_reminder_email(state, merchant_context, dispute)
+            .await
+            .map_err(|error| logger::error!(dispute_deadline_reminder_email_error=?error))
+            .ok();
+    }
+
+    metrics::DISPUTE_DEADLINE_REMINDER_SENT_METRIC.add(1, &[]);
+
+    Ok(())
+}
+
+/// Marks the dispute as expired and notifies the merchant through an outgoing webhook.
+#[instrument(skip_all)]
+pub async fn expire_dispute(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    dispute: storage::Dispute,
+) -> RouterResult<()> {
+    let dispute_update = storage::DisputeUpdate::StatusUpdate {
+        dispute_status: storage_enums::DisputeStatus::DisputeExpired,
+        connector_status: None,
+    };
+    let updated_dispute = state
+        .store
+        .update_dispute(dispute, dispute_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the dispute as expired")?;
+    logger::info!(
+        dispute_id = %updated_dispute.dispute_id,
+        "Dispute expired as the deadline to respond to it has passed"
+    );
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile,
+        storage_enums::EventType::DisputeExpired,
+        storage_enums::EventClass::Disputes,
+        updated_dispute.dispute_id.clone(),
+        storage_enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(
+            api_models::disputes::DisputeResponse::foreign_from(updated_dispute.clone()),
+        )),
+        Some(updated_dispute.created_at),
+    ))
+    .await?;
+
+    metrics::DISPUTE_EXPIRED_METRIC.add(1, &[]);
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_dispute_deadline_reminder_email(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let Some(email_id) = merchant_context
+        .get_merchant_account()
+        .merchant_details
+        .clone()
+        .map(|details| details.parse_value::<api::MerchantDetails>("MerchantDetails"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the merchant details")?
+        .and_then(|details| details.primary_email)
+    else {
+        logger::info!(
+            dispute_id = %dispute.dispute_id,
+            "Primary email of the merchant is not configured, skipping dispute deadline reminder email"
+        );
+        return Ok(());
+    };
+
+    let challenge_required_by = dispute
+        .challenge_required_by
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Deadline to respond to the dispute not found")?;
+    let currency = match dispute.dispute_currency {
+        Some(currency) => currency,
+        None => dispute
+            .currency
+            .clone()
+            .parse_enum("Currency")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to parse the currency of the dispute")?,
+    };
+    let amount = common_utils::types::StringMajorUnitForConnector
+        .convert(dispute.dispute_amount, currency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the dispute amount to major unit")?;
+    let deadline = challenge_required_by
+        .assume_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format the deadline to respond to the dispute")?;
+
+    let email_contents = email_types::DisputeDeadlineReminder {
+        recipient_email: domain::UserEmail::from_pii_email(email_id)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert recipient's email to UserEmail")?,
+        subject: consts::EMAIL_SUBJECT_DISPUTE_DEADLINE_REMINDER,
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.get_string_repr().to_owned(),
+        amount: amount.get_amount_as_string(),
+        currency: currency.to_string(),
+        connector: dispute.connector.clone(),
+        deadline,
+        hours_remaining: (challenge_required_by - common_utils::date_time::now()).whole_hours(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the dispute deadline reminder email")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_settings(reminder_offsets_in_hours: Vec<u16>) -> DisputeDeadlineReminderSettings {
+        DisputeDeadlineReminderSettings {
+            reminder_offsets_in_hours,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_next_reminder_time() {
+        let settings = get_settings(vec![72, 24, 6]);
+        let challenge_required_by = datetime!(2025-08-10 12:00);
+
+        // All the reminders are ahead, the earliest one is returned
+        assert_eq!(
+            get_next_reminder_time(&settings, challenge_required_by, datetime!(2025-08-01 0:00)),
+            Some(datetime!(2025-08-07 12:00))
+        );
+        // The reminders that are due are skipped
+        assert_eq!(
+            get_next_reminder_time(
+                &settings,
+                challenge_required_by,
+                datetime!(2025-08-07 12:00)
+            ),
+            Some(datetime!(2025-08-09 12:00))
+        );
+        assert_eq!(
+            get_next_reminder_time(&settings, challenge_required_by, datetime!(2025-08-10 3:00)),
+            Some(datetime!(2025-08-10 6:00))
+        );
+        // All the reminders are due
+        assert_eq!(
+            get_next_reminder_time(&settings, challenge_required_by, datetime!(2025-08-10 6:00)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_next_reminder_time_without_offsets() {
+        assert_eq!(
+            get_next_reminder_time(
+                &get_settings(vec![]),
+                datetime!(2025-08-10 12:00),
+                datetime!(2025-08-01 0:00)
+            ),
+            None
+        );
+    }
+}
@@ -1,16 +1,26 @@
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{LazyLock, Mutex},
+    time::Instant,
+};
+
 use api_models::gsm as gsm_api_types;
+use common_utils::id_type;
 use diesel_models::gsm as storage;
 use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
 
 use crate::{
     core::{
         errors,
         errors::{RouterResponse, StorageErrorExt},
+        payments::helpers,
     },
     db::gsm::GsmInterface,
     services,
-    types::transformers::ForeignInto,
+    types::{storage::enums, transformers::ForeignInto},
     SessionState,
 };
 
@@ -142,3 +152,306 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+/// Evaluates the connector error against the GSM rules the way the retry engine does on a failed
+/// payment, without counting it as a hit of the matched rule.
+#[instrument(skip_all)]
+pub async fn evaluate_gsm_rule(
+    state: SessionState,
+    gsm_request: gsm_api_types::GsmEvaluateRequest,
+) -> RouterResponse<gsm_api_types::GsmEvaluateResponse> {
+    let db = state.store.as_ref();
+    let gsm_api_types::GsmEvaluateRequest {
+        connector,
+        flow,
+        sub_flow,
+        code,
+        message,
+        merchant_id,
+        authentication_type,
+    } = gsm_request;
+
+    let matched_rule = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow,
+        sub_flow.unwrap_or_else(|| gsm_api_types::DEFAULT_GSM_SUB_FLOW.to_string()),
+        code,
+        message,
+    )
+    .await
+    .map(Some)
+    .or_else(|error| {
+        if error.current_context().is_db_not_found() {
+            Ok(None)
+        } else {
+            Err(error)
+        }
+    })
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while fetching Gsm rule")?;
+
+    let decision = matched_rule
+        .as_ref()
+        .map(|gsm| {
+            gsm_api_types::GsmDecision::from_str(gsm.decision.as_str())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("gsm decision parsing failed")
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    // Step up is only attempted for payments made without 3DS, on connectors the merchant has
+    // enabled it for
+    let step_up_possible = matched_rule
+        .as_ref()
+        .is_some_and(|gsm| gsm.step_up_possible);
+    let should_step_up = match merchant_id {
+        Some(merchant_id)
+            if step_up_possible
+                && authentication_type == Some(enums::AuthenticationType::NoThreeDs) =>
+        {
+            helpers::is_step_up_enabled_for_merchant_connector(&state, &merchant_id, connector)
+                .await
+        }
+        _ => false,
+    };
+
+    let retry_action = if should_step_up {
+        gsm_api_types::GsmRetryAction::StepUp
+    } else {
+        match decision {
+            gsm_api_types::GsmDecision::Retry => gsm_api_types::GsmRetryAction::Retry,
+            gsm_api_types::GsmDecision::Requeue => gsm_api_types::GsmRetryAction::Requeue,
+            gsm_api_types::GsmDecision::DoDefault => gsm_api_types::GsmRetryAction::DoNotRetry,
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        gsm_api_types::GsmEvaluateResponse {
+            matched_rule: matched_rule.map(ForeignInto::foreign_into),
+            decision,
+            retry_action,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_gsm_rules(
+    state: SessionState,
+    gsm_request: gsm_api_types::GsmListRequest,
+) -> RouterResponse<Vec<gsm_api_types::GsmResponse>> {
+    let db = state.store.as_ref();
+    let limit = gsm_request
+        .limit
+        .unwrap_or(gsm_api_types::DEFAULT_GSM_LIST_LIMIT)
+        .min(gsm_api_types::MAX_GSM_LIST_LIMIT);
+
+    GsmInterface::list_gsm_rules_by_connector(
+        db,
+        gsm_request.connector.to_string(),
+        gsm_request.flow,
+        i64::from(limit),
+        i64::from(gsm_request.offset.unwrap_or(0)),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while listing Gsm rules")
+    .map(|rules| {
+        services::ApplicationResponse::Json(
+            rules.into_iter().map(ForeignInto::foreign_into).collect(),
+        )
+    })
+}
+
+/// Interval at which the buffered hits of the gsm rules of a tenant are written to the database
+const GSM_RULE_HITS_FLUSH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Hits of the gsm rules that are yet to be written to the database, per tenant. The hits are
+/// buffered so that a rule matched by many payments is updated once per flush instead of once per
+/// match. Hits still buffered when the process shuts down are lost, so the counts are approximate.
+static PENDING_GSM_RULE_HITS: LazyLock<Mutex<HashMap<id_type::TenantId, PendingGsmRuleHits>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct GsmRuleKey {
+    connector: String,
+    flow: String,
+    sub_flow: String,
+    code: String,
+    message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct GsmRuleHits {
+    hit_count: i64,
+    last_hit_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+struct PendingGsmRuleHits {
+    hits: HashMap<GsmRuleKey, GsmRuleHits>,
+    buffered_since: Instant,
+}
+
+impl PendingGsmRuleHits {
+    fn new(buffered_since: Instant) -> Self {
+        Self {
+            hits: HashMap::new(),
+            buffered_since,
+        }
+    }
+
+    fn record(&mut self, rule: GsmRuleKey, hit_at: time::PrimitiveDateTime) {
+        self.hits
+            .entry(rule)
+            .and_modify(|hits| {
+                hits.hit_count += 1;
+                hits.last_hit_at = hit_at;
+            })
+            .or_insert(GsmRuleHits {
+                hit_count: 1,
+                last_hit_at: hit_at,
+            });
+    }
+
+    /// Takes the buffered hits if they have been buffered for at least `flush_interval`
+    fn take_if_due(
+        &mut self,
+        now: Instant,
+        flush_interval: std::time::Duration,
+    ) -> Option<HashMap<GsmRuleKey, GsmRuleHits>> {
+        (now.saturating_duration_since(self.buffered_since) >= flush_interval).then(|| {
+            self.buffered_since = now;
+            std::mem::take(&mut self.hits)
+        })
+    }
+}
+
+/// Counts the match of the rule where it decides how a failed payment or payout is handled. The
+/// hits are buffered and written to the database in the background, so that the connector error
+/// is not held up by the update of the rule.
+pub fn record_gsm_rule_hit(state: &SessionState, gsm: &storage::GatewayStatusMap) {
+    let now = Instant::now();
+    let rule = GsmRuleKey {
+        connector: gsm.connector.clone(),
+        flow: gsm.flow.clone(),
+        sub_flow: gsm.sub_flow.clone(),
+        code: gsm.code.clone(),
+        message: gsm.message.clone(),
+    };
+
+    let due_hits = match PENDING_GSM_RULE_HITS.lock() {
+        Ok(mut pending_hits) => {
+            let tenant_hits = pending_hits
+                .entry(state.tenant.tenant_id.clone())
+                .or_insert_with(|| PendingGsmRuleHits::new(now));
+            tenant_hits.record(rule, common_utils::date_time::now());
+            tenant_hits.take_if_due(now, GSM_RULE_HITS_FLUSH_INTERVAL)
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to record the hit of the gsm rule");
+            None
+        }
+    };
+
+    if let Some(due_hits) = due_hits {
+        let state = state.clone();
+        tokio::spawn(
+            async move {
+                for (rule, hits) in due_hits {
+                    if let Err(error) = state
+                        .store
+                        .increment_gsm_rule_hit_count(
+                            rule.connector,
+                            rule.flow,
+                            rule.sub_flow,
+                            rule.code,
+                            rule.message,
+                            hits.hit_count,
+                            hits.last_hit_at,
+                        )
+                        .await
+                    {
+                        logger::warn!(?error, "Failed to record the hits of the gsm rule");
+                    }
+                }
+            }
+            .in_current_span(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_rule(code: &str) -> GsmRuleKey {
+        GsmRuleKey {
+            connector: "stripe".to_string(),
+            flow: "Authorize".to_string(),
+            sub_flow: gsm_api_types::DEFAULT_GSM_SUB_FLOW.to_string(),
+            code: code.to_string(),
+            message: "card declined".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pending_gsm_rule_hits_are_aggregated_per_rule() {
+        let buffered_since = Instant::now();
+        let first_hit_at = common_utils::date_time::now();
+        let last_hit_at = first_hit_at + time::Duration::seconds(5);
+        let mut pending_hits = PendingGsmRuleHits::new(buffered_since);
+
+        pending_hits.record(get_rule("card_declined"), first_hit_at);
+        pending_hits.record(get_rule("card_declined"), last_hit_at);
+        pending_hits.record(get_rule("insufficient_funds"), first_hit_at);
+
+        let hits = pending_hits
+            .take_if_due(buffered_since, std::time::Duration::ZERO)
+            .unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(
+            hits.get(&get_rule("card_declined")),
+            Some(&GsmRuleHits {
+                hit_count: 2,
+                last_hit_at,
+            })
+        );
+        assert_eq!(
+            hits.get(&get_rule("insufficient_funds")),
+            Some(&GsmRuleHits {
+                hit_count: 1,
+                last_hit_at: first_hit_at,
+            })
+        );
+        assert!(pending_hits.hits.is_empty());
+    }
+
+    #[test]
+    fn test_pending_gsm_rule_hits_are_taken_once_due() {
+        let buffered_since = Instant::now();
+        let flush_interval = std::time::Duration::from_secs(60);
+        let mut pending_hits = PendingGsmRuleHits::new(buffered_since);
+        pending_hits.record(get_rule("card_declined"), common_utils::date_time::now());
+
+        assert!(pending_hits
+            .take_if_due(buffered_since + flush_interval / 2, flush_interval)
+            .is_none());
+
+        let flushed_at = buffered_since + flush_interval;
+        assert_eq!(
+            pending_hits
+                .take_if_due(flushed_at, flush_interval)
+                .unwrap()
+                .len(),
+            1
+        );
+        // The interval restarts from the flush
+        assert!(pending_hits
+            .take_if_due(flushed_at + flush_interval / 2, flush_interval)
+            .is_none());
+    }
+}
@@ -0,0 +1,481 @@
+//! Server side handling of the `Idempotency-Key` header on mutating merchant APIs.
+//!
+//! The response to the first request made with an idempotency key is stored against the merchant
+//! and the key, in redis and in the database as a fallback for when the redis entry is evicted.
+//! Responses may contain secrets such as the client secret of a payment, so they are stored
+//! encrypted with the key of the merchant.
+//! Retries made with the same key and the same request are answered with the stored response,
+//! while retries made with a different request are rejected. A request made while another request
+//! with the same key is in flight is rejected as well, using an API lock held on the key.
+
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    encryption::Encryption,
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::Flow;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        api_locking::{LockAction, LockingInput},
+        errors::{self, RouterResponse, RouterResult},
+    },
+    headers, logger,
+    routes::{lock_utils, SessionState},
+    services::{self, authentication::get_header_value_by_key},
+    types::{domain, storage},
+};
+
+const IDEMPOTENCY_KEY_PREFIX: &str = "IDEMPOTENCY";
+
+const IDEMPOTENCY_KEY_MAX_LENGTH: usize = 255;
+
+/// Time for which the response to a request is stored against its idempotency key
+const IDEMPOTENCY_KEY_TTL_IN_SECONDS: i64 = 86400;
+
+/// Idempotency key of a request, along with the fingerprint of the request it was sent with
+#[derive(Clone, Debug)]
+pub struct IdempotencyInput {
+    idempotency_key: String,
+    request_fingerprint: String,
+    api_identifier: lock_utils::ApiIdentifier,
+}
+
+impl IdempotencyInput {
+    /// Returns `None` when the request was sent without an idempotency key. The request must be
+    /// passed before any server generated values (such as the payment id) are populated in it.
+    pub fn from_request<T: Serialize>(
+        headers: &HeaderMap,
+        flow: Flow,
+        request: &T,
+    ) -> RouterResult<Option<Self>> {
+        let Some(idempotency_key) =
+            get_header_value_by_key(headers::IDEMPOTENCY_KEY.to_string(), headers)?
+        else {
+            return Ok(None);
+        };
+
+        if idempotency_key.is_empty() || idempotency_key.len() > IDEMPOTENCY_KEY_MAX_LENGTH {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{} must be between 1 and {IDEMPOTENCY_KEY_MAX_LENGTH} characters long",
+                    headers::IDEMPOTENCY_KEY
+                ),
+            }
+            .into());
+        }
+
+        let api_identifier = lock_utils::ApiIdentifier::from(flow);
+        let request = serde_json::to_vec(request)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the request for idempotency")?;
+        let digest = Sha256
+            .generate_digest(&[api_identifier.to_string().as_bytes(), &request].concat())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to compute the request fingerprint")?;
+
+        Ok(Some(Self {
+            idempotency_key: idempotency_key.to_string(),
+            request_fingerprint: hex::encode(digest),
+            api_identifier,
+        }))
+    }
+
+    fn get_redis_key(&self, merchant_id: &id_type::MerchantId) -> String {
+        format!(
+            "{IDEMPOTENCY_KEY_PREFIX}_{}_{}",
+            merchant_id.get_string_repr(),
+            self.idempotency_key
+        )
+    }
+
+    fn get_lock_action(&self) -> LockAction {
+        LockAction::Hold {
+            input: LockingInput {
+                unique_locking_key: format!("{IDEMPOTENCY_KEY_PREFIX}_{}", self.idempotency_key),
+                api_identifier: self.api_identifier.clone(),
+                // Requests are not queued behind an in flight request with the same key
+                override_lock_retries: Some(1),
+            },
+        }
+    }
+}
+
+/// Response to a request sent with an idempotency key
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum IdempotentResponse<R> {
+    Processed(R),
+    /// Stored response to an earlier request sent with the same key
+    Replayed(serde_json::Value),
+}
+
+impl<R: ApiEventMetric> ApiEventMetric for IdempotentResponse<R> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match self {
+            Self::Processed(response) => response.get_api_event_type(),
+            Self::Replayed(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredResponse {
+    request_fingerprint: String,
+    response: Encryption,
+}
+
+impl From<storage::IdempotencyKey> for StoredResponse {
+    fn from(idempotency_key: storage::IdempotencyKey) -> Self {
+        Self {
+            request_fingerprint: idempotency_key.request_fingerprint,
+            response: idempotency_key.response,
+        }
+    }
+}
+
+fn into_idempotent_response<R>(
+    response: services::ApplicationResponse<R>,
+) -> services::ApplicationResponse<IdempotentResponse<R>> {
+    match response {
+        services::ApplicationResponse::Json(body) => {
+            services::ApplicationResponse::Json(IdempotentResponse::Processed(body))
+        }
+        services::ApplicationResponse::JsonWithHeaders((body, headers)) => {
+            services::ApplicationResponse::JsonWithHeaders((
+                IdempotentResponse::Processed(body),
+                headers,
+            ))
+        }
+        services::ApplicationResponse::StatusOk => services::ApplicationResponse::StatusOk,
+        services::ApplicationResponse::TextPlain(text) => {
+            services::ApplicationResponse::TextPlain(text)
+        }
+        services::ApplicationResponse::JsonForRedirection(redirection) => {
+            services::ApplicationResponse::JsonForRedirection(redirection)
+        }
+        services::ApplicationResponse::Form(form) => services::ApplicationResponse::Form(form),
+        services::ApplicationResponse::PaymentLinkForm(form) => {
+            services::ApplicationResponse::PaymentLinkForm(form)
+        }
+        services::ApplicationResponse::FileData(data) => {
+            services::ApplicationResponse::FileData(data)
+        }
+        services::ApplicationResponse::GenericLinkForm(form) => {
+            services::ApplicationResponse::GenericLinkForm(form)
+        }
+    }
+}
+
+async fn encrypt_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    response: serde_json::Value,
+) -> RouterResult<Encryption> {
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::IdempotencyKey),
+        domain::types::CryptoOperation::Encrypt(response.into()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Encryption::from)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the response for idempotency")
+}
+
+async fn decrypt_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    response: Encryption,
+) -> RouterResult<serde_json::Value> {
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::IdempotencyKey),
+        domain::types::CryptoOperation::Decrypt(response),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|response| response.into_inner().expose())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the stored response for idempotency")
+}
+
+async fn find_stored_response(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    input: &IdempotencyInput,
+) -> RouterResult<Option<StoredResponse>> {
+    let cached_response = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .get_and_deserialize_key::<Option<StoredResponse>>(
+                &input.get_redis_key(merchant_id).as_str().into(),
+                "StoredResponse",
+            )
+            .await
+            .map_err(|error| logger::error!(idempotency_redis_error=?error))
+            .ok()
+            .flatten(),
+        Err(error) => {
+            logger::error!(idempotency_redis_error=?error);
+            None
+        }
+    };
+    if cached_response.is_some() {
+        return Ok(cached_response);
+    }
+
+    match state
+        .store
+        .find_idempotency_key_by_merchant_id_idempotency_key(merchant_id, &input.idempotency_key)
+        .await
+    {
+        Ok(idempotency_key) => Ok(Some(idempotency_key.into())),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the idempotency key")),
+    }
+}
+
+async fn store_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    input: &IdempotencyInput,
+    response: serde_json::Value,
+) -> RouterResult<()> {
+    let merchant_id = &key_store.merchant_id;
+    let stored_response = StoredResponse {
+        request_fingerprint: input.request_fingerprint.clone(),
+        response: encrypt_response(state, key_store, response).await?,
+    };
+
+    if let Err(error) = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(
+            &input.get_redis_key(merchant_id).as_str().into(),
+            &stored_response,
+            IDEMPOTENCY_KEY_TTL_IN_SECONDS,
+        )
+        .await
+    {
+        // The database record is enough for the response to be replayed
+        logger::error!(idempotency_redis_error=?error);
+    }
+
+    let created_at = common_utils::date_time::now();
+    state
+        .store
+        .insert_idempotency_key(storage::IdempotencyKeyNew {
+            merchant_id: merchant_id.clone(),
+            idempotency_key: input.idempotency_key.clone(),
+            request_fingerprint: stored_response.request_fingerprint,
+            response: stored_response.response,
+            created_at,
+            expires_at: created_at
+                .saturating_add(time::Duration::seconds(IDEMPOTENCY_KEY_TTL_IN_SECONDS)),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the idempotency key")?;
+
+    Ok(())
+}
+
+async fn process_idempotent_action<R, Fut>(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    input: &IdempotencyInput,
+    action: Fut,
+) -> RouterResponse<IdempotentResponse<R>>
+where
+    R: Serialize,
+    Fut: std::future::Future<Output = RouterResponse<R>>,
+{
+    if let Some(stored_response) =
+        find_stored_response(state, &key_store.merchant_id, input).await?
+    {
+        if stored_response.request_fingerprint != input.request_fingerprint {
+            return Err(errors::ApiErrorResponse::IdempotencyKeyReused.into());
+        }
+        let response = decrypt_response(state, key_store, stored_response.response).await?;
+        return Ok(services::ApplicationResponse::JsonWithHeaders((
+            IdempotentResponse::Replayed(response),
+            vec![(
+                headers::IDEMPOTENT_REPLAYED.to_string(),
+                "true".to_string().into(),
+            )],
+        )));
+    }
+
+    // Errors are not stored, so that a failed request can be retried with the same key
+    let response = action.await?;
+    if let services::ApplicationResponse::Json(body)
+    | services::ApplicationResponse::JsonWithHeaders((body, _)) = &response
+    {
+        let stored = match serde_json::to_value(body) {
+            Ok(body) => store_response(state, key_store, input, body).await,
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the response for idempotency"),
+        };
+        // The request has been processed at this point, so failing to store its response only
+        // prevents it from being replayed
+        if let Err(error) = stored {
+            logger::error!(
+                ?error,
+                "Failed to store the response to an idempotent request"
+            );
+        }
+    }
+
+    Ok(into_idempotent_response(response))
+}
+
+/// Performs the action at most once for the idempotency key of the request, replaying the
+/// stored response on retries. The action is performed as is when the request was sent without
+/// an idempotency key.
+pub async fn perform_idempotent_action<R, Fut>(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    input: Option<IdempotencyInput>,
+    action: Fut,
+) -> RouterResponse<IdempotentResponse<R>>
+where
+    R: Serialize,
+    Fut: std::future::Future<Output = RouterResponse<R>>,
+{
+    let Some(input) = input else {
+        return action.await.map(into_idempotent_response);
+    };
+
+    let merchant_id = key_store.merchant_id.clone();
+    let lock_action = input.get_lock_action();
+    lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.clone())
+        .await
+        .map_err(|error| match error.current_context() {
+            errors::ApiErrorResponse::ResourceBusy => {
+                error.change_context(errors::ApiErrorResponse::IdempotencyKeyInUse)
+            }
+            _ => error,
+        })?;
+
+    let response = process_idempotent_action(&state, &key_store, &input, action).await;
+
+    if let Err(error) = lock_action.free_lock_action(&state, merchant_id).await {
+        logger::error!(?error, "Failed to release the idempotency key lock");
+    }
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    fn headers_with_idempotency_key(idempotency_key: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HeaderName::from_static("idempotency-key"),
+            HeaderValue::from_str(idempotency_key).unwrap(),
+        );
+        headers
+    }
+
+    fn fingerprint(headers: &HeaderMap, flow: Flow, request: &serde_json::Value) -> String {
+        IdempotencyInput::from_request(headers, flow, request)
+            .unwrap()
+            .unwrap()
+            .request_fingerprint
+    }
+
+    #[test]
+    fn test_request_without_idempotency_key() {
+        let input = IdempotencyInput::from_request(
+            &HeaderMap::new(),
+            Flow::PaymentsCreate,
+            &serde_json::json!({ "amount": 100 }),
+        )
+        .unwrap();
+        assert!(input.is_none());
+    }
+
+    #[test]
+    fn test_idempotency_key_length() {
+        let request = serde_json::json!({ "amount": 100 });
+        for idempotency_key in [String::new(), "a".repeat(IDEMPOTENCY_KEY_MAX_LENGTH + 1)] {
+            let result = IdempotencyInput::from_request(
+                &headers_with_idempotency_key(&idempotency_key),
+                Flow::PaymentsCreate,
+                &request,
+            );
+            assert!(matches!(
+                result.unwrap_err().current_context(),
+                errors::ApiErrorResponse::InvalidRequestData { .. }
+            ));
+        }
+
+        let idempotency_key = "a".repeat(IDEMPOTENCY_KEY_MAX_LENGTH);
+        let input = IdempotencyInput::from_request(
+            &headers_with_idempotency_key(&idempotency_key),
+            Flow::PaymentsCreate,
+            &request,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(input.idempotency_key, idempotency_key);
+    }
+
+    #[test]
+    fn test_request_fingerprint() {
+        let headers = headers_with_idempotency_key("key_1");
+        let request = serde_json::json!({ "amount": 100, "currency": "USD" });
+
+        let payment_fingerprint = fingerprint(&headers, Flow::PaymentsCreate, &request);
+        assert_eq!(payment_fingerprint.len(), 64);
+        assert_eq!(
+            payment_fingerprint,
+            fingerprint(&headers, Flow::PaymentsCreate, &request)
+        );
+        // The fingerprint does not depend on the idempotency key
+        assert_eq!(
+            payment_fingerprint,
+            fingerprint(
+                &headers_with_idempotency_key("key_2"),
+                Flow::PaymentsCreate,
+                &request
+            )
+        );
+        assert_ne!(
+            payment_fingerprint,
+            fingerprint(
+                &headers,
+                Flow::PaymentsCreate,
+                &serde_json::json!({ "amount": 200, "currency": "USD" })
+            )
+        );
+        // The same request sent to a different API is a different request
+        assert_ne!(
+            payment_fingerprint,
+            fingerprint(&headers, Flow::RefundsCreate, &request)
+        );
+    }
+}
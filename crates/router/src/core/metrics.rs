@@ -30,6 +30,9 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(DISPUTE_AUTO_RESPONSE_SCHEDULED_METRIC, GLOBAL_METER); // No. of disputes for which an auto response was scheduled by the dispute auto response rules
+counter_metric!(DISPUTE_DEADLINE_REMINDER_SENT_METRIC, GLOBAL_METER); // No. of reminders sent for disputes with an approaching deadline
+counter_metric!(DISPUTE_EXPIRED_METRIC, GLOBAL_METER); // No. of disputes marked as expired as the deadline to respond passed
 
 counter_metric!(INCOMING_PAYOUT_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming payout webhooks
 counter_metric!(
@@ -1029,6 +1029,7 @@ impl PaymentMethodsController for PmCards<'_> {
             self.merchant_context.get_merchant_key_store(),
             self.merchant_context.get_merchant_account().get_id(),
             pm_id.payment_method_id.as_str(),
+            self.merchant_context.get_merchant_account().storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -1195,6 +1196,7 @@ impl PaymentMethodsController for PmCards<'_> {
                                 self.merchant_context.get_merchant_key_store(),
                                 merchant_id,
                                 &resp.payment_method_id,
+                                self.merchant_context.get_merchant_account().storage_scheme,
                             )
                             .await
                             .to_not_found_response(
@@ -1,6 +1,8 @@
 pub mod access_token;
 pub mod conditional_configs;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dcc;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
@@ -687,6 +689,18 @@ where
             should_continue_capture,
         );
 
+        if should_continue_transaction {
+            operation
+                .to_domain()?
+                .perform_dynamic_currency_conversion_if_eligible(
+                    state,
+                    &mut payment_data,
+                    &mut should_continue_transaction,
+                    merchant_context,
+                )
+                .await?;
+        }
+
         let is_eligible_for_uas = helpers::is_merchant_eligible_authentication_service(
             merchant_context.get_merchant_account().get_id(),
             state,
@@ -6698,6 +6712,8 @@ where
     pub vault_operation: Option<domain_payments::VaultOperation>,
     pub threeds_method_comp_ind: Option<api_models::payments::ThreeDsCompletionIndicator>,
     pub whole_connector_response: Option<Secret<String>>,
+    #[cfg(feature = "v1")]
+    pub dcc_details: Option<dcc::DccDetails>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -9592,6 +9608,9 @@ pub trait OperationSessionGetters<F> {
     #[cfg(feature = "v1")]
     fn get_whole_connector_response(&self) -> Option<Secret<String>>;
 
+    #[cfg(feature = "v1")]
+    fn get_dcc_offer(&self) -> Option<&api_models::payments::DccOffer>;
+
     #[cfg(feature = "v1")]
     fn get_vault_operation(&self) -> Option<&domain_payments::VaultOperation>;
 
@@ -9826,6 +9845,12 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.whole_connector_response.clone()
     }
 
+    fn get_dcc_offer(&self) -> Option<&api_models::payments::DccOffer> {
+        self.dcc_details
+            .as_ref()
+            .and_then(|dcc_details| dcc_details.pending_offer.as_ref())
+    }
+
     #[cfg(feature = "v1")]
     fn get_capture_method(&self) -> Option<enums::CaptureMethod> {
         self.payment_attempt.capture_method
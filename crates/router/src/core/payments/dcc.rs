@@ -0,0 +1,563 @@
+//! Dynamic currency conversion (DCC) lets a customer paying with a card issued in a foreign
+//! currency pay in the currency of their card instead of the currency of the payment.
+//!
+//! When DCC is configured for the merchant and the issuing currency of the card differs from the
+//! currency of the payment, confirm returns a [`DccOffer`] instead of authorizing the payment. The
+//! customer accepts or declines the offer by confirming the payment again with a
+//! [`api_models::payments::DccDecision`], after which the payment is authorized in the chosen
+//! currency.
+
+use std::str::FromStr;
+
+use api_models::payments::{DccDecision, DccOffer};
+use common_utils::{ext_traits::StringExt, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::PaymentData,
+    },
+    logger,
+    routes::SessionState,
+    types::domain,
+    utils::currency,
+};
+
+/// Time for which the decision of the customer on an offer is remembered, so that the payment is
+/// not offered a conversion again when it is confirmed after authentication
+const DCC_DECISION_TTL_IN_SECONDS: i64 = 86400;
+
+/// Merchant level DCC configuration, stored in the configs table against
+/// [`id_type::MerchantId::get_dcc_config_key`]. DCC is disabled when the config is absent.
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct DccConfig {
+    /// Markup over the wholesale exchange rate, in percent
+    pub markup_percentage: Decimal,
+    /// Time for which an offer can be accepted
+    pub offer_validity_in_seconds: i64,
+}
+
+/// DCC state of a payment during confirm
+#[derive(Clone, Debug, Default)]
+pub struct DccDetails {
+    /// Decision of the customer sent in the confirm request
+    pub decision: Option<DccDecision>,
+    /// Offer returned to the customer, awaiting their decision
+    pub pending_offer: Option<DccOffer>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct StoredDccOffer {
+    offer: DccOffer,
+    accepted: Option<bool>,
+}
+
+fn get_dcc_offer_key(merchant_id: &id_type::MerchantId, payment_id: &id_type::PaymentId) -> String {
+    format!(
+        "dcc_offer_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+async fn get_dcc_config(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<DccConfig>> {
+    match state
+        .store
+        .find_config_by_key(&merchant_id.get_dcc_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DccConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse DCC config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch DCC config")),
+    }
+}
+
+/// The issuing country of a card in the BIN data may be an alpha-2 code, an alpha-3 code or the
+/// name of the country, in any case
+fn parse_issuing_country(issuing_country: &str) -> Option<common_enums::CountryAlpha2> {
+    let normalize = |value: &str| {
+        value
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .collect::<String>()
+            .to_uppercase()
+    };
+    let issuing_country = normalize(issuing_country);
+
+    common_enums::CountryAlpha2::from_str(&issuing_country)
+        .ok()
+        .or_else(|| {
+            common_enums::Country::iter()
+                .find(|country| {
+                    country.to_alpha3().to_string() == issuing_country
+                        || normalize(&country.to_string()) == issuing_country
+                })
+                .map(common_enums::Country::to_alpha2)
+        })
+}
+
+async fn get_card_issuing_currency(
+    state: &SessionState,
+    card: &domain::Card,
+) -> Option<common_enums::Currency> {
+    let card_info = state
+        .store
+        .get_card_info(&card.card_number.get_card_isin())
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten();
+
+    card_info
+        .and_then(|card_info| card_info.card_issuing_country)
+        .or_else(|| card.card_issuing_country.clone())
+        .as_deref()
+        .and_then(parse_issuing_country)
+        .and_then(common_enums::CountryAlpha2::get_local_currency)
+}
+
+async fn create_dcc_offer(
+    state: &SessionState,
+    config: &DccConfig,
+    amount: MinorUnit,
+    payment_currency: common_enums::Currency,
+    card_currency: common_enums::Currency,
+) -> RouterResult<DccOffer> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(state, forex_api.data_expiration_delay_in_seconds)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch forex rates for DCC")?;
+
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        payment_currency,
+        card_currency,
+        amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the payment amount for DCC")?;
+
+    let (converted_amount, exchange_rate) = apply_dcc_markup(
+        amount,
+        payment_currency,
+        converted_amount,
+        card_currency,
+        config.markup_percentage,
+    )
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to apply DCC markup to the converted amount")?;
+
+    Ok(DccOffer {
+        offer_id: common_utils::generate_id(consts::ID_LENGTH, "dcc"),
+        original_amount: amount,
+        original_currency: payment_currency,
+        converted_amount,
+        card_currency,
+        exchange_rate,
+        markup_percentage: config.markup_percentage.normalize().to_string(),
+        expires_at: common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(config.offer_validity_in_seconds)),
+    })
+}
+
+/// Applies the markup to the amount converted to the currency of the card, given in its major
+/// unit. Returns the converted amount in the minor unit of the currency of the card along with the
+/// exchange rate inclusive of markup, or `None` if the amount is zero or out of range.
+fn apply_dcc_markup(
+    amount: MinorUnit,
+    payment_currency: common_enums::Currency,
+    converted_amount: Decimal,
+    card_currency: common_enums::Currency,
+    markup_percentage: Decimal,
+) -> Option<(MinorUnit, String)> {
+    let markup_factor = Decimal::ONE + markup_percentage / Decimal::ONE_HUNDRED;
+    let card_currency_digits = u32::from(card_currency.number_of_digits_after_decimal_point());
+    let original_amount = Decimal::new(
+        amount.get_amount_as_i64(),
+        u32::from(payment_currency.number_of_digits_after_decimal_point()),
+    );
+
+    let converted_amount_with_markup = converted_amount
+        .checked_mul(markup_factor)?
+        .round_dp(card_currency_digits);
+    let converted_minor_amount = converted_amount_with_markup
+        .checked_mul(Decimal::from(10_i64.checked_pow(card_currency_digits)?))?
+        .to_i64()?;
+    let exchange_rate = converted_amount_with_markup.checked_div(original_amount)?;
+
+    Some((
+        MinorUnit::new(converted_minor_amount),
+        exchange_rate.round_dp(6).normalize().to_string(),
+    ))
+}
+
+/// What is to be done with the DCC offer of a payment being confirmed
+#[derive(Debug, PartialEq)]
+enum DccAction {
+    /// The customer decided on the pending offer, which is applied if accepted
+    RecordDecision {
+        stored_offer: StoredDccOffer,
+        accepted: bool,
+    },
+    /// The decision refers to an offer which does not exist, has expired or was decided on
+    RejectDecision,
+    /// The payment is confirmed again after the customer decided on the offer, for instance after
+    /// authentication, so the earlier decision is applied again
+    ReapplyDecision { offer: DccOffer, accepted: bool },
+    /// No offer has been made for the payment, so one is made if the payment is eligible
+    MakeOffer,
+}
+
+fn get_dcc_action(
+    decision: Option<DccDecision>,
+    stored_offer: Option<StoredDccOffer>,
+) -> DccAction {
+    match (decision, stored_offer) {
+        (Some(decision), Some(stored_offer))
+            if stored_offer.offer.offer_id == decision.offer_id
+                && stored_offer.accepted.is_none() =>
+        {
+            DccAction::RecordDecision {
+                stored_offer,
+                accepted: decision.accepted,
+            }
+        }
+        (Some(_), _) => DccAction::RejectDecision,
+        (
+            None,
+            Some(StoredDccOffer {
+                offer,
+                accepted: Some(accepted),
+            }),
+        ) => DccAction::ReapplyDecision { offer, accepted },
+        (None, _) => DccAction::MakeOffer,
+    }
+}
+
+/// Switches the payment to the currency of the card. The amount and currency of both the intent
+/// and the attempt are updated, so that they are persisted together by the update tracker.
+fn apply_dcc_offer<F: Clone>(payment_data: &mut PaymentData<F>, offer: &DccOffer) {
+    payment_data.currency = offer.card_currency;
+    payment_data.payment_intent.amount = offer.converted_amount;
+    payment_data.payment_intent.currency = Some(offer.card_currency);
+    payment_data.payment_attempt.currency = Some(offer.card_currency);
+    payment_data
+        .payment_attempt
+        .net_amount
+        .set_order_amount(offer.converted_amount);
+}
+
+async fn store_dcc_offer(
+    state: &SessionState,
+    key: &str,
+    stored_offer: &StoredDccOffer,
+    ttl_in_seconds: i64,
+) -> RouterResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .serialize_and_set_key_with_expiry(&key.into(), stored_offer, ttl_in_seconds)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store DCC offer in redis")
+}
+
+async fn find_stored_dcc_offer(
+    state: &SessionState,
+    key: &str,
+) -> RouterResult<Option<StoredDccOffer>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_and_deserialize_key::<Option<StoredDccOffer>>(&key.into(), "StoredDccOffer")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch DCC offer from redis")
+}
+
+/// Offers a conversion to the currency of the card when eligible, or applies the decision of the
+/// customer on a previously returned offer. The transaction is not continued while an offer
+/// awaits the decision of the customer.
+pub async fn perform_dynamic_currency_conversion<F: Clone>(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_data: &mut PaymentData<F>,
+    should_continue_confirm_transaction: &mut bool,
+) -> RouterResult<()> {
+    let card = match payment_data.payment_method_data.as_ref() {
+        Some(domain::PaymentMethodData::Card(card)) => card.clone(),
+        _ => return Ok(()),
+    };
+
+    // Amounts other than the order amount (surcharge, shipping cost, taxes) are not converted,
+    // and zero amount payments have nothing to convert
+    let order_amount = payment_data.payment_attempt.net_amount.get_order_amount();
+    if payment_data.payment_attempt.net_amount.get_total_amount() != order_amount
+        || order_amount == MinorUnit::zero()
+    {
+        return Ok(());
+    }
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let Some(config) = get_dcc_config(state, merchant_id).await? else {
+        return Ok(());
+    };
+
+    let key = get_dcc_offer_key(merchant_id, &payment_data.payment_intent.payment_id);
+    let stored_offer = find_stored_dcc_offer(state, &key).await?;
+    let decision = payment_data
+        .dcc_details
+        .as_ref()
+        .and_then(|dcc_details| dcc_details.decision.clone());
+
+    match get_dcc_action(decision, stored_offer) {
+        DccAction::RecordDecision {
+            stored_offer,
+            accepted,
+        } => {
+            if accepted {
+                apply_dcc_offer(payment_data, &stored_offer.offer);
+            }
+
+            store_dcc_offer(
+                state,
+                &key,
+                &StoredDccOffer {
+                    accepted: Some(accepted),
+                    ..stored_offer
+                },
+                DCC_DECISION_TTL_IN_SECONDS,
+            )
+            .await
+        }
+        DccAction::RejectDecision => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The dynamic currency conversion offer is invalid or has expired".to_string(),
+        }
+        .into()),
+        DccAction::ReapplyDecision { offer, accepted } => {
+            if accepted {
+                apply_dcc_offer(payment_data, &offer);
+            }
+            Ok(())
+        }
+        DccAction::MakeOffer => {
+            let Some(card_currency) = get_card_issuing_currency(state, &card).await else {
+                return Ok(());
+            };
+            if card_currency == payment_data.currency {
+                return Ok(());
+            }
+
+            let offer = create_dcc_offer(
+                state,
+                &config,
+                payment_data.payment_attempt.net_amount.get_order_amount(),
+                payment_data.currency,
+                card_currency,
+            )
+            .await?;
+
+            store_dcc_offer(
+                state,
+                &key,
+                &StoredDccOffer {
+                    offer: offer.clone(),
+                    accepted: None,
+                },
+                config.offer_validity_in_seconds,
+            )
+            .await?;
+
+            payment_data.dcc_details = Some(DccDetails {
+                decision: None,
+                pending_offer: Some(offer),
+            });
+            *should_continue_confirm_transaction = false;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_offer(offer_id: &str) -> DccOffer {
+        DccOffer {
+            offer_id: offer_id.to_string(),
+            original_amount: MinorUnit::new(10000),
+            original_currency: common_enums::Currency::USD,
+            converted_amount: MinorUnit::new(850750),
+            card_currency: common_enums::Currency::INR,
+            exchange_rate: "85.075".to_string(),
+            markup_percentage: "2.5".to_string(),
+            expires_at: common_utils::date_time::now(),
+        }
+    }
+
+    fn get_decision(offer_id: &str, accepted: bool) -> DccDecision {
+        DccDecision {
+            offer_id: offer_id.to_string(),
+            accepted,
+        }
+    }
+
+    #[test]
+    fn test_parse_issuing_country() {
+        assert_eq!(
+            parse_issuing_country("US"),
+            Some(common_enums::CountryAlpha2::US)
+        );
+        assert_eq!(
+            parse_issuing_country("ind"),
+            Some(common_enums::CountryAlpha2::IN)
+        );
+        assert_eq!(
+            parse_issuing_country("United States of America"),
+            Some(common_enums::CountryAlpha2::US)
+        );
+        assert_eq!(
+            parse_issuing_country(" gb "),
+            Some(common_enums::CountryAlpha2::GB)
+        );
+        assert_eq!(parse_issuing_country("Atlantis"), None);
+        assert_eq!(parse_issuing_country(""), None);
+    }
+
+    #[test]
+    fn test_apply_dcc_markup() {
+        // 100.00 USD converted to 8300.00 INR with a markup of 2.5%
+        let (converted_amount, exchange_rate) = apply_dcc_markup(
+            MinorUnit::new(10000),
+            common_enums::Currency::USD,
+            Decimal::new(830000, 2),
+            common_enums::Currency::INR,
+            Decimal::new(25, 1),
+        )
+        .unwrap();
+        assert_eq!(converted_amount, MinorUnit::new(850750));
+        assert_eq!(exchange_rate, "85.075");
+
+        // The converted amount is rounded to the digits of a zero decimal currency
+        let (converted_amount, exchange_rate) = apply_dcc_markup(
+            MinorUnit::new(10000),
+            common_enums::Currency::USD,
+            Decimal::new(150004, 1),
+            common_enums::Currency::JPY,
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(converted_amount, MinorUnit::new(15000));
+        assert_eq!(exchange_rate, "150");
+    }
+
+    #[test]
+    fn test_apply_dcc_markup_zero_amount() {
+        assert_eq!(
+            apply_dcc_markup(
+                MinorUnit::zero(),
+                common_enums::Currency::USD,
+                Decimal::ZERO,
+                common_enums::Currency::INR,
+                Decimal::new(25, 1),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_get_dcc_action_without_decision() {
+        assert_eq!(get_dcc_action(None, None), DccAction::MakeOffer);
+
+        // Confirming again without deciding on the pending offer makes a new offer
+        let pending_offer = StoredDccOffer {
+            offer: get_offer("dcc_1"),
+            accepted: None,
+        };
+        assert_eq!(
+            get_dcc_action(None, Some(pending_offer)),
+            DccAction::MakeOffer
+        );
+
+        for accepted in [true, false] {
+            let decided_offer = StoredDccOffer {
+                offer: get_offer("dcc_1"),
+                accepted: Some(accepted),
+            };
+            assert_eq!(
+                get_dcc_action(None, Some(decided_offer)),
+                DccAction::ReapplyDecision {
+                    offer: get_offer("dcc_1"),
+                    accepted,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_dcc_action_with_decision() {
+        let pending_offer = StoredDccOffer {
+            offer: get_offer("dcc_1"),
+            accepted: None,
+        };
+
+        for accepted in [true, false] {
+            assert_eq!(
+                get_dcc_action(
+                    Some(get_decision("dcc_1", accepted)),
+                    Some(pending_offer.clone())
+                ),
+                DccAction::RecordDecision {
+                    stored_offer: pending_offer.clone(),
+                    accepted,
+                }
+            );
+        }
+
+        // The offer has expired
+        assert_eq!(
+            get_dcc_action(Some(get_decision("dcc_1", true)), None),
+            DccAction::RejectDecision
+        );
+        // The decision is for a different offer
+        assert_eq!(
+            get_dcc_action(
+                Some(get_decision("dcc_2", true)),
+                Some(pending_offer.clone())
+            ),
+            DccAction::RejectDecision
+        );
+        // The customer has already decided on the offer
+        assert_eq!(
+            get_dcc_action(
+                Some(get_decision("dcc_1", true)),
+                Some(StoredDccOffer {
+                    accepted: Some(false),
+                    ..pending_offer
+                })
+            ),
+            DccAction::RejectDecision
+        );
+    }
+}
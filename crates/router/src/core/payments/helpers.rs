@@ -4451,6 +4451,8 @@ impl AttemptType {
             setup_future_usage_applied: None,
             routing_approach: old_payment_attempt.routing_approach,
             connector_request_reference_id: None,
+            retry_details: None,
+            triggered_velocity_rules: None,
         }
     }
 
@@ -6320,7 +6322,7 @@ pub async fn get_gsm_record(
         state.store.find_gsm_rule(
                 connector_name.clone(),
                 flow.clone(),
-                "sub_flow".to_string(),
+                api_models::gsm::DEFAULT_GSM_SUB_FLOW.to_string(),
                 error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
                 error_message.clone().unwrap_or_default(),
             )
@@ -6351,6 +6353,30 @@ pub async fn get_gsm_record(
         .ok()
 }
 
+#[instrument(skip_all)]
+pub async fn is_step_up_enabled_for_merchant_connector(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: api_enums::Connector,
+) -> bool {
+    let key = merchant_id.get_step_up_enabled_key();
+    let db = &*state.store;
+    db.find_config_by_key_unwrap_or(key.as_str(), Some("[]".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|step_up_config| {
+            serde_json::from_str::<Vec<api_enums::Connector>>(&step_up_config.config)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Step-up config parsing failed")
+        })
+        .map_err(|err| {
+            logger::error!(step_up_config_error=?err);
+        })
+        .ok()
+        .map(|connectors_enabled| connectors_enabled.contains(&connector_name))
+        .unwrap_or(false)
+}
+
 pub async fn get_unified_translation(
     state: &SessionState,
     unified_code: String,
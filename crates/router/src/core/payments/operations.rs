@@ -367,6 +367,18 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         Ok(())
     }
 
+    /// Offers dynamic currency conversion when the card is issued in a different currency, or
+    /// applies the decision of the customer on a previous offer
+    async fn perform_dynamic_currency_conversion_if_eligible<'a>(
+        &'a self,
+        _state: &SessionState,
+        _payment_data: &mut D,
+        _should_continue_confirm_transaction: &mut bool,
+        _merchant_context: &domain::MerchantContext,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        Ok(())
+    }
+
     #[instrument(skip_all)]
     async fn guard_payment_against_blocklist<'a>(
         &'a self,
@@ -264,6 +264,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
+            dcc_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -826,6 +826,13 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
+            dcc_details: request
+                .dcc_decision
+                .clone()
+                .map(|decision| payments::dcc::DccDetails {
+                    decision: Some(decision),
+                    pending_offer: None,
+                }),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -857,15 +864,38 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         match payment_method_data {
             Some(api_models::payments::PaymentMethodData::Card(_card)) => {
-                payment_data.card_testing_guard_data =
+                let card_testing_guard_data =
                     card_testing_guard_utils::validate_card_testing_guard_checks(
                         state,
                         request,
                         payment_method_data,
                         customer_id,
                         business_profile,
+                        card_testing_guard_utils::PaymentVelocityDetails {
+                            amount: payment_data.payment_intent.amount,
+                            currency: payment_data.currency,
+                            email: payment_data.email.as_ref(),
+                        },
                     )
                     .await?;
+
+                if card_testing_guard_data
+                    .as_ref()
+                    .is_some_and(|data| data.should_force_three_ds())
+                {
+                    payment_data.payment_attempt.authentication_type =
+                        Some(common_enums::AuthenticationType::ThreeDs);
+                }
+
+                payment_data.payment_attempt.triggered_velocity_rules = card_testing_guard_data
+                    .as_ref()
+                    .filter(|data| !data.triggered_velocity_rules.is_empty())
+                    .map(|data| {
+                        common_types::payments::TriggeredVelocityRules(
+                            data.triggered_velocity_rules.clone(),
+                        )
+                    });
+                payment_data.card_testing_guard_data = card_testing_guard_data;
                 Ok(())
             }
             _ => Ok(()),
@@ -1592,6 +1622,23 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
         Ok(())
     }
 
+    #[instrument(skip_all)]
+    async fn perform_dynamic_currency_conversion_if_eligible<'a>(
+        &'a self,
+        state: &SessionState,
+        payment_data: &mut PaymentData<F>,
+        should_continue_confirm_transaction: &mut bool,
+        merchant_context: &domain::MerchantContext,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        payments::dcc::perform_dynamic_currency_conversion(
+            state,
+            merchant_context,
+            payment_data,
+            should_continue_confirm_transaction,
+        )
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn guard_payment_against_blocklist<'a>(
         &'a self,
@@ -1772,6 +1819,18 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
 
         let (intent_status, attempt_status, (error_code, error_message)) =
             match (frm_suggestion, payment_data.authentication.as_ref()) {
+                // The payment awaits the decision of the customer on the currency conversion offer
+                _ if payment_data
+                    .dcc_details
+                    .as_ref()
+                    .is_some_and(|dcc_details| dcc_details.pending_offer.is_some()) =>
+                {
+                    (
+                        storage_enums::IntentStatus::RequiresConfirmation,
+                        storage_enums::AttemptStatus::ConfirmationAwaited,
+                        (None, None),
+                    )
+                }
                 (Some(frm_suggestion), _) => status_handler_for_frm_results(frm_suggestion),
                 (_, Some(authentication_details)) => status_handler_for_authentication_results(
                     &authentication_details.authentication,
@@ -1966,6 +2025,9 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                         card_discovery,
                         routing_approach: payment_data.payment_attempt.routing_approach,
                         connector_request_reference_id,
+                        triggered_velocity_rules: payment_data
+                            .payment_attempt
+                            .triggered_velocity_rules,
                     },
                     storage_scheme,
                 )
@@ -629,6 +629,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
+            dcc_details: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -1386,6 +1387,8 @@ impl PaymentCreate {
                 setup_future_usage_applied: request.setup_future_usage,
                 routing_approach: Some(common_enums::RoutingApproach::default()),
                 connector_request_reference_id: None,
+                retry_details: None,
+                triggered_velocity_rules: None,
             },
             additional_pm_data,
 
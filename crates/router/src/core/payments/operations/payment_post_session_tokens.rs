@@ -176,6 +176,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsPostSess
             vault_operation: None,
             threeds_method_comp_ind: None,
             whole_connector_response: None,
+            dcc_details: None,
         };
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
@@ -1,4 +1,4 @@
-use std::{str::FromStr, vec::IntoIter};
+use std::{collections::HashSet, str::FromStr, vec::IntoIter};
 
 use common_utils::{ext_traits::Encode, types::MinorUnit};
 use diesel_models::enums as storage_enums;
@@ -11,6 +11,7 @@ use router_env::{
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        gsm as gsm_core,
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
@@ -25,7 +26,11 @@ use crate::{
         metrics,
     },
     services,
-    types::{self, api, domain, storage},
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        domain, storage,
+    },
 };
 
 #[instrument(skip_all)]
@@ -60,10 +65,21 @@ where
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
     let mut retries = None;
+    let mut current_connector_data = original_connector_data.clone();
+    // (connector, route) pairs the payment has already been attempted with
+    let mut attempted_routes = HashSet::from([(
+        original_connector_data.connector_name,
+        RetryRoute::CardNetwork(get_card_network(payment_data.get_payment_method_data())),
+    )]);
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(1, &[]);
 
+    // The hits of the gsm rules are only recorded where they decide how the payment is retried,
+    // as the rules are also looked up for every failed attempt to translate its error
     let mut initial_gsm = get_gsm(state, &router_data).await?;
+    if let Some(gsm) = initial_gsm.as_ref() {
+        gsm_core::record_gsm_rule_hit(state, gsm);
+    }
 
     //Check if step-up to threeDS is possible and merchant has enabled
     let step_up_possible = initial_gsm
@@ -84,7 +100,7 @@ where
     );
 
     let should_step_up = if step_up_possible && is_no_three_ds_payment {
-        is_step_up_enabled_for_merchant_connector(
+        payments::helpers::is_step_up_enabled_for_merchant_connector(
             state,
             merchant_context.get_merchant_account().get_id(),
             original_connector_data.connector_name,
@@ -106,10 +122,9 @@ where
             router_data,
             validate_result,
             schedule_time,
-            true,
+            storage_enums::PaymentRetryStrategy::StepUp,
             frm_suggestion,
             business_profile,
-            false, //should_retry_with_pan is not applicable for step-up
             None,
         )
         .await?;
@@ -120,7 +135,13 @@ where
             // Use initial_gsm for first time alone
             let gsm = match initial_gsm.as_ref() {
                 Some(gsm) => Some(gsm.clone()),
-                None => get_gsm(state, &router_data).await?,
+                None => {
+                    let gsm = get_gsm(state, &router_data).await?;
+                    if let Some(gsm) = gsm.as_ref() {
+                        gsm_core::record_gsm_rule_hit(state, gsm);
+                    }
+                    gsm
+                }
             };
 
             match get_gsm_decision(gsm) {
@@ -139,44 +160,53 @@ where
                         break;
                     }
 
-                    if connector_routing_data.len() == 0 {
+                    let is_clear_pan_retry_possible = initial_gsm
+                        .as_ref()
+                        .map(|gsm| gsm.clear_pan_possible)
+                        .unwrap_or(false)
+                        && business_profile.is_clear_pan_retries_enabled;
+
+                    let Some(retry_decision) = get_retry_decision(
+                        payment_data.get_payment_method_data(),
+                        &mut connector_routing_data,
+                        &current_connector_data,
+                        &attempted_routes,
+                        is_clear_pan_retry_possible,
+                    ) else {
                         logger::info!("connectors exhausted for auto_retry payment");
                         metrics::AUTO_RETRY_EXHAUSTED_COUNT.add(1, &[]);
                         break;
-                    }
-
-                    let is_network_token = payment_data
-                        .get_payment_method_data()
-                        .map(|pmd| pmd.is_network_token_payment_method_data())
-                        .unwrap_or(false);
+                    };
 
-                    let should_retry_with_pan = is_network_token
-                        && initial_gsm
-                            .as_ref()
-                            .map(|gsm| gsm.clear_pan_possible)
-                            .unwrap_or(false)
-                        && business_profile.is_clear_pan_retries_enabled;
+                    logger::info!(
+                        retry_strategy = %retry_decision.strategy,
+                        connector = %retry_decision.connector_data.connector_name,
+                        "retrying payment"
+                    );
 
-                    let (connector, routing_decision) = if should_retry_with_pan {
-                        // If should_retry_with_pan is true, it indicates that we are retrying with PAN using the same connector.
-                        (original_connector_data.clone(), None)
-                    } else {
-                        let connector_routing_data =
-                            super::get_connector_data(&mut connector_routing_data)?;
-                        let routing_decision = connector_routing_data.network.map(|card_network| {
-                            routing_helpers::RoutingDecisionData::get_debit_routing_decision_data(
-                                card_network,
-                                None,
+                    let retry_route = match retry_decision.strategy {
+                        storage_enums::PaymentRetryStrategy::ClearPan => RetryRoute::ClearPan,
+                        storage_enums::PaymentRetryStrategy::StepUp
+                        | storage_enums::PaymentRetryStrategy::AlternateNetwork
+                        | storage_enums::PaymentRetryStrategy::NextConnector => {
+                            RetryRoute::CardNetwork(
+                                retry_decision
+                                    .routing_decision
+                                    .as_ref()
+                                    .map(|routing_decision| routing_decision.get_card_network())
+                                    .or_else(|| {
+                                        get_card_network(payment_data.get_payment_method_data())
+                                    }),
                             )
-                        });
-
-                        (connector_routing_data.connector_data, routing_decision)
+                        }
                     };
+                    attempted_routes
+                        .insert((retry_decision.connector_data.connector_name, retry_route));
 
                     router_data = do_retry(
                         &state.clone(),
                         req_state.clone(),
-                        &connector,
+                        &retry_decision.connector_data,
                         operation,
                         customer,
                         merchant_context,
@@ -184,15 +214,14 @@ where
                         router_data,
                         validate_result,
                         schedule_time,
-                        //this is an auto retry payment, but not step-up
-                        false,
+                        retry_decision.strategy,
                         frm_suggestion,
                         business_profile,
-                        should_retry_with_pan,
-                        routing_decision,
+                        retry_decision.routing_decision,
                     )
                     .await?;
 
+                    current_connector_data = retry_decision.connector_data;
                     retries = retries.map(|i| i - 1);
                 }
                 api_models::gsm::GsmDecision::Requeue => {
@@ -210,28 +239,130 @@ where
     Ok(router_data)
 }
 
-#[instrument(skip_all)]
-pub async fn is_step_up_enabled_for_merchant_connector(
-    state: &app::SessionState,
-    merchant_id: &common_utils::id_type::MerchantId,
-    connector_name: types::Connector,
-) -> bool {
-    let key = merchant_id.get_step_up_enabled_key();
-    let db = &*state.store;
-    db.find_config_by_key_unwrap_or(key.as_str(), Some("[]".to_string()))
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .and_then(|step_up_config| {
-            serde_json::from_str::<Vec<types::Connector>>(&step_up_config.config)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Step-up config parsing failed")
+/// The way a payment has been attempted with a connector
+#[cfg(feature = "v1")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum RetryRoute {
+    /// Through the given network of the card or network token
+    CardNetwork(Option<api_enums::CardNetwork>),
+    /// With the raw card number instead of the network token
+    ClearPan,
+}
+
+/// The connector and credentials that a failed payment attempt is to be retried with
+#[cfg(feature = "v1")]
+struct RetryDecision {
+    connector_data: api::ConnectorData,
+    strategy: storage_enums::PaymentRetryStrategy,
+    routing_decision: Option<routing_helpers::RoutingDecisionData>,
+}
+
+/// Decides how the failed payment attempt is to be retried, in the order of preference:
+/// 1. With the raw card number instead of the network token on the same connector
+/// 2. Through another network of the co-badged card on the same connector
+/// 3. On the next connector (and network) of the routing result, which has not been attempted
+#[cfg(feature = "v1")]
+fn get_retry_decision(
+    payment_method_data: Option<&domain::PaymentMethodData>,
+    connector_routing_data: &mut IntoIter<api::ConnectorRoutingData>,
+    current_connector_data: &api::ConnectorData,
+    attempted_routes: &HashSet<(api_enums::Connector, RetryRoute)>,
+    is_clear_pan_retry_possible: bool,
+) -> Option<RetryDecision> {
+    let is_network_token = payment_method_data
+        .map(|pmd| pmd.is_network_token_payment_method_data())
+        .unwrap_or(false);
+
+    if is_network_token
+        && is_clear_pan_retry_possible
+        && !attempted_routes
+            .contains(&(current_connector_data.connector_name, RetryRoute::ClearPan))
+    {
+        return Some(RetryDecision {
+            connector_data: current_connector_data.clone(),
+            strategy: storage_enums::PaymentRetryStrategy::ClearPan,
+            routing_decision: None,
+        });
+    }
+
+    let is_not_attempted = |routing_data: &api::ConnectorRoutingData| {
+        !attempted_routes.contains(&(
+            routing_data.connector_data.connector_name,
+            RetryRoute::CardNetwork(routing_data.network.clone()),
+        ))
+    };
+
+    let is_co_badged_card = payment_method_data
+        .and_then(|pmd| pmd.get_card_data())
+        .map(|card| {
+            card.card_number
+                .is_cobadged_card()
+                .map_err(|error| logger::error!(co_badged_card_check_error=?error))
+                .unwrap_or(false)
         })
-        .map_err(|err| {
-            logger::error!(step_up_config_error=?err);
+        .unwrap_or(false);
+
+    // Networks the debit routing has found the connector to support, are present in the routing
+    // result as separate entries of the same connector
+    let alternate_network_position = is_co_badged_card
+        .then(|| {
+            connector_routing_data
+                .as_slice()
+                .iter()
+                .position(|routing_data| {
+                    routing_data.connector_data.connector_name
+                        == current_connector_data.connector_name
+                        && routing_data.network.is_some()
+                        && is_not_attempted(routing_data)
+                })
         })
-        .ok()
-        .map(|connectors_enabled| connectors_enabled.contains(&connector_name))
-        .unwrap_or(false)
+        .flatten();
+
+    let mut remaining_connector_routing_data = connector_routing_data.collect::<Vec<_>>();
+    let next_connector_routing_data = alternate_network_position
+        .or_else(|| {
+            remaining_connector_routing_data
+                .iter()
+                .position(is_not_attempted)
+        })
+        .map(|position| remaining_connector_routing_data.remove(position));
+    *connector_routing_data = remaining_connector_routing_data.into_iter();
+
+    next_connector_routing_data.map(|routing_data| {
+        let strategy = if routing_data.connector_data.connector_name
+            == current_connector_data.connector_name
+            && routing_data.network.is_some()
+        {
+            storage_enums::PaymentRetryStrategy::AlternateNetwork
+        } else {
+            storage_enums::PaymentRetryStrategy::NextConnector
+        };
+        let routing_decision = routing_data.network.map(|card_network| {
+            routing_helpers::RoutingDecisionData::get_debit_routing_decision_data(
+                card_network,
+                None,
+            )
+        });
+
+        RetryDecision {
+            connector_data: routing_data.connector_data,
+            strategy,
+            routing_decision,
+        }
+    })
+}
+
+#[cfg(feature = "v1")]
+fn get_card_network(
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> Option<api_enums::CardNetwork> {
+    match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => card.card_network.clone(),
+        Some(domain::PaymentMethodData::NetworkToken(network_token)) => {
+            network_token.card_network.clone()
+        }
+        _ => None,
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -337,10 +468,9 @@ pub async fn do_retry<F, ApiRequest, FData, D>(
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     validate_result: &operations::ValidateResult,
     schedule_time: Option<time::PrimitiveDateTime>,
-    is_step_up: bool,
+    retry_strategy: storage_enums::PaymentRetryStrategy,
     frm_suggestion: Option<storage_enums::FrmSuggestion>,
     business_profile: &domain::Profile,
-    should_retry_with_pan: bool,
     routing_decision: Option<routing_helpers::RoutingDecisionData>,
 ) -> RouterResult<types::RouterData<F, FData, types::PaymentsResponseData>>
 where
@@ -356,7 +486,22 @@ where
     types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData>,
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
-    metrics::AUTO_RETRY_PAYMENT_COUNT.add(1, &[]);
+    metrics::AUTO_RETRY_PAYMENT_COUNT.add(
+        1,
+        router_env::metric_attributes!(("retry_strategy", retry_strategy.to_string())),
+    );
+
+    let previous_card_network = get_card_network(payment_data.get_payment_method_data());
+    let retry_details = common_types::payments::PaymentRetryDetails {
+        strategy: retry_strategy,
+        previous_attempt_id: payment_data.get_payment_attempt().attempt_id.clone(),
+        previous_connector: payment_data.get_payment_attempt().connector.clone(),
+        card_network: routing_decision
+            .as_ref()
+            .map(|routing_decision| routing_decision.get_card_network())
+            .or_else(|| previous_card_network.clone()),
+        previous_card_network,
+    };
 
     modify_trackers(
         state,
@@ -365,10 +510,12 @@ where
         merchant_context.get_merchant_key_store(),
         merchant_context.get_merchant_account().storage_scheme,
         router_data,
-        is_step_up,
+        retry_details,
     )
     .await?;
 
+    let should_retry_with_pan = retry_strategy == storage_enums::PaymentRetryStrategy::ClearPan;
+
     let (merchant_connector_account, router_data, tokenization_action) =
         payments::call_connector_service_prerequisites(
             state,
@@ -418,7 +565,7 @@ pub async fn modify_trackers<F, FData, D>(
     key_store: &domain::MerchantKeyStore,
     storage_scheme: storage_enums::MerchantStorageScheme,
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
-    is_step_up: bool,
+    retry_details: common_types::payments::PaymentRetryDetails,
 ) -> RouterResult<()>
 where
     F: Clone + Send,
@@ -437,7 +584,7 @@ pub async fn modify_trackers<F, FData, D>(
     key_store: &domain::MerchantKeyStore,
     storage_scheme: storage_enums::MerchantStorageScheme,
     router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
-    is_step_up: bool,
+    retry_details: common_types::payments::PaymentRetryDetails,
 ) -> RouterResult<()>
 where
     F: Clone + Send,
@@ -449,7 +596,7 @@ where
         connector,
         payment_data.get_payment_attempt().clone(),
         new_attempt_count,
-        is_step_up,
+        retry_details,
         payment_data.get_payment_intent().setup_future_usage,
     );
 
@@ -653,7 +800,7 @@ pub fn make_new_payment_attempt(
     connector: String,
     old_payment_attempt: storage::PaymentAttempt,
     new_attempt_count: i16,
-    is_step_up: bool,
+    retry_details: common_types::payments::PaymentRetryDetails,
     setup_future_usage_intent: Option<storage_enums::FutureUsage>,
 ) -> storage::PaymentAttemptNew {
     let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
@@ -674,7 +821,9 @@ pub fn make_new_payment_attempt(
         capture_method: old_payment_attempt.capture_method,
         capture_on: old_payment_attempt.capture_on,
         confirm: old_payment_attempt.confirm,
-        authentication_type: if is_step_up {
+        authentication_type: if retry_details.strategy
+            == storage_enums::PaymentRetryStrategy::StepUp
+        {
             Some(storage_enums::AuthenticationType::ThreeDs)
         } else {
             old_payment_attempt.authentication_type
@@ -728,6 +877,8 @@ pub fn make_new_payment_attempt(
         setup_future_usage_applied: setup_future_usage_intent, // setup future usage is picked from intent for new payment attempt
         routing_approach: old_payment_attempt.routing_approach,
         connector_request_reference_id: Default::default(),
+        retry_details: Some(retry_details),
+        triggered_velocity_rules: old_payment_attempt.triggered_velocity_rules,
     }
 }
 
@@ -737,7 +888,7 @@ pub fn make_new_payment_attempt(
     _connector: String,
     _old_payment_attempt: storage::PaymentAttempt,
     _new_attempt_count: i16,
-    _is_step_up: bool,
+    _retry_details: common_types::payments::PaymentRetryDetails,
 ) -> storage::PaymentAttempt {
     todo!()
 }
@@ -817,3 +968,216 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::configs::settings::Connectors;
+
+    fn get_connector_data(connector_name: &str) -> api::ConnectorData {
+        api::ConnectorData::get_connector_by_name(
+            &Connectors::default(),
+            connector_name,
+            api::GetToken::Connector,
+            None,
+        )
+        .unwrap()
+    }
+
+    fn get_connector_routing_data(
+        connector_name: &str,
+        network: Option<api_enums::CardNetwork>,
+    ) -> api::ConnectorRoutingData {
+        api::ConnectorRoutingData {
+            connector_data: get_connector_data(connector_name),
+            network,
+        }
+    }
+
+    fn get_card_payment_method_data(card_number: &str) -> domain::PaymentMethodData {
+        domain::PaymentMethodData::Card(domain::Card {
+            card_number: cards::CardNumber::from_str(card_number).unwrap(),
+            card_network: Some(api_enums::CardNetwork::Visa),
+            ..Default::default()
+        })
+    }
+
+    fn get_network_token_payment_method_data() -> domain::PaymentMethodData {
+        domain::PaymentMethodData::NetworkToken(domain::NetworkTokenData {
+            card_network: Some(api_enums::CardNetwork::Visa),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_get_retry_decision_prefers_clear_pan() {
+        let payment_method_data = get_network_token_payment_method_data();
+        let current_connector_data = get_connector_data("stripe");
+        let mut connector_routing_data =
+            vec![get_connector_routing_data("adyen", None)].into_iter();
+        let attempted_routes = HashSet::from([(
+            api_enums::Connector::Stripe,
+            RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+        )]);
+
+        let retry_decision = get_retry_decision(
+            Some(&payment_method_data),
+            &mut connector_routing_data,
+            &current_connector_data,
+            &attempted_routes,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            retry_decision.strategy,
+            storage_enums::PaymentRetryStrategy::ClearPan
+        );
+        assert_eq!(
+            retry_decision.connector_data.connector_name,
+            api_enums::Connector::Stripe
+        );
+        // The routing result is left untouched for the retries after the clear pan retry
+        assert_eq!(connector_routing_data.len(), 1);
+    }
+
+    #[test]
+    fn test_get_retry_decision_retries_clear_pan_only_once() {
+        let payment_method_data = get_network_token_payment_method_data();
+        let current_connector_data = get_connector_data("stripe");
+        let mut connector_routing_data =
+            vec![get_connector_routing_data("adyen", None)].into_iter();
+        let attempted_routes = HashSet::from([
+            (
+                api_enums::Connector::Stripe,
+                RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+            ),
+            (api_enums::Connector::Stripe, RetryRoute::ClearPan),
+        ]);
+
+        let retry_decision = get_retry_decision(
+            Some(&payment_method_data),
+            &mut connector_routing_data,
+            &current_connector_data,
+            &attempted_routes,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(
+            retry_decision.strategy,
+            storage_enums::PaymentRetryStrategy::NextConnector
+        );
+        assert_eq!(
+            retry_decision.connector_data.connector_name,
+            api_enums::Connector::Adyen
+        );
+    }
+
+    #[test]
+    fn test_get_retry_decision_prefers_alternate_network_for_co_badged_card() {
+        let payment_method_data = get_card_payment_method_data("4010050000000005");
+        let current_connector_data = get_connector_data("stripe");
+        let mut connector_routing_data = vec![
+            get_connector_routing_data("adyen", None),
+            get_connector_routing_data("stripe", Some(api_enums::CardNetwork::CartesBancaires)),
+        ]
+        .into_iter();
+        let attempted_routes = HashSet::from([(
+            api_enums::Connector::Stripe,
+            RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+        )]);
+
+        let retry_decision = get_retry_decision(
+            Some(&payment_method_data),
+            &mut connector_routing_data,
+            &current_connector_data,
+            &attempted_routes,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            retry_decision.strategy,
+            storage_enums::PaymentRetryStrategy::AlternateNetwork
+        );
+        assert_eq!(
+            retry_decision.connector_data.connector_name,
+            api_enums::Connector::Stripe
+        );
+        assert_eq!(
+            retry_decision
+                .routing_decision
+                .map(|routing_decision| routing_decision.get_card_network()),
+            Some(api_enums::CardNetwork::CartesBancaires)
+        );
+        assert_eq!(connector_routing_data.len(), 1);
+    }
+
+    #[test]
+    fn test_get_retry_decision_skips_attempted_routes() {
+        let payment_method_data = get_card_payment_method_data("4242424242424242");
+        let current_connector_data = get_connector_data("stripe");
+        let mut connector_routing_data = vec![
+            get_connector_routing_data("stripe", Some(api_enums::CardNetwork::Visa)),
+            get_connector_routing_data("adyen", Some(api_enums::CardNetwork::Visa)),
+            get_connector_routing_data("checkout", Some(api_enums::CardNetwork::Visa)),
+        ]
+        .into_iter();
+        let attempted_routes = HashSet::from([
+            (
+                api_enums::Connector::Stripe,
+                RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+            ),
+            (
+                api_enums::Connector::Adyen,
+                RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+            ),
+        ]);
+
+        let retry_decision = get_retry_decision(
+            Some(&payment_method_data),
+            &mut connector_routing_data,
+            &current_connector_data,
+            &attempted_routes,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            retry_decision.strategy,
+            storage_enums::PaymentRetryStrategy::NextConnector
+        );
+        assert_eq!(
+            retry_decision.connector_data.connector_name,
+            api_enums::Connector::Checkout
+        );
+    }
+
+    #[test]
+    fn test_get_retry_decision_returns_none_when_routes_are_exhausted() {
+        let payment_method_data = get_card_payment_method_data("4242424242424242");
+        let current_connector_data = get_connector_data("stripe");
+        let mut connector_routing_data = vec![get_connector_routing_data(
+            "stripe",
+            Some(api_enums::CardNetwork::Visa),
+        )]
+        .into_iter();
+        let attempted_routes = HashSet::from([(
+            api_enums::Connector::Stripe,
+            RetryRoute::CardNetwork(Some(api_enums::CardNetwork::Visa)),
+        )]);
+
+        let retry_decision = get_retry_decision(
+            Some(&payment_method_data),
+            &mut connector_routing_data,
+            &current_connector_data,
+            &attempted_routes,
+            true,
+        );
+
+        assert!(retry_decision.is_none());
+    }
+}
@@ -84,6 +84,29 @@ pub enum CachedAlgorithm {
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
 }
 
+impl CachedAlgorithm {
+    pub fn try_from_static_algorithm(
+        algorithm: routing_types::StaticRoutingAlgorithm,
+    ) -> RoutingResult<Self> {
+        Ok(match algorithm {
+            routing_types::StaticRoutingAlgorithm::Single(conn) => Self::Single(conn),
+            routing_types::StaticRoutingAlgorithm::Priority(plist) => Self::Priority(plist),
+            routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => Self::VolumeSplit(splits),
+            routing_types::StaticRoutingAlgorithm::Advanced(program) => {
+                let interpreter = backend::VirInterpreterBackend::with_program(program)
+                    .change_context(errors::RoutingError::DslBackendInitError)
+                    .attach_printable("Error initializing DSL interpreter backend")?;
+
+                Self::Advanced(interpreter)
+            }
+            api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
+                Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
+                    .attach_printable("Unsupported algorithm received")?
+            }
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 pub struct SessionFlowRoutingInput<'a> {
     pub state: &'a SessionState,
@@ -200,6 +223,7 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
     })
 }
 
@@ -315,6 +339,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
     })
 }
 
@@ -429,6 +454,100 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
+    })
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub fn make_dsl_input_for_simulation(
+    payment_intent: &oss_storage::PaymentIntent,
+    payment_attempt: &oss_storage::PaymentAttempt,
+) -> RoutingResult<dsl_inputs::BackendInput> {
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: payment_attempt.mandate_details.as_ref().map(
+            |mandate_type| match mandate_type {
+                hyperswitch_domain_models::mandates::MandateDataType::SingleUse(_) => {
+                    euclid_enums::MandateType::SingleUse
+                }
+                hyperswitch_domain_models::mandates::MandateDataType::MultiUse(_) => {
+                    euclid_enums::MandateType::MultiUse
+                }
+            },
+        ),
+        payment_type: Some(payment_attempt.mandate_details.as_ref().map_or_else(
+            || euclid_enums::PaymentType::NonMandate,
+            |_| euclid_enums::PaymentType::SetupMandate,
+        )),
+    };
+
+    let additional_card_info = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|data| {
+            data.parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()
+        .change_context(errors::RoutingError::DslMissingRequiredField {
+            field_name: "payment_method_data".to_string(),
+        })
+        .attach_printable("Unable to parse payment_method_data of payment_attempt")?
+        .and_then(|additional_data| match additional_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => Some(card_info),
+            _ => None,
+        });
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        card_network: additional_card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_network.clone()),
+    };
+
+    let currency = payment_attempt
+        .currency
+        .or(payment_intent.currency)
+        .get_required_value("currency")
+        .change_context(errors::RoutingError::DslMissingRequiredField {
+            field_name: "currency".to_string(),
+        })?;
+
+    // The billing address is not part of the stored attempt, hence rules on billing country
+    // are evaluated as if the billing country was not provided.
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment_attempt.get_total_amount(),
+        card_bin: additional_card_info
+            .as_ref()
+            .and_then(|card_info| card_info.card_isin.clone()),
+        currency,
+        authentication_type: payment_attempt.authentication_type,
+        capture_method: payment_attempt
+            .capture_method
+            .and_then(|cm| cm.foreign_into()),
+        business_country: payment_intent
+            .business_country
+            .map(api_enums::Country::from_alpha2),
+        billing_country: None,
+        business_label: payment_intent.business_label.clone(),
+        setup_future_usage: payment_intent.setup_future_usage,
+    };
+
+    let metadata = payment_intent
+        .parse_and_get_metadata("routing_parameters")
+        .change_context(errors::RoutingError::MetadataParsingError)
+        .attach_printable("Unable to parse routing_parameters from metadata of payment_intent")
+        .unwrap_or(None);
+
+    Ok(dsl_inputs::BackendInput {
+        metadata,
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+        acquirer_data: None,
+        customer_device_data: None,
+        issuer_data: None,
+        dispute_data: None,
     })
 }
 
@@ -521,22 +640,8 @@ pub async fn perform_static_routing_v1(
         Vec::default()
     };
 
-    let (routable_connectors, routing_approach) = match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => (
-            vec![(**conn).clone()],
-            Some(common_enums::RoutingApproach::StraightThroughRouting),
-        ),
-        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
-        CachedAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec())
-                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            Some(common_enums::RoutingApproach::VolumeBasedRouting),
-        ),
-        CachedAlgorithm::Advanced(interpreter) => (
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
-            Some(common_enums::RoutingApproach::RuleBasedRouting),
-        ),
-    };
+    let (routable_connectors, routing_approach) =
+        perform_routing_for_cached_algorithm(cached_algorithm.as_ref(), backend_input)?;
 
     utils::compare_and_log_result(
         de_euclid_connectors.clone(),
@@ -556,6 +661,31 @@ pub async fn perform_static_routing_v1(
     ))
 }
 
+pub fn perform_routing_for_cached_algorithm(
+    cached_algorithm: &CachedAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<common_enums::RoutingApproach>,
+)> {
+    Ok(match cached_algorithm {
+        CachedAlgorithm::Single(conn) => (
+            vec![(**conn).clone()],
+            Some(common_enums::RoutingApproach::StraightThroughRouting),
+        ),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec())
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            Some(common_enums::RoutingApproach::VolumeBasedRouting),
+        ),
+        CachedAlgorithm::Advanced(interpreter) => (
+            execute_dsl_and_get_connector_v1(backend_input, interpreter)?,
+            Some(common_enums::RoutingApproach::RuleBasedRouting),
+        ),
+    })
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -677,24 +807,7 @@ pub async fn refresh_routing_cache_v1(
         algorithm
     };
 
-    let cached_algorithm = match algorithm {
-        routing_types::StaticRoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::StaticRoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
-        routing_types::StaticRoutingAlgorithm::Advanced(program) => {
-            let interpreter = backend::VirInterpreterBackend::with_program(program)
-                .change_context(errors::RoutingError::DslBackendInitError)
-                .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
-        }
-        api_models::routing::StaticRoutingAlgorithm::ThreeDsDecisionRule(_program) => {
-            Err(errors::RoutingError::InvalidRoutingAlgorithmStructure)
-                .attach_printable("Unsupported algorithm received")?
-        }
-    };
+    let cached_algorithm = CachedAlgorithm::try_from_static_algorithm(algorithm)?;
 
     let arc_cached_algorithm = Arc::new(cached_algorithm);
 
@@ -1144,6 +1257,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1293,6 +1407,7 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1617,6 +1732,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        dispute_data: None,
     };
     Ok(backend_input)
 }
@@ -591,6 +591,7 @@ where
                                         merchant_context.get_merchant_key_store(),
                                         merchant_id,
                                         &resp.payment_method_id,
+                                        merchant_context.get_merchant_account().storage_scheme,
                                     )
                                     .await
                                     .to_not_found_response(
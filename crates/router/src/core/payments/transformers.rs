@@ -2973,6 +2973,10 @@ where
             issuer_error_message: payment_attempt.issuer_error_message,
             is_iframe_redirection_enabled: payment_intent.is_iframe_redirection_enabled,
             whole_connector_response: payment_data.get_whole_connector_response(),
+            dcc_offer: payment_data.get_dcc_offer().cloned(),
+            triggered_velocity_rules: payment_attempt
+                .triggered_velocity_rules
+                .map(|triggered_velocity_rules| triggered_velocity_rules.0),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -3264,6 +3268,10 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             force_3ds_challenge: pi.force_3ds_challenge,
             force_3ds_challenge_trigger: pi.force_3ds_challenge_trigger,
             whole_connector_response: None,
+            dcc_offer: None,
+            triggered_velocity_rules: pa
+                .triggered_velocity_rules
+                .map(|triggered_velocity_rules| triggered_velocity_rules.0),
             issuer_error_code: pa.issuer_error_code,
             issuer_error_message: pa.issuer_error_message,
             is_iframe_redirection_enabled:pi.is_iframe_redirection_enabled
@@ -635,6 +635,7 @@ pub async fn save_payout_data_to_locker(
                 merchant_context.get_merchant_key_store(),
                 merchant_context.get_merchant_account().get_id(),
                 &existing_pm.payment_method_id,
+                merchant_context.get_merchant_account().storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
@@ -1066,7 +1067,7 @@ pub async fn get_gsm_record(
         state.store.find_gsm_rule(
                 connector_name.clone(),
                 flow.to_string(),
-                "sub_flow".to_string(),
+                api_models::gsm::DEFAULT_GSM_SUB_FLOW.to_string(),
                 error_code.clone().unwrap_or_default(), // TODO: make changes in connector to get a mandatory code in case of success or error response
                 error_message.clone().unwrap_or_default(),
             )
@@ -11,7 +11,7 @@ use super::{call_connector_payout, PayoutData};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payouts,
+        gsm as gsm_core, payouts,
     },
     db::StorageInterface,
     routes::{self, app, metrics},
@@ -36,6 +36,9 @@ pub async fn do_gsm_multiple_connector_actions(
 
     loop {
         let gsm = get_gsm(state, &connector, payout_data).await?;
+        if let Some(gsm) = gsm.as_ref() {
+            gsm_core::record_gsm_rule_hit(state, gsm);
+        }
 
         match get_gsm_decision(gsm) {
             api_models::gsm::GsmDecision::Retry => {
@@ -100,6 +103,9 @@ pub async fn do_gsm_single_connector_actions(
 
     loop {
         let gsm = get_gsm(state, &original_connector_data, payout_data).await?;
+        if let Some(gsm) = gsm.as_ref() {
+            gsm_core::record_gsm_rule_hit(state, gsm);
+        }
 
         // if the error config is same as previous, we break out of the loop
         if let Ordering::Equal = gsm.cmp(&previous_gsm) {
@@ -0,0 +1,379 @@
+use api_models::process_tracker::admin as process_tracker_api;
+use common_utils::fp_utils;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::storage::{self, enums},
+};
+
+#[instrument(skip(state))]
+pub async fn list_processes(
+    state: SessionState,
+    constraints: process_tracker_api::ProcessTrackerListConstraints,
+) -> RouterResponse<process_tracker_api::ProcessTrackerListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(process_tracker_api::DEFAULT_LIST_LIMIT)
+        .min(process_tracker_api::MAX_LIST_LIMIT);
+    let offset = constraints.offset.unwrap_or(0);
+
+    let processes = state
+        .store
+        .as_scheduler()
+        .list_processes_by_constraints(
+            constraints.runner,
+            constraints.status,
+            constraints.business_status,
+            i64::from(limit),
+            i64::from(offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+
+    let data = processes
+        .into_iter()
+        .map(|process| to_process_tracker_response(process, false))
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        process_tracker_api::ProcessTrackerListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_process(
+    state: SessionState,
+    id: String,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(&state, &id).await?;
+
+    Ok(ApplicationResponse::Json(to_process_tracker_response(
+        process, true,
+    )))
+}
+
+/// Schedules the task to be executed immediately, irrespective of its current schedule or
+/// outcome.
+#[instrument(skip(state))]
+pub async fn retry_process(
+    state: SessionState,
+    id: String,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(&state, &id).await?;
+    ensure_process_not_running(&process)?;
+
+    let schedule_time = common_utils::date_time::now();
+    let process = schedule_process(
+        &state,
+        process,
+        schedule_time,
+        enums::ProcessTrackerEventType::ForceRetried,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(to_process_tracker_response(
+        process, true,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn reschedule_process(
+    state: SessionState,
+    request: process_tracker_api::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    fp_utils::when(
+        request.schedule_time < common_utils::date_time::now(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`schedule_time` must not be in the past".to_string(),
+            }))
+        },
+    )?;
+
+    let process = find_process(&state, &request.id).await?;
+    ensure_process_not_running(&process)?;
+
+    let process = schedule_process(
+        &state,
+        process,
+        request.schedule_time,
+        enums::ProcessTrackerEventType::Rescheduled,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(to_process_tracker_response(
+        process, true,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_process(
+    state: SessionState,
+    id: String,
+) -> RouterResponse<process_tracker_api::ProcessTrackerResponse> {
+    let process = find_process(&state, &id).await?;
+    ensure_process_not_running(&process)?;
+
+    fp_utils::when(
+        process.status == enums::ProcessTrackerStatus::Finish,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker task has already finished with business status `{}`",
+                    process.business_status
+                ),
+            }))
+        },
+    )?;
+
+    let event = process.record_event(enums::ProcessTrackerEventType::Cancelled, None);
+    let process = update_process_if_status_unchanged(
+        &state,
+        process,
+        storage::ProcessTrackerUpdate::AdminUpdate {
+            status: enums::ProcessTrackerStatus::Finish,
+            business_status: String::from(storage::business_status::CANCELLED),
+            schedule_time: None,
+            event,
+        },
+    )
+    .await
+    .attach_printable("Failed to cancel process tracker task")?;
+
+    Ok(ApplicationResponse::Json(to_process_tracker_response(
+        process, true,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn list_paused_runners(
+    state: SessionState,
+) -> RouterResponse<Vec<process_tracker_api::PausedRunnerResponse>> {
+    let paused_runners = state
+        .store
+        .as_scheduler()
+        .get_paused_runners()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch paused process tracker runners")?;
+
+    Ok(ApplicationResponse::Json(
+        paused_runners
+            .into_iter()
+            .map(
+                |(runner, paused_at)| process_tracker_api::PausedRunnerResponse {
+                    runner,
+                    paused_at,
+                },
+            )
+            .collect(),
+    ))
+}
+
+/// Stops the producer from scheduling tasks of the runner. Tasks which have already been picked
+/// up by the consumers are not affected.
+#[instrument(skip(state))]
+pub async fn pause_runner(
+    state: SessionState,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResponse<process_tracker_api::PausedRunnerResponse> {
+    state
+        .store
+        .as_scheduler()
+        .pause_runner(runner)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to pause process tracker runner")?;
+
+    Ok(ApplicationResponse::Json(
+        process_tracker_api::PausedRunnerResponse {
+            runner,
+            paused_at: common_utils::date_time::now(),
+        },
+    ))
+}
+
+/// Resumes scheduling of tasks of the runner. Tasks that became due while the runner was paused
+/// are rescheduled to be executed immediately, since the producer only picks up tasks which are
+/// due within its fetch window. Tasks that were already overdue beyond the fetch window when the
+/// runner was paused are left untouched, as the producer would not have picked them up either.
+#[instrument(skip(state))]
+pub async fn resume_runner(
+    state: SessionState,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResponse<process_tracker_api::ResumedRunnerResponse> {
+    let db = state.store.as_scheduler();
+    let paused_at = db
+        .get_paused_runners()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch paused process tracker runners")?
+        .into_iter()
+        .find_map(|(paused_runner, paused_at)| (paused_runner == runner).then_some(paused_at));
+    let was_paused = db
+        .resume_runner(runner)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to resume process tracker runner")?;
+
+    let rescheduled_tasks_count = match paused_at.filter(|_| was_paused) {
+        Some(paused_at) => {
+            let lower_fetch_limit = state.conf.scheduler.as_ref().map_or_else(
+                || {
+                    scheduler::SchedulerSettings::default()
+                        .producer
+                        .lower_fetch_limit
+                },
+                |scheduler| scheduler.producer.lower_fetch_limit,
+            );
+            let overdue_since =
+                paused_at.saturating_sub(time::Duration::seconds(lower_fetch_limit));
+
+            db.reschedule_overdue_processes_by_runner(
+                runner,
+                overdue_since,
+                common_utils::date_time::now(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule overdue tasks of resumed runner")?
+        }
+        None => 0,
+    };
+    logger::info!(%runner, was_paused, rescheduled_tasks_count, "Resumed process tracker runner");
+
+    Ok(ApplicationResponse::Json(
+        process_tracker_api::ResumedRunnerResponse {
+            runner,
+            was_paused,
+            rescheduled_tasks_count,
+        },
+    ))
+}
+
+async fn find_process(state: &SessionState, id: &str) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Process tracker task with id `{id}` not found"),
+            })
+        })
+}
+
+/// Tasks that have been picked up by the producer or a consumer must not be modified, as the
+/// scheduler would overwrite the changes once it is done with the task. This only rejects requests
+/// early, the updates themselves are conditional on the status of the task not having changed.
+fn ensure_process_not_running(process: &storage::ProcessTracker) -> RouterResult<()> {
+    fp_utils::when(
+        matches!(
+            process.status,
+            enums::ProcessTrackerStatus::Processing | enums::ProcessTrackerStatus::ProcessStarted
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Process tracker task is currently being executed with status `{}`",
+                    process.status
+                ),
+            }))
+        },
+    )
+}
+
+async fn schedule_process(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    schedule_time: time::PrimitiveDateTime,
+    event_type: enums::ProcessTrackerEventType,
+) -> RouterResult<storage::ProcessTracker> {
+    // Tasks that were never executed are left as new, the rest are sent to retry
+    let status = match process.status {
+        enums::ProcessTrackerStatus::New => enums::ProcessTrackerStatus::New,
+        _ => enums::ProcessTrackerStatus::Pending,
+    };
+    let event = process.record_event(event_type, Some(schedule_time));
+
+    update_process_if_status_unchanged(
+        state,
+        process,
+        storage::ProcessTrackerUpdate::AdminUpdate {
+            status,
+            business_status: String::from(storage::business_status::PENDING),
+            schedule_time: Some(schedule_time),
+            event,
+        },
+    )
+    .await
+    .attach_printable("Failed to reschedule process tracker task")
+}
+
+/// Updates the task only if the scheduler or another request has not changed its status since it
+/// was read, so that a task picked up by the producer in the meantime is not modified.
+async fn update_process_if_status_unchanged(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    process_update: storage::ProcessTrackerUpdate,
+) -> RouterResult<storage::ProcessTracker> {
+    let id = process.id.clone();
+    state
+        .store
+        .as_scheduler()
+        .update_process_if_status_unchanged(process, process_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::ConcurrentModification {
+                message: format!(
+                    "Process tracker task with id `{id}` was modified while being updated, retry the request"
+                ),
+            })
+        })
+}
+
+fn to_process_tracker_response(
+    process: storage::ProcessTracker,
+    include_history: bool,
+) -> process_tracker_api::ProcessTrackerResponse {
+    let history = include_history.then(|| {
+        process
+            .get_events()
+            .into_iter()
+            .map(|event| process_tracker_api::ProcessTrackerEventResponse {
+                event_type: event.event_type,
+                previous_status: event.previous_status,
+                previous_business_status: event.previous_business_status,
+                retry_count: event.retry_count,
+                schedule_time: event.schedule_time,
+                created_at: event.created_at,
+            })
+            .collect()
+    });
+
+    process_tracker_api::ProcessTrackerResponse {
+        id: process.id,
+        name: process.name,
+        runner: process.runner,
+        tag: process.tag,
+        retry_count: process.retry_count,
+        schedule_time: process.schedule_time,
+        tracking_data: process.tracking_data,
+        status: process.status,
+        business_status: process.business_status,
+        history,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    }
+}
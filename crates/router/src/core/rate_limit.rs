@@ -0,0 +1,236 @@
+//! Token bucket rate limiting of the requests made by merchant integrations.
+//!
+//! Every route group has a bucket per merchant, shared by all the requests the merchant makes to
+//! the route group, and a bucket per API key. Buckets are stored in redis so that the limits hold
+//! across all the router instances. Requests authenticated using a JWT are not rate limited.
+
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    configs::settings::TokenBucketConfig,
+    routes::{lock_utils::ApiIdentifier, metrics, SessionState},
+    services::authentication::AuthenticationType,
+};
+
+const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Refills each of the buckets for the time elapsed since it was last refilled and, only when all
+/// of them have a token available, takes a token from each of them. The buckets are passed as the
+/// keys, with the capacity and refill rate of each bucket as consecutive arguments. Returns the
+/// position of the first bucket without a token available, or 0 when the tokens were taken, and
+/// the time in milliseconds after which all of the buckets have a token available.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local tokens = {}
+local rejected_by = 0
+local retry_after = 0
+for index, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[index * 2 - 1])
+    local refill_rate_per_second = tonumber(ARGV[index * 2])
+    local bucket = redis.call("HMGET", key, "tokens", "refilled_at")
+    local refilled_at = tonumber(bucket[2]) or now
+    tokens[index] = math.min(
+        capacity,
+        (tonumber(bucket[1]) or capacity)
+            + math.max(0, now - refilled_at) * refill_rate_per_second / 1000
+    )
+    if tokens[index] < 1 then
+        if rejected_by == 0 then
+            rejected_by = index
+        end
+        retry_after = math.max(
+            retry_after,
+            math.ceil((1 - tokens[index]) * 1000 / refill_rate_per_second)
+        )
+    end
+end
+for index, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[index * 2 - 1])
+    local refill_rate_per_second = tonumber(ARGV[index * 2])
+    if rejected_by == 0 then
+        tokens[index] = tokens[index] - 1
+    end
+    redis.call("HSET", key, "tokens", tostring(tokens[index]), "refilled_at", now)
+    redis.call("PEXPIRE", key, math.ceil(capacity * 1000 / refill_rate_per_second) + 1000)
+end
+return { rejected_by, retry_after }
+"#;
+
+#[derive(Clone, Copy, Debug, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum RateLimitScope {
+    Merchant,
+    ApiKey,
+}
+
+struct TokenBucket<'a> {
+    scope: RateLimitScope,
+    identifier: &'a str,
+    config: TokenBucketConfig,
+}
+
+impl TokenBucket<'_> {
+    /// The merchant id is used as the hash tag of the key, so that all the buckets of a request
+    /// are in the same slot when redis runs as a cluster
+    fn get_redis_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        api_identifier: &ApiIdentifier,
+    ) -> String {
+        format!(
+            "{RATE_LIMIT_PREFIX}_{{{}}}_{api_identifier}_{}_{}",
+            merchant_id.get_string_repr(),
+            self.scope,
+            self.identifier
+        )
+    }
+}
+
+/// Takes a token from each of the buckets applicable to the request, failing with
+/// [`errors::ApiErrorResponse::RateLimitExceeded`] when any of them is empty. Requests are let
+/// through when the buckets cannot be read from redis.
+#[instrument(skip_all)]
+pub async fn check_rate_limit(
+    state: &SessionState,
+    api_identifier: ApiIdentifier,
+    auth_type: &AuthenticationType,
+) -> RouterResult<()> {
+    let rate_limit = &state.conf.rate_limit;
+    if !rate_limit.enabled {
+        return Ok(());
+    }
+
+    let (merchant_id, key_id) = match auth_type {
+        AuthenticationType::ApiKey {
+            merchant_id,
+            key_id,
+        } => (merchant_id, Some(key_id)),
+        AuthenticationType::PublishableKey { merchant_id } => (merchant_id, None),
+        _ => return Ok(()),
+    };
+
+    let config = rate_limit
+        .route_groups
+        .get(&api_identifier.to_string())
+        .unwrap_or(&rate_limit.default);
+
+    let buckets = [
+        key_id
+            .zip(config.api_key)
+            .map(|(key_id, config)| TokenBucket {
+                scope: RateLimitScope::ApiKey,
+                identifier: key_id.get_string_repr(),
+                config,
+            }),
+        config.merchant.map(|config| TokenBucket {
+            scope: RateLimitScope::Merchant,
+            identifier: merchant_id.get_string_repr(),
+            config,
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    if buckets.is_empty() {
+        return Ok(());
+    }
+
+    match take_tokens(state, merchant_id, &api_identifier, &buckets).await {
+        Ok(None) => {
+            for bucket in &buckets {
+                metrics::RATE_LIMIT_ALLOWED_REQUESTS.add(
+                    1,
+                    router_env::metric_attributes!(
+                        ("route_group", api_identifier.to_string()),
+                        ("scope", bucket.scope.to_string()),
+                    ),
+                );
+            }
+        }
+        Ok(Some((bucket, retry_after_seconds))) => {
+            metrics::RATE_LIMIT_REJECTED_REQUESTS.add(
+                1,
+                router_env::metric_attributes!(
+                    ("route_group", api_identifier.to_string()),
+                    ("scope", bucket.scope.to_string()),
+                ),
+            );
+            logger::warn!(
+                scope = %bucket.scope,
+                route_group = %api_identifier,
+                "Request rejected by the rate limiter"
+            );
+            return Err(errors::ApiErrorResponse::RateLimitExceeded {
+                retry_after_seconds,
+            }
+            .into());
+        }
+        Err(error) => {
+            for bucket in &buckets {
+                metrics::RATE_LIMIT_FAILURES.add(
+                    1,
+                    router_env::metric_attributes!(
+                        ("route_group", api_identifier.to_string()),
+                        ("scope", bucket.scope.to_string()),
+                    ),
+                );
+            }
+            logger::error!(?error, "Failed to check the rate limit of the request");
+        }
+    }
+
+    Ok(())
+}
+
+/// Takes a token from all of the buckets, or from none of them when any of them is empty, so that
+/// a request rejected by one of the buckets does not use up the tokens of the others. Returns the
+/// bucket which rejected the request and the number of seconds after which the request can be
+/// retried.
+async fn take_tokens<'a>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    api_identifier: &ApiIdentifier,
+    buckets: &'a [TokenBucket<'a>],
+) -> RouterResult<Option<(&'a TokenBucket<'a>, u64)>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let redis_keys = buckets
+        .iter()
+        .map(|bucket| redis_conn.add_prefix(&bucket.get_redis_key(merchant_id, api_identifier)))
+        .collect::<Vec<_>>();
+    let bucket_configs = buckets
+        .iter()
+        .flat_map(|bucket| {
+            [
+                bucket.config.capacity.to_string(),
+                bucket.config.refill_rate_per_second.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let result = redis_conn
+        .evaluate_redis_script::<_, Vec<u64>>(TOKEN_BUCKET_SCRIPT, redis_keys, bucket_configs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to evaluate the token bucket script")?;
+
+    match result.as_slice() {
+        [0, _] => Ok(None),
+        [rejected_by, retry_after_milliseconds] => usize::try_from(*rejected_by)
+            .ok()
+            .and_then(|position| buckets.get(position.checked_sub(1)?))
+            .map(|bucket| Some((bucket, retry_after_milliseconds.div_ceil(1000).max(1))))
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected bucket position from the token bucket script"),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from the token bucket script"),
+    }
+}
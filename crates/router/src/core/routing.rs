@@ -510,7 +510,16 @@ pub async fn create_routing_algorithm_under_profile(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-    let new_record = record.foreign_into();
+    let mut new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+    new_record.warnings = helpers::get_routing_algorithm_warnings(
+        &state,
+        merchant_context.get_merchant_key_store(),
+        &new_record.profile_id,
+        &transaction_type,
+        &algorithm,
+    )
+    .await
+    .filter(|warnings| !warnings.is_empty());
 
     metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
     Ok(service_api::ApplicationResponse::Json(new_record))
@@ -838,6 +847,149 @@ pub async fn link_routing_config(
     ))
 }
 
+#[cfg(feature = "v1")]
+pub async fn link_or_schedule_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+    transaction_type: enums::TransactionType,
+    payload: routing_types::RoutingActivatePayload,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    if !payload.is_scheduled() {
+        return Box::pin(link_routing_config(
+            state,
+            merchant_context,
+            authentication_profile_id,
+            algorithm_id,
+            transaction_type,
+        ))
+        .await;
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(
+        authentication_profile_id.clone(),
+        &business_profile,
+    )?;
+
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Scheduled activation is only supported for static routing algorithms"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    utils::when(routing_algorithm.algorithm_for != transaction_type, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Cannot use {}'s routing algorithm for {} operation",
+                routing_algorithm.algorithm_for, transaction_type
+            ),
+        })
+    })?;
+
+    if let Some(rollback_config) = payload.rollback_config.as_ref() {
+        helpers::validate_routing_rollback_config(rollback_config)?;
+    }
+
+    let now = common_utils::date_time::now();
+    let activate_at = payload.activate_at.filter(|activate_at| *activate_at > now);
+
+    let (tracking_data, schedule_time) = match (activate_at, payload.rollback_config) {
+        (Some(activate_at), rollback_config) => (
+            storage::RoutingActivationTrackingData {
+                merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+                profile_id: business_profile.get_id().clone(),
+                algorithm_id,
+                transaction_type,
+                stage: storage::RoutingActivationStage::Activate,
+                rollback_config,
+                previous_algorithm_id: None,
+                activated_at: None,
+            },
+            activate_at,
+        ),
+        (None, Some(rollback_config)) => {
+            let previous_algorithm_id =
+                helpers::get_active_routing_algorithm_ref(&business_profile, &transaction_type)?
+                    .algorithm_id;
+
+            Box::pin(link_routing_config(
+                state.clone(),
+                merchant_context.clone(),
+                authentication_profile_id,
+                algorithm_id.clone(),
+                transaction_type,
+            ))
+            .await?;
+
+            // The activation time must not precede the timestamp recorded by the activation, as
+            // the guard uses it to detect re-activations during the guard window
+            let activated_at = common_utils::date_time::now();
+            let schedule_time = activated_at.saturating_add(time::Duration::seconds(i64::from(
+                rollback_config.guard_window_in_secs,
+            )));
+
+            (
+                storage::RoutingActivationTrackingData {
+                    merchant_id: merchant_context.get_merchant_account().get_id().clone(),
+                    profile_id: business_profile.get_id().clone(),
+                    algorithm_id,
+                    transaction_type,
+                    stage: storage::RoutingActivationStage::Guard,
+                    rollback_config: Some(rollback_config),
+                    previous_algorithm_id,
+                    activated_at: Some(activated_at),
+                },
+                schedule_time,
+            )
+        }
+        (None, None) => {
+            return Box::pin(link_routing_config(
+                state,
+                merchant_context,
+                authentication_profile_id,
+                algorithm_id,
+                transaction_type,
+            ))
+            .await;
+        }
+    };
+
+    helpers::add_routing_activation_task(db, tracking_data, schedule_time).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn retrieve_routing_algorithm_from_algorithm_id(
     state: SessionState,
@@ -1031,6 +1183,7 @@ pub async fn unlink_routing_config(
                             timestamp,
                             config_algo_id: routing_algo_ref.config_algo_id.clone(),
                             surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            previous_algorithm_id: None,
                         };
 
                     let record = db
@@ -2683,3 +2836,290 @@ pub async fn update_gateway_score_open_router(
         response,
     ))
 }
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn simulate_routing_algorithm(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let limit = request.validated_limit();
+
+    let profile_id = request
+        .profile_id
+        .clone()
+        .or(authentication_profile_id.clone())
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    if request
+        .algorithm
+        .should_validate_connectors_in_routing_config()
+    {
+        helpers::validate_connectors_in_routing_config(
+            &state,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().get_id(),
+            &profile_id,
+            &request.algorithm,
+        )
+        .await?;
+    }
+
+    let cached_algorithm =
+        payments_routing::CachedAlgorithm::try_from_static_algorithm(request.algorithm)
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unable to initialize the routing algorithm for simulation".to_string(),
+            })?;
+
+    let mut fetch_constraints = PaymentIntentFetchConstraints::from(request.time_range);
+    if let PaymentIntentFetchConstraints::List(ref mut list_params) = fetch_constraints {
+        list_params.profile_id = Some(vec![profile_id.clone()]);
+        list_params.limit = Some(limit);
+    }
+
+    let payments = db
+        .get_filtered_payment_intents_attempt(
+            key_manager_state,
+            merchant_context.get_merchant_account().get_id(),
+            &fetch_constraints,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payments for routing simulation")?;
+
+    let results = payments
+        .into_iter()
+        .map(|(payment_intent, payment_attempt)| {
+            let simulated_connector =
+                payments_routing::make_dsl_input_for_simulation(&payment_intent, &payment_attempt)
+                    .and_then(|backend_input| {
+                        payments_routing::perform_routing_for_cached_algorithm(
+                            &cached_algorithm,
+                            backend_input,
+                        )
+                    })
+                    .map(|(connectors, _routing_approach)| connectors.into_iter().next())
+                    .map_err(|error| {
+                        router_env::logger::warn!(
+                            ?error,
+                            payment_id = ?payment_attempt.payment_id,
+                            "Failed to evaluate routing algorithm for payment during simulation"
+                        );
+                        error.current_context().to_string()
+                    });
+
+            get_routing_simulation_result(
+                payment_attempt.payment_id,
+                payment_attempt.attempt_id,
+                payment_attempt.connector,
+                simulated_connector,
+            )
+        })
+        .collect::<Vec<_>>();
+    let summary = get_routing_simulation_summary(&results);
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingSimulationResponse { summary, results },
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_routing_simulation_result(
+    payment_id: common_utils::id_type::PaymentId,
+    attempt_id: String,
+    actual_connector: Option<String>,
+    simulated_connector: Result<Option<routing_types::RoutableConnectorChoice>, String>,
+) -> routing_types::RoutingSimulationResult {
+    match simulated_connector {
+        Ok(simulated_connector) => {
+            let is_changed = simulated_connector
+                .as_ref()
+                .map(|choice| choice.connector.to_string())
+                != actual_connector;
+
+            routing_types::RoutingSimulationResult {
+                payment_id,
+                attempt_id,
+                actual_connector,
+                simulated_connector,
+                is_changed,
+                error: None,
+            }
+        }
+        Err(error) => routing_types::RoutingSimulationResult {
+            payment_id,
+            attempt_id,
+            actual_connector,
+            simulated_connector: None,
+            is_changed: false,
+            error: Some(error),
+        },
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_routing_simulation_summary(
+    results: &[routing_types::RoutingSimulationResult],
+) -> routing_types::RoutingSimulationSummary {
+    let mut summary = routing_types::RoutingSimulationSummary {
+        total_payments: results.len(),
+        ..Default::default()
+    };
+    let mut connector_distribution = std::collections::BTreeMap::<String, (usize, usize)>::new();
+
+    for result in results {
+        if let Some(actual_connector) = result.actual_connector.clone() {
+            connector_distribution
+                .entry(actual_connector)
+                .or_default()
+                .0 += 1;
+        }
+
+        if result.error.is_some() {
+            summary.failed_count += 1;
+            continue;
+        }
+
+        if result.is_changed {
+            summary.changed_count += 1;
+        } else {
+            summary.unchanged_count += 1;
+        }
+
+        if let Some(choice) = result.simulated_connector.as_ref() {
+            connector_distribution
+                .entry(choice.connector.to_string())
+                .or_default()
+                .1 += 1;
+        }
+    }
+
+    summary.connector_distribution = connector_distribution
+        .into_iter()
+        .map(|(connector, (actual_count, simulated_count))| {
+            routing_types::RoutingSimulationConnectorCount {
+                connector,
+                actual_count,
+                simulated_count,
+            }
+        })
+        .collect();
+
+    summary
+}
+
+#[cfg(all(test, feature = "v1", feature = "olap"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use api_models::routing::{RoutableChoiceKind, RoutableConnectorChoice};
+
+    use super::*;
+
+    fn connector_choice(connector: enums::RoutableConnectors) -> RoutableConnectorChoice {
+        RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    fn simulation_result(
+        actual_connector: Option<&str>,
+        simulated_connector: Result<Option<RoutableConnectorChoice>, String>,
+    ) -> routing_types::RoutingSimulationResult {
+        get_routing_simulation_result(
+            common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_test"))
+                .unwrap(),
+            "pay_test_1".to_string(),
+            actual_connector.map(ToString::to_string),
+            simulated_connector,
+        )
+    }
+
+    #[test]
+    fn test_routing_simulation_result_is_changed() {
+        let unchanged = simulation_result(
+            Some("stripe"),
+            Ok(Some(connector_choice(enums::RoutableConnectors::Stripe))),
+        );
+        assert!(!unchanged.is_changed);
+        assert!(unchanged.error.is_none());
+
+        let changed = simulation_result(
+            Some("stripe"),
+            Ok(Some(connector_choice(enums::RoutableConnectors::Adyen))),
+        );
+        assert!(changed.is_changed);
+
+        let no_connector_chosen = simulation_result(Some("stripe"), Ok(None));
+        assert!(no_connector_chosen.is_changed);
+
+        let never_routed = simulation_result(None, Ok(None));
+        assert!(!never_routed.is_changed);
+
+        let failed = simulation_result(Some("stripe"), Err("evaluation failed".to_string()));
+        assert!(!failed.is_changed);
+        assert!(failed.simulated_connector.is_none());
+        assert_eq!(failed.error.as_deref(), Some("evaluation failed"));
+    }
+
+    #[test]
+    fn test_routing_simulation_summary() {
+        let results = vec![
+            simulation_result(
+                Some("stripe"),
+                Ok(Some(connector_choice(enums::RoutableConnectors::Stripe))),
+            ),
+            simulation_result(
+                Some("stripe"),
+                Ok(Some(connector_choice(enums::RoutableConnectors::Adyen))),
+            ),
+            simulation_result(Some("adyen"), Err("evaluation failed".to_string())),
+            simulation_result(None, Ok(None)),
+        ];
+
+        let summary = get_routing_simulation_summary(&results);
+
+        assert_eq!(summary.total_payments, 4);
+        assert_eq!(summary.changed_count, 1);
+        assert_eq!(summary.unchanged_count, 2);
+        assert_eq!(summary.failed_count, 1);
+
+        let distribution = summary
+            .connector_distribution
+            .iter()
+            .map(|count| {
+                (
+                    count.connector.as_str(),
+                    count.actual_count,
+                    count.simulated_count,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(distribution, vec![("adyen", 1, 1), ("stripe", 2, 1)]);
+    }
+}
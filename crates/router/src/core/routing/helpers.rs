@@ -11,7 +11,7 @@ use std::sync::Arc;
 #[cfg(feature = "v1")]
 use api_models::open_router;
 use api_models::routing as routing_types;
-#[cfg(all(feature = "dynamic_routing", feature = "v1"))]
+#[cfg(feature = "v1")]
 use common_utils::ext_traits::ValueExt;
 use common_utils::{ext_traits::Encode, id_type, types::keymanager::KeyManagerState};
 use diesel_models::configs;
@@ -55,7 +55,10 @@ use crate::{
 #[cfg(feature = "v1")]
 use crate::{
     core::payments::{
-        routing::utils::{self as routing_utils, DecisionEngineApiHandler},
+        routing::{
+            self as payments_routing,
+            utils::{self as routing_utils, DecisionEngineApiHandler},
+        },
         OperationSessionGetters, OperationSessionSetters,
     },
     services,
@@ -73,6 +76,9 @@ pub const ELIMINATION_BASED_DYNAMIC_ROUTING_ALGORITHM: &str =
 pub const CONTRACT_BASED_DYNAMIC_ROUTING_ALGORITHM: &str =
     "Contract based dynamic routing algorithm";
 
+pub const ROUTING_ACTIVATION_TASK: &str = "ROUTING_ACTIVATION";
+pub const ROUTING_ACTIVATION_TAG: &str = "ROUTING";
+
 pub const DECISION_ENGINE_RULE_CREATE_ENDPOINT: &str = "rule/create";
 pub const DECISION_ENGINE_RULE_UPDATE_ENDPOINT: &str = "rule/update";
 pub const DECISION_ENGINE_RULE_GET_ENDPOINT: &str = "rule/get";
@@ -292,6 +298,125 @@ pub async fn update_profile_active_algorithm_ref(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+pub fn get_active_routing_algorithm_ref(
+    business_profile: &domain::Profile,
+    transaction_type: &storage::enums::TransactionType,
+) -> RouterResult<routing_types::RoutingAlgorithmRef> {
+    let routing_algorithm = match transaction_type {
+        storage::enums::TransactionType::Payment => business_profile.routing_algorithm.clone(),
+        #[cfg(feature = "payouts")]
+        storage::enums::TransactionType::Payout => {
+            business_profile.payout_routing_algorithm.clone()
+        }
+        storage::enums::TransactionType::ThreeDsAuthentication => {
+            business_profile.three_ds_decision_rule_algorithm.clone()
+        }
+    };
+
+    Ok(routing_algorithm
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default())
+}
+
+#[cfg(feature = "v1")]
+pub fn validate_routing_rollback_config(
+    rollback_config: &routing_types::RoutingRollbackConfig,
+) -> RouterResult<()> {
+    if !(0.0..=100.0).contains(&rollback_config.success_rate_threshold) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "success_rate_threshold must be between 0 and 100".to_string(),
+        })?
+    }
+
+    if rollback_config.guard_window_in_secs == 0 {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "guard_window_in_secs must be greater than 0".to_string(),
+        })?
+    }
+
+    Ok(())
+}
+
+/// Returns the number of payments in a terminal status and the percentage of them that were
+/// authorized, from the count of payments per status.
+#[cfg(feature = "v1")]
+pub fn get_authorization_success_rate(
+    status_counts: &[(storage::enums::AttemptStatus, i64)],
+) -> (u32, f64) {
+    let (sample_size, success_count) = status_counts
+        .iter()
+        .filter(|(status, _)| status.is_terminal_status())
+        .fold((0u32, 0u32), |(total, success), (status, count)| {
+            let count = u32::try_from(*count).unwrap_or_default();
+            let is_authorized = matches!(
+                status,
+                storage::enums::AttemptStatus::Authorized
+                    | storage::enums::AttemptStatus::Charged
+                    | storage::enums::AttemptStatus::PartialCharged
+                    | storage::enums::AttemptStatus::PartialChargedAndChargeable
+            );
+            (
+                total.saturating_add(count),
+                success.saturating_add(if is_authorized { count } else { 0 }),
+            )
+        });
+
+    let success_rate = if sample_size == 0 {
+        0.0
+    } else {
+        f64::from(success_count) * 100.0 / f64::from(sample_size)
+    };
+
+    (sample_size, success_rate)
+}
+
+#[cfg(feature = "v1")]
+pub async fn add_routing_activation_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::RoutingActivationTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::RoutingActivationWorkflow;
+    let task = ROUTING_ACTIVATION_TASK;
+    let tag = [ROUTING_ACTIVATION_TAG];
+
+    let process_tracker_id = format!(
+        "{runner}_{task}_{}_{}",
+        tracking_data.algorithm_id.get_string_repr(),
+        common_utils::date_time::now_unix_timestamp()
+    );
+    let algorithm_id = tracking_data.algorithm_id.clone();
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct ROUTING_ACTIVATION process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting ROUTING_ACTIVATION task to process_tracker for algorithm_id: {}",
+                algorithm_id.get_string_repr()
+            )
+        })?;
+
+    Ok(())
+}
+
 #[cfg(feature = "v1")]
 pub async fn update_business_profile_active_dynamic_algorithm_ref(
     db: &dyn StorageInterface,
@@ -357,6 +482,12 @@ impl RoutingDecisionData {
             debit_routing_result,
         })
     }
+
+    pub fn get_card_network(&self) -> common_enums::enums::CardNetwork {
+        match self {
+            Self::DebitRouting(data) => data.card_network.clone(),
+        }
+    }
 }
 #[cfg(feature = "v1")]
 impl DebitRoutingDecisionData {
@@ -579,6 +710,41 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Performs static analysis over an advanced routing algorithm against the merchant's knowledge
+/// graph and returns the warnings found, such as rules that can never be selected.
+///
+/// Analysis failures are only logged, since they should not block the creation of the algorithm.
+#[cfg(feature = "v1")]
+pub async fn get_routing_algorithm_warnings(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage::enums::TransactionType,
+    routing_algorithm: &routing_types::StaticRoutingAlgorithm,
+) -> Option<Vec<routing_types::RoutingAnalysisWarning>> {
+    let routing_types::StaticRoutingAlgorithm::Advanced(program) = routing_algorithm else {
+        return None;
+    };
+
+    let knowledge_graph =
+        payments_routing::get_merchant_cgraph(state, key_store, profile_id, transaction_type)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the knowledge graph for routing analysis"
+                );
+            })
+            .ok();
+
+    euclid::dssa::analyzer::analyze_with_warnings(program.clone(), knowledge_graph.as_deref())
+        .map(|(_, warnings)| warnings.into_iter().map(Into::into).collect())
+        .map_err(|error| {
+            logger::error!(?error, "Static analysis of the routing algorithm failed");
+        })
+        .ok()
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -2585,3 +2751,48 @@ pub async fn delete_decision_engine_merchant(
 
     Ok(())
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    use super::*;
+
+    fn rollback_config(
+        success_rate_threshold: f64,
+        guard_window_in_secs: u32,
+    ) -> routing_types::RoutingRollbackConfig {
+        routing_types::RoutingRollbackConfig {
+            success_rate_threshold,
+            guard_window_in_secs,
+            min_sample_size: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_routing_rollback_config() {
+        assert!(validate_routing_rollback_config(&rollback_config(0.0, 1)).is_ok());
+        assert!(validate_routing_rollback_config(&rollback_config(85.5, 3600)).is_ok());
+        assert!(validate_routing_rollback_config(&rollback_config(100.0, 3600)).is_ok());
+
+        assert!(validate_routing_rollback_config(&rollback_config(-0.1, 3600)).is_err());
+        assert!(validate_routing_rollback_config(&rollback_config(100.1, 3600)).is_err());
+        assert!(validate_routing_rollback_config(&rollback_config(f64::NAN, 3600)).is_err());
+        assert!(validate_routing_rollback_config(&rollback_config(85.0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_get_authorization_success_rate() {
+        use storage::enums::AttemptStatus;
+
+        assert_eq!(get_authorization_success_rate(&[]), (0, 0.0));
+
+        let (sample_size, success_rate) = get_authorization_success_rate(&[
+            (AttemptStatus::Charged, 6),
+            (AttemptStatus::Authorized, 2),
+            (AttemptStatus::Failure, 2),
+            // Payments yet to reach a terminal status are not considered
+            (AttemptStatus::Pending, 10),
+        ]);
+        assert_eq!(sample_size, 10);
+        assert!((success_rate - 80.0).abs() < f64::EPSILON);
+    }
+}
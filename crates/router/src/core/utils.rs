@@ -876,6 +876,31 @@ mod tests {
         ];
         assert_eq!(filtered_list, expected_result);
     }
+
+    #[test]
+    fn test_validate_dispute_status_from_expired() {
+        for dispute_status in [
+            DisputeStatus::DisputeExpired,
+            DisputeStatus::DisputeAccepted,
+            DisputeStatus::DisputeCancelled,
+            DisputeStatus::DisputeChallenged,
+            DisputeStatus::DisputeWon,
+            DisputeStatus::DisputeLost,
+        ] {
+            assert!(validate_dispute_status(
+                DisputeStatus::DisputeExpired,
+                dispute_status
+            ));
+        }
+        assert!(!validate_dispute_status(
+            DisputeStatus::DisputeExpired,
+            DisputeStatus::DisputeOpened
+        ));
+        assert!(!validate_dispute_status(
+            DisputeStatus::DisputeWon,
+            DisputeStatus::DisputeExpired
+        ));
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -891,15 +916,15 @@ pub fn validate_dispute_stage(
 }
 
 //Dispute status can go from Opened -> (Expired | Accepted | Cancelled | Challenged -> (Won | Lost))
+// Disputes are marked as expired once the deadline to respond to them passes, which the connector
+// can still settle differently, so the connector may move a dispute out of Expired
 pub fn validate_dispute_status(
     prev_dispute_status: DisputeStatus,
     dispute_status: DisputeStatus,
 ) -> bool {
     match prev_dispute_status {
         DisputeStatus::DisputeOpened => true,
-        DisputeStatus::DisputeExpired => {
-            matches!(dispute_status, DisputeStatus::DisputeExpired)
-        }
+        DisputeStatus::DisputeExpired => !matches!(dispute_status, DisputeStatus::DisputeOpened),
         DisputeStatus::DisputeAccepted => {
             matches!(dispute_status, DisputeStatus::DisputeAccepted)
         }
@@ -19,8 +19,9 @@ pub mod webhook_events;
 pub(crate) use self::{
     incoming::{incoming_webhooks_wrapper, network_token_incoming_webhooks_wrapper},
     outgoing::{
-        create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        create_event_and_trigger_outgoing_webhook,
+        create_repeatable_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
+        get_webhook_endpoint, trigger_webhook_and_raise_event,
     },
 };
 #[cfg(feature = "v2")]
@@ -26,7 +26,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payment_methods,
         payments::{self, tokenization},
@@ -1798,6 +1798,27 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        // Failures in the dispute auto response must not fail the webhook, the merchant can
+        // still respond to the dispute manually
+        if let Err(error) = disputes::auto_response::trigger_dispute_auto_response(
+            &state,
+            &merchant_context,
+            &dispute_object,
+            &payment_attempt,
+        )
+        .await
+        {
+            logger::error!(?error, "Failed to trigger the dispute auto response");
+        }
+        if let Err(error) = disputes::deadline_reminder::schedule_dispute_deadline_reminder(
+            &state,
+            merchant_context.get_merchant_account().get_id(),
+            &dispute_object,
+        )
+        .await
+        {
+            logger::error!(?error, "Failed to schedule the dispute deadline reminder");
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.into();
 
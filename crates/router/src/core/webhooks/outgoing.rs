@@ -45,6 +45,8 @@ use crate::{
     workflows::outgoing_webhook_retry,
 };
 
+const OUTGOING_WEBHOOK_RETRY_TASK: &str = "OUTGOING_WEBHOOK_RETRY";
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn create_event_and_trigger_outgoing_webhook(
@@ -58,24 +60,100 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    create_repeatable_event_and_trigger_outgoing_webhook(
+        state,
+        merchant_context,
+        business_profile,
+        event_type,
+        event_class,
+        primary_object_id,
+        primary_object_type,
+        content,
+        primary_object_created_at,
+        None,
+    )
+    .await
+}
 
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+/// Events are deduplicated using the primary object ID and the event type. Events which are
+/// raised more than once for the same resource and event type (such as reminders) must specify an
+/// `occurrence_id` unique to each occurrence, which is included in the idempotent event ID.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub(crate) async fn create_repeatable_event_and_trigger_outgoing_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    business_profile: domain::Profile,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    occurrence_id: Option<String>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let webhook_endpoints = get_webhook_endpoints_for_event(&business_profile, event_type);
+
+    if !state.conf.webhooks.outgoing_enabled || webhook_endpoints.is_empty() {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            ?event_type,
+            "Outgoing webhooks are disabled in application configuration, or no merchant webhook \
+             endpoint is configured for the event; skipping outgoing webhooks for event"
         );
         return Ok(());
     }
 
+    futures::future::join_all(webhook_endpoints.into_iter().map(|webhook_endpoint| {
+        create_event_and_trigger_outgoing_webhook_for_endpoint(
+            state.clone(),
+            &merchant_context,
+            business_profile.clone(),
+            webhook_endpoint,
+            event_type,
+            event_class,
+            primary_object_id.clone(),
+            primary_object_type,
+            content.clone(),
+            primary_object_created_at,
+            occurrence_id.as_deref(),
+        )
+    }))
+    .await
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()
+    .map(|_| ())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all, fields(webhook_endpoint_id = ?webhook_endpoint.endpoint_id))]
+async fn create_event_and_trigger_outgoing_webhook_for_endpoint(
+    state: SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: domain::Profile,
+    webhook_endpoint: types::WebhookDeliveryEndpoint,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    occurrence_id: Option<&str>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let idempotent_event_id =
+        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
+    let idempotent_event_id = match occurrence_id {
+        Some(occurrence_id) => format!("{idempotent_event_id}_{occurrence_id}"),
+        None => idempotent_event_id,
+    };
+    // Events delivered to additional endpoints are deduplicated separately from each other
+    let idempotent_event_id = match webhook_endpoint.endpoint_id.as_deref() {
+        Some(endpoint_id) => format!("{idempotent_event_id}_{endpoint_id}"),
+        None => idempotent_event_id,
+    };
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -88,10 +166,14 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_context, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = get_outgoing_webhook_request(
+        merchant_context,
+        outgoing_webhook,
+        &business_profile,
+        &webhook_endpoint,
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -140,6 +222,9 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: webhook_endpoint.endpoint_id,
+        dead_lettered_at: None,
+        dead_letter_status_code: None,
     };
 
     let lock_value = utils::perform_redis_lock(
@@ -293,7 +378,8 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_endpoint(&business_profile, event.webhook_endpoint_id.as_deref())
+            .map(|webhook_endpoint| webhook_endpoint.url),
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -389,6 +475,7 @@ async fn trigger_webhook_to_merchant(
                 } else {
                     error_response_handler(
                         state.clone(),
+                        merchant_key_store.clone(),
                         &business_profile.merchant_id,
                         delivery_attempt,
                         status_code.as_u16(),
@@ -447,6 +534,7 @@ async fn trigger_webhook_to_merchant(
                     } else {
                         error_response_handler(
                             state.clone(),
+                            merchant_key_store.clone(),
                             &business_profile.merchant_id,
                             delivery_attempt,
                             status_code.as_u16(),
@@ -487,6 +575,7 @@ async fn trigger_webhook_to_merchant(
                 } else {
                     error_response_handler(
                         state,
+                        merchant_key_store.clone(),
                         &business_profile.merchant_id,
                         delivery_attempt,
                         status_code.as_u16(),
@@ -593,9 +682,28 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     ))
     .attach_printable("Failed to obtain initial process tracker schedule time")?;
 
+    insert_outgoing_webhook_retry_task(
+        db,
+        &business_profile.merchant_id,
+        business_profile.get_id(),
+        event,
+        schedule_time,
+    )
+    .await
+}
+
+/// Inserts the task delivering the event through the outgoing webhook retry workflow, scheduled at
+/// `schedule_time`.
+pub(crate) async fn insert_outgoing_webhook_retry_task(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    event: &domain::Event,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
     let tracking_data = types::OutgoingWebhookTrackingData {
-        merchant_id: business_profile.merchant_id.clone(),
-        business_profile_id: business_profile.get_id().to_owned(),
+        merchant_id: merchant_id.clone(),
+        business_profile_id: profile_id.to_owned(),
         event_type: event.event_type,
         event_class: event.event_class,
         primary_object_id: event.primary_object_id.clone(),
@@ -603,19 +711,12 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         initial_attempt_id: event.initial_attempt_id.clone(),
     };
 
-    let runner = storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow;
-    let task = "OUTGOING_WEBHOOK_RETRY";
     let tag = ["OUTGOING_WEBHOOKS"];
-    let process_tracker_id = scheduler::utils::get_process_tracker_id(
-        runner,
-        task,
-        &event.event_id,
-        &business_profile.merchant_id,
-    );
+    let process_tracker_id = get_outgoing_webhook_retry_task_id(merchant_id, &event.event_id);
     let process_tracker_entry = storage::ProcessTrackerNew::new(
         process_tracker_id,
-        task,
-        runner,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
         tag,
         tracking_data,
         None,
@@ -637,31 +738,147 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
-fn get_webhook_url_from_business_profile(
+pub(crate) fn get_outgoing_webhook_retry_task_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    event_id: &str,
+) -> String {
+    scheduler::utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+        OUTGOING_WEBHOOK_RETRY_TASK,
+        event_id,
+        merchant_id,
+    )
+}
+
+/// Returns the webhook URL of the business profile when `webhook_endpoint_id` is not provided, or
+/// the enabled additional webhook endpoint with the identifier otherwise.
+pub(crate) fn get_webhook_endpoint(
     business_profile: &domain::Profile,
-) -> CustomResult<String, errors::WebhooksFlowError> {
+    webhook_endpoint_id: Option<&str>,
+) -> CustomResult<types::WebhookDeliveryEndpoint, errors::WebhooksFlowError> {
     let webhook_details = business_profile
         .webhook_details
-        .clone()
+        .as_ref()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
+    let now = common_utils::date_time::now();
+
+    match webhook_endpoint_id {
+        None => {
+            let (signing_secret, previous_signing_secret) =
+                get_payment_response_hash_key_signing_secrets(business_profile, now);
+
+            webhook_details
+                .webhook_url
+                .clone()
+                .get_required_value("webhook_url")
+                .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)
+                .map(|webhook_url| types::WebhookDeliveryEndpoint {
+                    endpoint_id: None,
+                    url: webhook_url.expose(),
+                    signing_secret,
+                    previous_signing_secret,
+                })
+        }
+        Some(webhook_endpoint_id) => {
+            let webhook_endpoint = webhook_details
+                .webhook_endpoints
+                .iter()
+                .flatten()
+                .find(|endpoint| {
+                    endpoint.endpoint_id == webhook_endpoint_id
+                        && endpoint.is_enabled.unwrap_or(true)
+                })
+                .ok_or(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Webhook endpoint `{webhook_endpoint_id}` is not configured or disabled"
+                    )
+                })?;
+
+            // The payment response hash key is used when the endpoint has no signing secret
+            let (signing_secret, previous_signing_secret) = match &webhook_endpoint.signing_secret {
+                Some(signing_secret) => (
+                    Some(signing_secret.clone()),
+                    webhook_endpoint
+                        .signing_secret_rotation
+                        .as_ref()
+                        .filter(|rotation| rotation.is_active(now))
+                        .map(|rotation| rotation.previous_signing_secret.clone()),
+                ),
+                None => get_payment_response_hash_key_signing_secrets(business_profile, now),
+            };
+
+            Ok(types::WebhookDeliveryEndpoint {
+                endpoint_id: Some(webhook_endpoint.endpoint_id.clone()),
+                url: webhook_endpoint.url.clone().expose(),
+                signing_secret,
+                previous_signing_secret,
+            })
+        }
+    }
+}
+
+fn get_payment_response_hash_key_signing_secrets(
+    business_profile: &domain::Profile,
+    now: time::PrimitiveDateTime,
+) -> (Option<Secret<String>>, Option<Secret<String>>) {
+    let previous_payment_response_hash_key = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.payment_response_hash_key_rotation.as_ref())
+        .filter(|rotation| rotation.is_active(now))
+        .map(|rotation| rotation.previous_signing_secret.clone());
+
+    (
+        business_profile
+            .payment_response_hash_key
+            .clone()
+            .map(Secret::new),
+        previous_payment_response_hash_key,
+    )
+}
+
+/// Returns the endpoints of the business profile subscribed to the event type, starting with the
+/// webhook URL of the profile.
+fn get_webhook_endpoints_for_event(
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+) -> Vec<types::WebhookDeliveryEndpoint> {
+    let additional_webhook_endpoint_ids = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_endpoints.as_ref())
+        .into_iter()
+        .flatten()
+        .filter(|endpoint| {
+            endpoint
+                .enabled_events
+                .as_ref()
+                .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+        })
+        .map(|endpoint| Some(endpoint.endpoint_id.as_str()));
 
-    webhook_details
-        .webhook_url
-        .get_required_value("webhook_url")
-        .change_context(errors::WebhooksFlowError::MerchantWebhookUrlNotConfigured)
-        .map(ExposeInterface::expose)
+    std::iter::once(None)
+        .chain(additional_webhook_endpoint_ids)
+        .filter_map(|webhook_endpoint_id| {
+            get_webhook_endpoint(business_profile, webhook_endpoint_id).ok()
+        })
+        .filter(|webhook_endpoint| !webhook_endpoint.url.is_empty())
+        .collect()
 }
 
 pub(crate) fn get_outgoing_webhook_request(
     merchant_context: &domain::MerchantContext,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    webhook_endpoint: &types::WebhookDeliveryEndpoint,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        webhook_endpoint: &types::WebhookDeliveryEndpoint,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -675,7 +892,6 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -695,12 +911,26 @@ pub(crate) fn get_outgoing_webhook_request(
             );
         };
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(
+                webhook_endpoint
+                    .signing_secret
+                    .as_ref()
+                    .map(PeekInterface::peek),
+            )?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
         }
 
+        if let Some(previous_signing_secret) = webhook_endpoint.previous_signing_secret.as_ref() {
+            if let Some(signature) = transformed_outgoing_webhook
+                .get_outgoing_webhooks_signature(Some(previous_signing_secret.peek()))?
+                .signature
+            {
+                WebhookType::add_previous_webhook_signature_header(&mut headers, signature)
+            }
+        }
+
         Ok(OutgoingWebhookRequestContent {
             body: outgoing_webhooks_signature.payload,
             headers: headers
@@ -715,12 +945,17 @@ pub(crate) fn get_outgoing_webhook_request(
         .get_compatible_connector()
     {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                webhook_endpoint,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            webhook_endpoint,
         ),
     }
 }
@@ -797,7 +1032,7 @@ async fn api_client_error_handler(
     // much of diagnostic information to be exposed to the merchant.
     update_event_if_client_error(
         state.clone(),
-        merchant_key_store,
+        merchant_key_store.clone(),
         merchant_id,
         event_id,
         "Unable to send request to merchant server".to_string(),
@@ -813,13 +1048,14 @@ async fn api_client_error_handler(
 
     if let ScheduleWebhookRetry::WithProcessTracker(process_tracker) = schedule_webhook_retry {
         // Schedule a retry attempt for webhook delivery
-        outgoing_webhook_retry::retry_webhook_delivery_task(
-            &*state.store,
+        schedule_webhook_retry_or_move_to_dead_letter_queue(
+            &state,
+            &merchant_key_store,
             merchant_id,
             *process_tracker,
+            None,
         )
-        .await
-        .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?;
+        .await?;
     }
 
     Err(error)
@@ -973,6 +1209,7 @@ async fn success_response_handler(
 
 async fn error_response_handler(
     state: SessionState,
+    merchant_key_store: domain::MerchantKeyStore,
     merchant_id: &common_utils::id_type::MerchantId,
     delivery_attempt: enums::WebhookDeliveryAttempt,
     status_code: u16,
@@ -989,18 +1226,76 @@ async fn error_response_handler(
 
     if let ScheduleWebhookRetry::WithProcessTracker(process_tracker) = schedule_webhook_retry {
         // Schedule a retry attempt for webhook delivery
-        outgoing_webhook_retry::retry_webhook_delivery_task(
-            &*state.store,
+        schedule_webhook_retry_or_move_to_dead_letter_queue(
+            &state,
+            &merchant_key_store,
             merchant_id,
             *process_tracker,
+            Some(status_code),
         )
-        .await
-        .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?;
+        .await?;
     }
 
     Err(error)
 }
 
+/// Schedules the next automatic retry of the webhook delivery, or moves the event to the
+/// dead-letter queue if the retries are exhausted, so that it can be replayed once the merchant
+/// server recovers.
+async fn schedule_webhook_retry_or_move_to_dead_letter_queue(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    merchant_id: &common_utils::id_type::MerchantId,
+    process_tracker: storage::ProcessTracker,
+    status_code: Option<u16>,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let tracking_data = process_tracker.tracking_data.clone();
+
+    let is_retry_scheduled = outgoing_webhook_retry::retry_webhook_delivery_task(
+        &*state.store,
+        merchant_id,
+        process_tracker,
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?;
+
+    if is_retry_scheduled {
+        return Ok(());
+    }
+
+    let initial_attempt_id = tracking_data
+        .parse_value::<types::OutgoingWebhookTrackingData>("OutgoingWebhookTrackingData")
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?
+        .initial_attempt_id
+        .get_required_value("initial_attempt_id")
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .attach_printable("Failed to move event to dead-letter queue")?;
+
+    logger::info!(
+        %initial_attempt_id,
+        ?status_code,
+        "Outgoing webhook retries exhausted, moving event to dead-letter queue"
+    );
+
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            &state.into(),
+            merchant_id,
+            &initial_attempt_id,
+            domain::EventUpdate::DeadLetterUpdate {
+                dead_lettered_at: common_utils::date_time::now(),
+                dead_letter_status_code: status_code.map(i32::from),
+            },
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .attach_printable("Failed to move event to dead-letter queue")?;
+
+    Ok(())
+}
+
 impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
     fn foreign_from(content: &api::OutgoingWebhookContent) -> Self {
         match content {
@@ -31,6 +31,13 @@ pub trait OutgoingWebhookType:
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
+
+    /// Adds the signature generated with the signing secret being rotated out, which is sent
+    /// along with the signature generated with the current secret until the rotation expires
+    fn add_previous_webhook_signature_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    );
 }
 
 impl OutgoingWebhookType for webhooks::OutgoingWebhook {
@@ -65,6 +72,28 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
         header.push((headers::X_WEBHOOK_SIGNATURE.to_string(), signature.into()))
     }
+
+    fn add_previous_webhook_signature_header(
+        header: &mut Vec<(String, Maskable<String>)>,
+        signature: String,
+    ) {
+        header.push((
+            headers::X_WEBHOOK_SIGNATURE_PREVIOUS.to_string(),
+            signature.into(),
+        ))
+    }
+}
+
+/// An endpoint of the business profile an outgoing webhook is delivered to
+#[derive(Clone, Debug)]
+pub(crate) struct WebhookDeliveryEndpoint {
+    /// Identifier of the additional webhook endpoint, not set for the webhook URL of the profile
+    pub(crate) endpoint_id: Option<String>,
+    pub(crate) url: String,
+    pub(crate) signing_secret: Option<Secret<String>>,
+    /// Signing secret being rotated out, webhooks are signed with it as well until the rotation
+    /// expires
+    pub(crate) previous_signing_secret: Option<Secret<String>>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
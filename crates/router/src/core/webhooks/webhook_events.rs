@@ -15,6 +15,20 @@ use crate::{
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const DEAD_LETTER_EVENTS_REPLAY_MAX_EVENTS: usize = 100;
+const DEAD_LETTER_EVENTS_REPLAY_DEFAULT_EVENTS_PER_MINUTE: u16 = 60;
+
+/// Reserves the next delivery slot of the dead-letter replays of the merchant, which is not earlier
+/// than the current time, and moves the next slot by the delivery interval. The times are in
+/// milliseconds since the unix epoch, and the key expires once all the reserved slots are past.
+#[cfg(feature = "v1")]
+const DEAD_LETTER_REPLAY_SLOT_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local delivery_interval = tonumber(ARGV[2])
+local slot = math.max(tonumber(redis.call("GET", KEYS[1])) or now, now)
+redis.call("SET", KEYS[1], slot + delivery_interval, "PX", slot + delivery_interval - now + 1000)
+return slot
+"#;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -308,6 +322,9 @@ pub async fn retry_delivery_attempt(
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
         is_overall_delivery_successful: Some(false),
+        webhook_endpoint_id: event_to_retry.webhook_endpoint_id,
+        dead_lettered_at: None,
+        dead_letter_status_code: None,
     };
 
     let event = store
@@ -354,6 +371,305 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+pub async fn list_dead_letter_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    constraints: api::webhook_events::DeadLetterEventListConstraints,
+) -> RouterResponse<api::webhook_events::TotalDeadLetterEventsResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    // Also validates that the business profile, if specified, belongs to the merchant
+    let (_, key_store) = get_account_and_key_store(
+        state.clone(),
+        merchant_id.clone(),
+        constraints.profile_id.clone(),
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let events_list_begin_time =
+        (now.date() - time::Duration::days(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS)).midnight();
+
+    let dead_lettered_after = match constraints.dead_lettered_after {
+        Some(dead_lettered_after) if dead_lettered_after < events_list_begin_time => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`dead_lettered_after` must be a timestamp within the past {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS} days."),
+            })
+        }
+        Some(dead_lettered_after) => Ok(dead_lettered_after),
+        None => Ok(events_list_begin_time),
+    }?;
+    let dead_lettered_before = constraints.dead_lettered_before.unwrap_or(now);
+
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number less than {INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT}"
+                ),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+    let event_types = constraints.event_types.unwrap_or_default();
+    let status_code = constraints.status_code.map(i32::from);
+
+    let events = store
+        .list_dead_letter_events_by_merchant_id_constraints(
+            key_manager_state,
+            &merchant_id,
+            constraints.profile_id.clone(),
+            dead_lettered_after,
+            dead_lettered_before,
+            Some(limit),
+            offset,
+            event_types.clone(),
+            status_code,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dead-lettered events with specified constraints")?;
+
+    let total_count = store
+        .count_dead_letter_events_by_constraints(
+            &merchant_id,
+            constraints.profile_id,
+            dead_lettered_after,
+            dead_lettered_before,
+            event_types,
+            status_code,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get total dead-lettered events count")?;
+
+    let events = events
+        .into_iter()
+        .map(api::webhook_events::DeadLetterEventResponse::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::TotalDeadLetterEventsResponse {
+            events,
+            total_count,
+        },
+    ))
+}
+
+/// Re-enqueues the specified events in the dead-letter queue through the outgoing webhook retry
+/// workflow. The deliveries are spread out to at most `max_events_per_minute`, including the
+/// deliveries scheduled by previous replays which are yet to be delivered, so that a recovering
+/// merchant server is not flooded with webhooks.
+#[instrument(skip(state))]
+#[cfg(feature = "v1")]
+pub async fn replay_dead_letter_events(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    request: api::webhook_events::DeadLetterEventReplayRequest,
+) -> RouterResponse<api::webhook_events::DeadLetterEventReplayResponse> {
+    fp_utils::when(request.event_ids.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`event_ids` must not be empty".to_string(),
+        })
+    })?;
+    fp_utils::when(
+        request.event_ids.len() > DEAD_LETTER_EVENTS_REPLAY_MAX_EVENTS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "At most {DEAD_LETTER_EVENTS_REPLAY_MAX_EVENTS} events can be replayed at once"
+                ),
+            })
+        },
+    )?;
+    let max_events_per_minute = request
+        .max_events_per_minute
+        .unwrap_or(DEAD_LETTER_EVENTS_REPLAY_DEFAULT_EVENTS_PER_MINUTE);
+    fp_utils::when(max_events_per_minute == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`max_events_per_minute` must be greater than 0".to_string(),
+        })
+    })?;
+    let delivery_interval = time::Duration::milliseconds(60_000 / i64::from(max_events_per_minute));
+
+    let store = state.store.as_ref();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &(&state).into(),
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut replayed_event_ids = HashSet::new();
+    let mut results = Vec::with_capacity(request.event_ids.len());
+    for event_id in request.event_ids {
+        if !replayed_event_ids.insert(event_id.clone()) {
+            continue;
+        }
+
+        let (status, scheduled_at) =
+            replay_dead_letter_event(&state, &key_store, &event_id, delivery_interval).await?;
+
+        results.push(api::webhook_events::DeadLetterEventReplayResult {
+            event_id,
+            status,
+            scheduled_at,
+        });
+    }
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::DeadLetterEventReplayResponse { events: results },
+    ))
+}
+
+#[cfg(feature = "v1")]
+fn get_dead_letter_replay_schedule_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!(
+        "webhook_dead_letter_replay_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Reserves the time at which the next replayed event of the merchant is to be delivered. The slot
+/// is reserved atomically, so that concurrent replays do not schedule deliveries at the same time.
+#[cfg(feature = "v1")]
+async fn reserve_dead_letter_replay_slot(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    delivery_interval: time::Duration,
+) -> errors::RouterResult<time::PrimitiveDateTime> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let now = common_utils::date_time::now().assume_utc();
+    let slot_in_millis = redis_conn
+        .evaluate_redis_script::<_, i64>(
+            DEAD_LETTER_REPLAY_SLOT_SCRIPT,
+            vec![redis_conn.add_prefix(&get_dead_letter_replay_schedule_key(merchant_id))],
+            vec![
+                (now.unix_timestamp_nanos() / 1_000_000).to_string(),
+                delivery_interval.whole_milliseconds().to_string(),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve the next dead-letter replay slot in redis")?;
+
+    time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(slot_in_millis) * 1_000_000)
+        .map(common_utils::date_time::convert_to_pdt)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid dead-letter replay slot in redis")
+}
+
+#[cfg(feature = "v1")]
+async fn replay_dead_letter_event(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    event_id: &str,
+    delivery_interval: time::Duration,
+) -> errors::RouterResult<(
+    api::webhook_events::DeadLetterEventReplayStatus,
+    Option<time::PrimitiveDateTime>,
+)> {
+    use api::webhook_events::DeadLetterEventReplayStatus;
+
+    let store = state.store.as_ref();
+    let event = match store
+        .find_event_by_merchant_id_event_id(
+            &state.into(),
+            &key_store.merchant_id,
+            event_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(event) => event,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok((DeadLetterEventReplayStatus::NotDeadLettered, None));
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find event to replay"));
+        }
+    };
+
+    let is_dead_lettered = event.dead_lettered_at.is_some()
+        && event.is_overall_delivery_successful == Some(false)
+        && event.initial_attempt_id.as_deref() == Some(event.event_id.as_str());
+    if !is_dead_lettered {
+        return Ok((DeadLetterEventReplayStatus::NotDeadLettered, None));
+    }
+
+    let process_tracker_id =
+        super::outgoing::get_outgoing_webhook_retry_task_id(&key_store.merchant_id, event_id);
+    let process_tracker = store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find outgoing webhook retry task")?;
+
+    match process_tracker {
+        Some(process_tracker)
+            if process_tracker.status != storage::enums::ProcessTrackerStatus::Finish =>
+        {
+            Ok((
+                DeadLetterEventReplayStatus::AlreadyScheduled,
+                process_tracker.schedule_time,
+            ))
+        }
+        // The retry task is restarted from the first retry, so that the event goes through the
+        // retry schedule again, and is moved back to the dead-letter queue if the deliveries fail
+        Some(process_tracker) => {
+            let schedule_time =
+                reserve_dead_letter_replay_slot(state, &key_store.merchant_id, delivery_interval)
+                    .await?;
+            store
+                .as_scheduler()
+                .reset_process(process_tracker, schedule_time)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule outgoing webhook retry task")?;
+            Ok((DeadLetterEventReplayStatus::Scheduled, Some(schedule_time)))
+        }
+        // The retry task may have been cleaned up by the scheduler
+        None => {
+            let business_profile_id = event
+                .business_profile_id
+                .as_ref()
+                .get_required_value("business_profile_id")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to read business profile ID from event to replay")?;
+            let schedule_time =
+                reserve_dead_letter_replay_slot(state, &key_store.merchant_id, delivery_interval)
+                    .await?;
+            super::outgoing::insert_outgoing_webhook_retry_task(
+                store,
+                &key_store.merchant_id,
+                business_profile_id,
+                &event,
+                schedule_time,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add outgoing webhook retry task")?;
+            Ok((DeadLetterEventReplayStatus::Scheduled, Some(schedule_time)))
+        }
+    }
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
@@ -15,11 +15,13 @@ pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
 pub mod events;
+pub mod exchange_rate_snapshot;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod idempotency_key;
 pub mod kafka_store;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -104,8 +106,10 @@ pub trait StorageInterface:
     + ephemeral_key::EphemeralKeyInterface
     + ephemeral_key::ClientSecretInterface
     + events::EventInterface
+    + exchange_rate_snapshot::ExchangeRateSnapshotInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + idempotency_key::IdempotencyKeyInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
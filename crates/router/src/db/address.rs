@@ -79,6 +79,16 @@ where
         address: storage_types::AddressUpdate,
         key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Address>, errors::StorageError>;
+
+    async fn delete_address_for_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        address_id: &str,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<domain::PaymentAddress, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -300,6 +310,33 @@ mod storage {
             })
             .await
         }
+
+        #[instrument(skip_all)]
+        async fn delete_address_for_payments(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            _payment_id: &id_type::PaymentId,
+            address_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Address::delete_by_merchant_id_address_id(&conn, merchant_id, address_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+                .async_and_then(|address| async {
+                    address
+                        .convert(
+                            state,
+                            key_store.key.get_inner(),
+                            key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)
+                })
+                .await
+        }
     }
 }
 
@@ -310,8 +347,9 @@ mod storage {
     use error_stack::{report, ResultExt};
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
-    use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    use storage_impl::{
+        kv_router_store::{DeleteResourceParams, FindResourceBy},
+        redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
     };
 
     use super::AddressInterface;
@@ -697,6 +735,43 @@ mod storage {
             })
             .await
         }
+
+        #[instrument(skip_all)]
+        async fn delete_address_for_payments(
+            &self,
+            state: &KeyManagerState,
+            merchant_id: &id_type::MerchantId,
+            payment_id: &id_type::PaymentId,
+            address_id: &str,
+            key_store: &domain::MerchantKeyStore,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            self.delete_resource(
+                state,
+                key_store,
+                storage_scheme,
+                storage_types::Address::delete_by_merchant_id_address_id(
+                    &conn,
+                    merchant_id,
+                    address_id,
+                ),
+                DeleteResourceParams {
+                    deletable: kv::Deletable::AddressDelete(kv::AddressDeleteMems {
+                        merchant_id: merchant_id.clone(),
+                        address_id: address_id.to_owned(),
+                    }),
+                    find_by: FindResourceBy::Id(
+                        format!("add_{address_id}"),
+                        PartitionKey::MerchantIdPaymentId {
+                            merchant_id,
+                            payment_id,
+                        },
+                    ),
+                },
+            )
+            .await
+        }
     }
 }
 
@@ -927,4 +1002,32 @@ impl AddressInterface for MockDb {
             }
         }
     }
+
+    async fn delete_address_for_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        _payment_id: &id_type::PaymentId,
+        address_id: &str,
+        key_store: &domain::MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
+        let mut addresses = self.addresses.lock().await;
+        match addresses.iter().position(|address| {
+            address.merchant_id == *merchant_id && address.address_id == address_id
+        }) {
+            Some(index) => addresses
+                .remove(index)
+                .convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError),
+            None => {
+                Err(errors::StorageError::ValueNotFound("address not found".to_string()).into())
+            }
+        }
+    }
 }
@@ -233,6 +233,7 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            restrictions: api_key.restrictions,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +259,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                restrictions,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +274,9 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if restrictions.is_some() {
+                    key_to_update.restrictions = restrictions;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -417,6 +422,7 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                restrictions: None,
             })
             .await
             .unwrap();
@@ -432,6 +438,7 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                restrictions: None,
             })
             .await
             .unwrap();
@@ -513,6 +520,7 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            restrictions: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -41,6 +41,12 @@ pub trait BlocklistInterface {
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn list_blocklist_entries_by_merchant_id_data_kinds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -100,6 +106,18 @@ impl BlocklistInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn list_blocklist_entries_by_merchant_id_data_kinds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Blocklist::list_by_merchant_id_data_kinds(&conn, merchant_id, data_kinds)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
@@ -148,6 +166,14 @@ impl BlocklistInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_blocklist_entries_by_merchant_id_data_kinds(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_blocklist_entry_by_merchant_id_fingerprint_id(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
@@ -202,6 +228,17 @@ impl BlocklistInterface for KafkaStore {
             .await
     }
 
+    #[instrument(skip_all)]
+    async fn list_blocklist_entries_by_merchant_id_data_kinds(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        data_kinds: Vec<common_enums::BlocklistDataKind>,
+    ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError> {
+        self.diesel_store
+            .list_blocklist_entries_by_merchant_id_data_kinds(merchant_id, data_kinds)
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn list_blocklist_entries_by_merchant_id(
         &self,
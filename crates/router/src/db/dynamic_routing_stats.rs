@@ -29,6 +29,13 @@ pub trait DynamicRoutingStatsInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         data: storage::DynamicRoutingStatsUpdate,
     ) -> CustomResult<storage::DynamicRoutingStats, errors::StorageError>;
+
+    async fn get_dynamic_routing_stats_payment_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -71,6 +78,24 @@ impl DynamicRoutingStatsInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn get_dynamic_routing_stats_payment_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DynamicRoutingStats::get_payment_status_with_count_by_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            time_range,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -99,6 +124,15 @@ impl DynamicRoutingStatsInterface for MockDb {
     ) -> CustomResult<storage::DynamicRoutingStats, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn get_dynamic_routing_stats_payment_status_with_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -133,4 +167,19 @@ impl DynamicRoutingStatsInterface for KafkaStore {
             .update_dynamic_routing_stats(attempt_id, merchant_id, data)
             .await
     }
+
+    async fn get_dynamic_routing_stats_payment_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<Vec<(common_enums::AttemptStatus, i64)>, errors::StorageError> {
+        self.diesel_store
+            .get_dynamic_routing_stats_payment_status_with_count(
+                merchant_id,
+                profile_id,
+                time_range,
+            )
+            .await
+    }
 }
@@ -108,6 +108,30 @@ where
         event_types: HashSet<common_enums::EventType>,
         is_delivered: Option<bool>,
     ) -> CustomResult<i64, errors::StorageError>;
+    #[allow(clippy::too_many_arguments)]
+    async fn list_dead_letter_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
+    async fn count_dead_letter_events_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -398,6 +422,75 @@ impl EventInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+    #[instrument(skip_all)]
+    async fn list_dead_letter_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_dead_letter_attempts_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            profile_id,
+            dead_lettered_after,
+            dead_lettered_before,
+            limit,
+            offset,
+            event_types,
+            status_code,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
+    async fn count_dead_letter_events_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::count_dead_letter_attempts_by_constraints(
+            &conn,
+            merchant_id,
+            profile_id,
+            dead_lettered_after,
+            dead_lettered_before,
+            event_types,
+            status_code,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -729,6 +822,13 @@ impl EventInterface for MockDb {
                 event_to_update.is_overall_delivery_successful =
                     Some(is_overall_delivery_successful)
             }
+            domain::EventUpdate::DeadLetterUpdate {
+                dead_lettered_at,
+                dead_letter_status_code,
+            } => {
+                event_to_update.dead_lettered_at = Some(dead_lettered_at);
+                event_to_update.dead_letter_status_code = dead_letter_status_code;
+            }
         }
 
         event_to_update
@@ -771,6 +871,111 @@ impl EventInterface for MockDb {
             .change_context(errors::StorageError::MockDbError)
             .attach_printable("Failed to convert usize to i64")
     }
+    async fn list_dead_letter_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                is_dead_letter_event_matching(
+                    event,
+                    merchant_id,
+                    profile_id.as_ref(),
+                    (dead_lettered_after, dead_lettered_before),
+                    &event_types,
+                    status_code,
+                )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| b.dead_lettered_at.cmp(&a.dead_lettered_at));
+
+        let offset =
+            usize::try_from(offset.unwrap_or(0)).map_err(|_| errors::StorageError::MockDbError)?;
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .map_err(|_| errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut domain_events = Vec::new();
+        for event in events.into_iter().skip(offset).take(limit) {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
+    async fn count_dead_letter_events_by_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        dead_lettered_after: time::PrimitiveDateTime,
+        dead_lettered_before: time::PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let count = locked_events
+            .iter()
+            .filter(|event| {
+                is_dead_letter_event_matching(
+                    event,
+                    merchant_id,
+                    profile_id.as_ref(),
+                    (dead_lettered_after, dead_lettered_before),
+                    &event_types,
+                    status_code,
+                )
+            })
+            .count();
+
+        i64::try_from(count)
+            .change_context(errors::StorageError::MockDbError)
+            .attach_printable("Failed to convert usize to i64")
+    }
+}
+
+fn is_dead_letter_event_matching(
+    event: &storage::events::Event,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    (dead_lettered_after, dead_lettered_before): (time::PrimitiveDateTime, time::PrimitiveDateTime),
+    event_types: &HashSet<common_enums::EventType>,
+    status_code: Option<i32>,
+) -> bool {
+    event.initial_attempt_id.as_ref() == Some(&event.event_id)
+        && event.merchant_id.as_ref() == Some(merchant_id)
+        && profile_id.map_or(true, |profile_id| {
+            event.business_profile_id.as_ref() == Some(profile_id)
+        })
+        && event.is_overall_delivery_successful == Some(false)
+        && event.dead_lettered_at.is_some_and(|dead_lettered_at| {
+            dead_lettered_at >= dead_lettered_after && dead_lettered_at <= dead_lettered_before
+        })
+        && (event_types.is_empty() || event_types.contains(&event.event_type))
+        && status_code.map_or(true, |status_code| {
+            event.dead_letter_status_code == Some(status_code)
+        })
 }
 
 #[cfg(test)]
@@ -884,6 +1089,9 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
+                    dead_lettered_at: None,
+                    dead_letter_status_code: None,
                 },
                 &merchant_key_store,
             )
@@ -1001,6 +1209,9 @@ mod tests {
                         .unwrap(),
                     }),
                     is_overall_delivery_successful: Some(false),
+                    webhook_endpoint_id: None,
+                    dead_lettered_at: None,
+                    dead_letter_status_code: None,
                 },
                 &merchant_key_store,
             )
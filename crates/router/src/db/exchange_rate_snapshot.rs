@@ -0,0 +1,105 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ExchangeRateSnapshotInterface {
+    /// Stores the snapshot for its rate date, replacing any snapshot already taken on that date
+    async fn upsert_exchange_rate_snapshot(
+        &self,
+        snapshot: storage::ExchangeRateSnapshotNew,
+    ) -> CustomResult<storage::ExchangeRateSnapshot, errors::StorageError>;
+
+    async fn find_latest_exchange_rate_snapshot_on_or_before(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<Option<storage::ExchangeRateSnapshot>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ExchangeRateSnapshotInterface for Store {
+    #[instrument(skip_all)]
+    async fn upsert_exchange_rate_snapshot(
+        &self,
+        snapshot: storage::ExchangeRateSnapshotNew,
+    ) -> CustomResult<storage::ExchangeRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let rate_date = snapshot.rate_date;
+        match snapshot.clone().insert(&conn).await {
+            Ok(snapshot) => Ok(snapshot),
+            Err(error) => {
+                let error = errors::StorageError::from(error);
+                if error.is_db_unique_violation() {
+                    storage::ExchangeRateSnapshot::update_by_rate_date(
+                        &conn,
+                        rate_date,
+                        snapshot.into(),
+                    )
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+                } else {
+                    Err(report!(error))
+                }
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_exchange_rate_snapshot_on_or_before(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<Option<storage::ExchangeRateSnapshot>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ExchangeRateSnapshot::find_latest_on_or_before(&conn, rate_date)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeRateSnapshotInterface for MockDb {
+    async fn upsert_exchange_rate_snapshot(
+        &self,
+        _snapshot: storage::ExchangeRateSnapshotNew,
+    ) -> CustomResult<storage::ExchangeRateSnapshot, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_latest_exchange_rate_snapshot_on_or_before(
+        &self,
+        _rate_date: time::Date,
+    ) -> CustomResult<Option<storage::ExchangeRateSnapshot>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeRateSnapshotInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn upsert_exchange_rate_snapshot(
+        &self,
+        snapshot: storage::ExchangeRateSnapshotNew,
+    ) -> CustomResult<storage::ExchangeRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .upsert_exchange_rate_snapshot(snapshot)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_exchange_rate_snapshot_on_or_before(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<Option<storage::ExchangeRateSnapshot>, errors::StorageError> {
+        self.diesel_store
+            .find_latest_exchange_rate_snapshot_on_or_before(rate_date)
+            .await
+    }
+}
@@ -49,6 +49,26 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<bool, errors::StorageError>;
+
+    #[allow(clippy::too_many_arguments)]
+    async fn increment_gsm_rule_hit_count(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        hit_count: i64,
+        last_hit_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_gsm_rules_by_connector(
+        &self,
+        connector: String,
+        flow: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -126,6 +146,47 @@ impl GsmInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn increment_gsm_rule_hit_count(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        hit_count: i64,
+        last_hit_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::GatewayStatusMap::increment_hit_count(
+            &conn,
+            connector,
+            flow,
+            sub_flow,
+            code,
+            message,
+            hit_count,
+            last_hit_at,
+        )
+        .await
+        .map(|_| ())
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_gsm_rules_by_connector(
+        &self,
+        connector: String,
+        flow: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::list_by_connector(&conn, connector, flow, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -181,4 +242,27 @@ impl GsmInterface for MockDb {
     ) -> CustomResult<bool, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn increment_gsm_rule_hit_count(
+        &self,
+        _connector: String,
+        _flow: String,
+        _sub_flow: String,
+        _code: String,
+        _message: String,
+        _hit_count: i64,
+        _last_hit_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_gsm_rules_by_connector(
+        &self,
+        _connector: String,
+        _flow: Option<String>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
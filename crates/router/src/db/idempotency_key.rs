@@ -0,0 +1,117 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IdempotencyKeyInterface {
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        match idempotency_key.clone().insert(&conn).await {
+            Ok(idempotency_key) => Ok(idempotency_key),
+            Err(error) => {
+                let error = errors::StorageError::from(error);
+                if error.is_db_unique_violation() {
+                    // The key may have been used before and expired since, in which case its
+                    // record is replaced
+                    storage::IdempotencyKey::delete_expired_by_merchant_id_idempotency_key(
+                        &conn,
+                        &idempotency_key.merchant_id,
+                        &idempotency_key.idempotency_key,
+                    )
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))?;
+                    idempotency_key
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                } else {
+                    Err(report!(error))
+                }
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IdempotencyKey::find_unexpired_by_merchant_id_idempotency_key(
+            &conn,
+            merchant_id,
+            idempotency_key,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for MockDb {
+    async fn insert_idempotency_key(
+        &self,
+        _idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyKeyInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_idempotency_key(
+        &self,
+        idempotency_key: storage::IdempotencyKeyNew,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        self.diesel_store
+            .insert_idempotency_key(idempotency_key)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_idempotency_key_by_merchant_id_idempotency_key(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        idempotency_key: &str,
+    ) -> CustomResult<storage::IdempotencyKey, errors::StorageError> {
+        self.diesel_store
+            .find_idempotency_key_by_merchant_id_idempotency_key(merchant_id, idempotency_key)
+            .await
+    }
+}
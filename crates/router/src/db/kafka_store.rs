@@ -223,6 +223,27 @@ impl AddressInterface for KafkaStore {
             )
             .await
     }
+
+    async fn delete_address_for_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        address_id: &str,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
+        self.diesel_store
+            .delete_address_for_payments(
+                state,
+                merchant_id,
+                payment_id,
+                address_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -890,6 +911,55 @@ impl EventInterface for KafkaStore {
             )
             .await
     }
+    async fn list_dead_letter_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        dead_lettered_after: PrimitiveDateTime,
+        dead_lettered_before: PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_dead_letter_events_by_merchant_id_constraints(
+                state,
+                merchant_id,
+                profile_id,
+                dead_lettered_after,
+                dead_lettered_before,
+                limit,
+                offset,
+                event_types,
+                status_code,
+                merchant_key_store,
+            )
+            .await
+    }
+
+    async fn count_dead_letter_events_by_constraints(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        dead_lettered_after: PrimitiveDateTime,
+        dead_lettered_before: PrimitiveDateTime,
+        event_types: HashSet<common_enums::EventType>,
+        status_code: Option<i32>,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_dead_letter_events_by_constraints(
+                merchant_id,
+                profile_id,
+                dead_lettered_after,
+                dead_lettered_before,
+                event_types,
+                status_code,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1002,6 +1072,17 @@ impl MandateInterface for KafkaStore {
             .insert_mandate(mandate, storage_scheme)
             .await
     }
+
+    async fn delete_mandate_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        self.diesel_store
+            .delete_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1410,9 +1491,10 @@ impl QueueInterface for KafkaStore {
         stream_name: &str,
         group_name: &str,
         consumer_name: &str,
+        batch_count: u64,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
         self.diesel_store
-            .fetch_consumer_tasks(stream_name, group_name, consumer_name)
+            .fetch_consumer_tasks(stream_name, group_name, consumer_name, batch_count)
             .await
     }
 
@@ -1457,6 +1539,26 @@ impl QueueInterface for KafkaStore {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.diesel_store.get_key(key).await
     }
+
+    async fn pause_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<(), RedisError> {
+        self.diesel_store.pause_runner(runner).await
+    }
+
+    async fn resume_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<bool, RedisError> {
+        self.diesel_store.resume_runner(runner).await
+    }
+
+    async fn get_paused_runners(
+        &self,
+    ) -> CustomResult<Vec<(storage::ProcessTrackerRunner, PrimitiveDateTime)>, RedisError> {
+        self.diesel_store.get_paused_runners().await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2289,6 +2391,7 @@ impl PaymentMethodInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
         self.diesel_store
             .delete_payment_method_by_merchant_id_payment_method_id(
@@ -2296,6 +2399,7 @@ impl PaymentMethodInterface for KafkaStore {
                 key_store,
                 merchant_id,
                 payment_method_id,
+                storage_scheme,
             )
             .await
     }
@@ -2613,6 +2717,16 @@ impl ProcessTrackerInterface for KafkaStore {
         self.diesel_store.update_process(this, process).await
     }
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_process_if_status_unchanged(this, process)
+            .await
+    }
+
     async fn process_tracker_update_process_status_by_ids(
         &self,
         task_ids: Vec<String>,
@@ -2666,6 +2780,30 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .list_processes_by_constraints(runner, status, business_status, limit, offset)
+            .await
+    }
+
+    async fn reschedule_overdue_processes_by_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        overdue_since: PrimitiveDateTime,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .reschedule_overdue_processes_by_runner(runner, overdue_since, schedule_time)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3242,6 +3380,41 @@ impl GsmInterface for KafkaStore {
             .delete_gsm_rule(connector, flow, sub_flow, code, message)
             .await
     }
+
+    async fn increment_gsm_rule_hit_count(
+        &self,
+        connector: String,
+        flow: String,
+        sub_flow: String,
+        code: String,
+        message: String,
+        hit_count: i64,
+        last_hit_at: PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .increment_gsm_rule_hit_count(
+                connector,
+                flow,
+                sub_flow,
+                code,
+                message,
+                hit_count,
+                last_hit_at,
+            )
+            .await
+    }
+
+    async fn list_gsm_rules_by_connector(
+        &self,
+        connector: String,
+        flow: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .list_gsm_rules_by_connector(connector, flow, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -55,6 +55,13 @@ pub trait MandateInterface {
         mandate: storage_types::MandateNew,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
+
+    async fn delete_mandate_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -64,8 +71,9 @@ mod storage {
     use error_stack::{report, ResultExt};
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
-    use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    use storage_impl::{
+        kv_router_store::{DeleteResourceParams, FindResourceBy},
+        redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
     };
 
     use super::MandateInterface;
@@ -395,6 +403,38 @@ mod storage {
                 }
             }
         }
+
+        #[instrument(skip_all)]
+        async fn delete_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            self.delete_storage_resource(
+                storage_scheme,
+                storage_types::Mandate::delete_by_merchant_id_mandate_id(
+                    &conn,
+                    merchant_id,
+                    mandate_id,
+                ),
+                DeleteResourceParams {
+                    deletable: kv::Deletable::MandateDelete(kv::MandateDeleteMems {
+                        merchant_id: merchant_id.clone(),
+                        mandate_id: mandate_id.to_owned(),
+                    }),
+                    find_by: FindResourceBy::Id(
+                        format!("mandate_{mandate_id}"),
+                        PartitionKey::MerchantIdMandateId {
+                            merchant_id,
+                            mandate_id,
+                        },
+                    ),
+                },
+            )
+            .await
+        }
     }
 }
 
@@ -513,6 +553,19 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn delete_mandate_by_merchant_id_mandate_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            _storage_scheme: MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::delete_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -696,4 +749,21 @@ impl MandateInterface for MockDb {
         mandates.push(mandate.clone());
         Ok(mandate)
     }
+
+    async fn delete_mandate_by_merchant_id_mandate_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        match mandates.iter().position(|mandate| {
+            mandate.merchant_id == *merchant_id && mandate.mandate_id == mandate_id
+        }) {
+            Some(index) => Ok(mandates.remove(index)),
+            None => {
+                Err(errors::StorageError::ValueNotFound("mandate not found".to_string()).into())
+            }
+        }
+    }
 }
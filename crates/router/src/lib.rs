@@ -59,6 +59,7 @@ pub mod headers {
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+    pub const IDEMPOTENT_REPLAYED: &str = "Idempotent-Replayed";
     pub const NONCE: &str = "nonce";
     pub const TIMESTAMP: &str = "Timestamp";
     pub const TOKEN: &str = "token";
@@ -75,6 +76,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_PREVIOUS: &str = "X-Webhook-Signature-512-Previous";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
@@ -205,7 +207,8 @@ pub fn mk_app(
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ProcessTracker::server(state.clone()));
         }
 
         #[cfg(feature = "v2")]
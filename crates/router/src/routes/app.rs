@@ -937,6 +937,9 @@ impl Routing {
                     )
                 })),
             )
+            .service(
+                web::resource("/simulate").route(web::post().to(routing::routing_simulate_config)),
+            )
             .service(web::resource("/rule/migrate").route(web::post().to(
                 |state, req, query: web::Query<RuleMigrationQuery>| {
                     routing::migrate_routing_rules_for_profile(state, req, query)
@@ -1960,6 +1963,12 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/auto_response_config")
+                    .route(web::get().to(disputes::retrieve_dispute_auto_response_config))
+                    .route(web::post().to(disputes::upsert_dispute_auto_response_config))
+                    .route(web::delete().to(disputes::delete_dispute_auto_response_config)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -2234,6 +2243,8 @@ impl Gsm {
             .service(web::resource("/get").route(web::post().to(gsm::get_gsm_rule)))
             .service(web::resource("/update").route(web::post().to(gsm::update_gsm_rule)))
             .service(web::resource("/delete").route(web::post().to(gsm::delete_gsm_rule)))
+            .service(web::resource("/evaluate").route(web::post().to(gsm::evaluate_gsm_rule)))
+            .service(web::resource("/list").route(web::post().to(gsm::list_gsm_rules)))
     }
 }
 
@@ -2742,6 +2753,15 @@ impl WebhookEvents {
                     .service(web::resource("").route(
                         web::post().to(webhook_events::list_initial_webhook_delivery_attempts),
                     ))
+                    .service(
+                        web::scope("/dead_letter")
+                            .service(web::resource("").route(
+                                web::post().to(webhook_events::list_dead_letter_webhook_events),
+                            ))
+                            .service(web::resource("replay").route(
+                                web::post().to(webhook_events::replay_dead_letter_webhook_events),
+                            )),
+                    )
                     .service(
                         web::scope("/{event_id}")
                             .service(web::resource("attempts").route(
@@ -2787,6 +2807,35 @@ impl ProcessTrackerDeprecated {
 #[cfg(feature = "olap")]
 pub struct ProcessTracker;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::admin;
+        web::scope("/process_trackers")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(admin::list_processes)))
+            .service(
+                web::scope("/runners")
+                    .service(
+                        web::resource("/paused").route(web::get().to(admin::list_paused_runners)),
+                    )
+                    .service(
+                        web::resource("/{runner}/pause").route(web::post().to(admin::pause_runner)),
+                    )
+                    .service(
+                        web::resource("/{runner}/resume")
+                            .route(web::post().to(admin::resume_runner)),
+                    ),
+            )
+            .service(web::resource("/{id}").route(web::get().to(admin::retrieve_process)))
+            .service(web::resource("/{id}/retry").route(web::post().to(admin::retry_process)))
+            .service(
+                web::resource("/{id}/reschedule").route(web::post().to(admin::reschedule_process)),
+            )
+            .service(web::resource("/{id}/cancel").route(web::post().to(admin::cancel_process)))
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 impl ProcessTracker {
     pub fn server(state: AppState) -> Scope {
@@ -7,7 +7,7 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     core::{api_locking, unified_authentication_service},
     routes::app::{self},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::domain,
 };
 
@@ -34,6 +34,7 @@ pub async fn authentication_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -50,7 +51,13 @@ pub async fn authentication_eligibility(
 ) -> impl Responder {
     let flow = Flow::AuthenticationEligibility;
 
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+
+        is_platform_allowed: false,
+
+        permission: Some(Permission::ProfilePaymentWrite),
+    };
     let payload = json_payload.into_inner();
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
@@ -92,7 +99,11 @@ pub async fn authentication_authenticate(
 ) -> impl Responder {
     let flow = Flow::AuthenticationAuthenticate;
     let authentication_id = path.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::ProfilePaymentWrite),
+    };
     let payload = AuthenticationAuthenticateRequest {
         authentication_id,
         ..json_payload.into_inner()
@@ -20,6 +20,7 @@ pub async fn retrieve_forex(state: web::Data<AppState>, req: HttpRequest) -> Htt
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: None,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
@@ -39,6 +40,7 @@ pub async fn convert_forex(
     let amount = params.amount;
     let to_currency = &params.to_currency;
     let from_currency = &params.from_currency;
+    let as_of = params.as_of;
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
@@ -50,12 +52,14 @@ pub async fn convert_forex(
                 amount.get_amount_as_i64(),
                 to_currency.to_string(),
                 from_currency.to_string(),
+                as_of,
             )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: None,
             }),
             &auth::DashboardNoPermissionAuth,
             req.headers(),
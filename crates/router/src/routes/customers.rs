@@ -64,6 +64,7 @@ pub async fn customers_create(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantCustomerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -91,7 +92,11 @@ pub async fn customers_retrieve(
             permission: Permission::MerchantCustomerRead,
         })
     } else {
-        let api_auth = auth::ApiKeyAuth::default();
+        let api_auth = auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
+        };
         match auth::is_ephemeral_auth(req.headers(), api_auth) {
             Ok(auth) => auth,
             Err(err) => return api::log_and_return_error_response(err),
@@ -228,6 +233,7 @@ pub async fn customers_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantCustomerRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerRead,
@@ -270,6 +276,7 @@ pub async fn customers_update(
             &auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantCustomerWrite),
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -381,6 +388,7 @@ pub async fn customers_delete(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantCustomerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantCustomerWrite,
@@ -417,6 +425,7 @@ pub async fn get_customer_mandates(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantMandateRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantMandateRead,
@@ -54,6 +54,7 @@ pub async fn retrieve_dispute(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -111,6 +112,7 @@ pub async fn retrieve_disputes_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -175,6 +177,7 @@ pub async fn retrieve_disputes_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -216,6 +219,7 @@ pub async fn get_disputes_filters(state: web::Data<AppState>, req: HttpRequest)
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -264,6 +268,7 @@ pub async fn get_disputes_filters_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -316,6 +321,7 @@ pub async fn accept_dispute(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -363,6 +369,7 @@ pub async fn submit_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -417,6 +424,7 @@ pub async fn attach_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -469,6 +477,7 @@ pub async fn retrieve_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -517,6 +526,7 @@ pub async fn delete_dispute_evidence(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeWrite,
@@ -552,6 +562,7 @@ pub async fn get_disputes_aggregate(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantDisputeRead,
@@ -593,6 +604,7 @@ pub async fn get_disputes_aggregate_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileDisputeRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileDisputeRead,
@@ -603,3 +615,110 @@ pub async fn get_disputes_aggregate_profile(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAutoResponseConfigUpsert))]
+pub async fn upsert_dispute_auto_response_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeAutoResponseConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::DisputeAutoResponseConfigUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::auto_response::upsert_dispute_auto_response_config(
+                state,
+                merchant_context,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeWrite),
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAutoResponseConfigRetrieve))]
+pub async fn retrieve_dispute_auto_response_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeAutoResponseConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::auto_response::retrieve_dispute_auto_response_config(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeRead),
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeAutoResponseConfigDelete))]
+pub async fn delete_dispute_auto_response_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeAutoResponseConfigDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            disputes::auto_response::delete_dispute_auto_response_config(state, merchant_context)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Some(Permission::MerchantDisputeWrite),
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantDisputeWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -6,7 +6,7 @@ use super::AppState;
 use crate::types::domain;
 use crate::{
     core::{api_locking, payments::helpers},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[cfg(feature = "v1")]
@@ -33,6 +33,7 @@ pub async fn ephemeral_key_create(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     )
@@ -57,6 +58,7 @@ pub async fn ephemeral_key_delete(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     )
@@ -3,7 +3,7 @@ use router_env::Flow;
 
 use crate::{
     core::{api_locking, fraud_check as frm_core},
-    services::{self, api},
+    services::{self, api, authorization::permissions::Permission},
     types::domain,
     AppState,
 };
@@ -29,6 +29,7 @@ pub async fn frm_fulfillment(
         &services::authentication::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentWrite),
         },
         api_locking::LockAction::NotApplicable,
     ))
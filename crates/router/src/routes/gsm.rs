@@ -159,3 +159,79 @@ pub async fn delete_gsm_rule(
     ))
     .await
 }
+
+/// Gsm - Evaluate
+///
+/// To evaluate a connector error against the Gsm Rules, returning the matched rule and the retry decision
+#[utoipa::path(
+    post,
+    path = "/gsm/evaluate",
+    request_body(
+        content = GsmEvaluateRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm evaluated", body = GsmEvaluateResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "Evaluate Gsm Rule",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleEvaluate))]
+pub async fn evaluate_gsm_rule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmEvaluateRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRuleEvaluate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::evaluate_gsm_rule(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Gsm - List
+///
+/// To list the Gsm Rules of a connector, the most matched ones first
+#[utoipa::path(
+    post,
+    path = "/gsm/list",
+    request_body(
+        content = GsmListRequest,
+    ),
+    responses(
+        (status = 200, description = "Gsm rules listed", body = Vec<GsmResponse>),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Gsm",
+    operation_id = "List Gsm Rules",
+    security(("admin_api_key" = [])),
+)]
+#[instrument(skip_all, fields(flow = ?Flow::GsmRuleList))]
+pub async fn list_gsm_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<gsm_api_types::GsmListRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+
+    let flow = Flow::GsmRuleList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| gsm::list_gsm_rules(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
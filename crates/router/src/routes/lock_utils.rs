@@ -1,6 +1,6 @@
 use router_env::Flow;
 
-#[derive(Clone, Debug, strum::Display)]
+#[derive(Clone, Debug, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
@@ -82,6 +82,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UpdateDynamicRoutingConfigs
             | Flow::DecisionManagerUpsertConfig
             | Flow::RoutingEvaluateRule
+            | Flow::RoutingSimulateConfig
             | Flow::DecisionEngineRuleMigration
             | Flow::VolumeSplitOnRoutingType
             | Flow::DecisionEngineDecideGatewayCall
@@ -196,6 +197,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventDeadLetterList
+            | Flow::WebhookEventDeadLetterReplay
             | Flow::RecoveryIncomingWebhookReceive
             | Flow::IncomingNetworkTokenWebhookReceive => Self::Webhooks,
 
@@ -212,7 +215,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
-            | Flow::DeleteDisputeEvidence => Self::Disputes,
+            | Flow::DeleteDisputeEvidence
+            | Flow::DisputeAutoResponseConfigUpsert
+            | Flow::DisputeAutoResponseConfigRetrieve
+            | Flow::DisputeAutoResponseConfigDelete => Self::Disputes,
 
             Flow::CardsInfo
             | Flow::CardsInfoCreate
@@ -243,7 +249,9 @@ impl From<Flow> for ApiIdentifier {
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
-            | Flow::GsmRuleDelete => Self::Gsm,
+            | Flow::GsmRuleDelete
+            | Flow::GsmRuleEvaluate
+            | Flow::GsmRuleList => Self::Gsm,
 
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
@@ -360,7 +368,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
 
-            Flow::RevenueRecoveryRetrieve => Self::ProcessTracker,
+            Flow::RevenueRecoveryRetrieve
+            | Flow::ProcessTrackerList
+            | Flow::ProcessTrackerRetrieve
+            | Flow::ProcessTrackerRetry
+            | Flow::ProcessTrackerReschedule
+            | Flow::ProcessTrackerCancel
+            | Flow::ProcessTrackerPausedRunnersList
+            | Flow::ProcessTrackerRunnerPause
+            | Flow::ProcessTrackerRunnerResume => Self::ProcessTracker,
 
             Flow::AuthenticationCreate
             | Flow::AuthenticationEligibility
@@ -36,6 +36,7 @@ pub async fn get_mandate(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantMandateRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -67,6 +68,7 @@ pub async fn revoke_mandate(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantMandateWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -96,6 +98,7 @@ pub async fn retrieve_mandates_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantMandateRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantMandateRead,
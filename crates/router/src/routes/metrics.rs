@@ -12,6 +12,15 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 histogram_metric_f64!(REQUEST_TIME, GLOBAL_METER);
 
+// Rate limiting metrics
+counter_metric!(RATE_LIMIT_ALLOWED_REQUESTS, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_REJECTED_REQUESTS, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_FAILURES, GLOBAL_METER);
+
+// Card testing guard velocity rule metrics
+counter_metric!(VELOCITY_RULE_TRIGGERED, GLOBAL_METER);
+counter_metric!(VELOCITY_RULE_EVALUATION_FAILURES, GLOBAL_METER);
+
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
 
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use crate::{
     core::{api_locking, payment_link::*},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
     types::domain,
     AppState,
 };
@@ -20,7 +20,11 @@ pub async fn payment_link_retrieve(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkRetrieve;
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::ProfilePaymentRead),
+    };
 
     let (auth_type, _) =
         match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth) {
@@ -137,6 +141,7 @@ pub async fn payments_link_list(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePaymentRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
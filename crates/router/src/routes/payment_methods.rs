@@ -59,6 +59,7 @@ pub async fn create_payment_method_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -424,7 +425,11 @@ pub async fn save_payment_method_api(
     let flow = Flow::PaymentMethodSave;
     let payload = json_payload.into_inner();
     let pm_id = path.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerWrite),
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -463,7 +468,11 @@ pub async fn list_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsList;
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::ProfilePaymentRead),
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -503,7 +512,11 @@ pub async fn list_customer_payment_method_api(
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
     let customer_id = customer_id.into_inner().0;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerRead),
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -545,7 +558,11 @@ pub async fn list_customer_payment_method_api_client(
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
     let api_key = auth::get_api_key(req.headers()).ok();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerRead),
+    };
 
     let (auth, _, is_ephemeral_auth) =
         match auth::get_ephemeral_or_other_auth(req.headers(), false, Some(&payload), api_auth)
@@ -600,6 +617,7 @@ pub async fn initiate_pm_collect_link_flow(
         &auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerWrite),
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -790,6 +808,7 @@ pub async fn payment_method_retrieve_api(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::MerchantCustomerRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -807,7 +826,11 @@ pub async fn payment_method_update_api(
     let flow = Flow::PaymentMethodsUpdate;
     let payment_method_id = path.into_inner();
     let payload = json_payload.into_inner();
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerWrite),
+    };
 
     let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth)
     {
@@ -843,7 +866,11 @@ pub async fn payment_method_delete_api(
     let pm = PaymentMethodId {
         payment_method_id: payment_method_id.into_inner().0,
     };
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerWrite),
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -898,6 +925,7 @@ pub async fn list_countries_currencies_for_connector_payment_method(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileConnectorWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileConnectorWrite,
@@ -924,7 +952,11 @@ pub async fn default_payment_method_set_api(
     let payload = path.into_inner();
     let pc = payload.clone();
     let customer_id = &pc.customer_id;
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::MerchantCustomerWrite),
+    };
 
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers(), api_auth) {
         Ok(auth) => auth,
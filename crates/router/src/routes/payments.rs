@@ -14,6 +14,7 @@ use crate::{
     self as app,
     core::{
         errors::{self, http_not_implemented},
+        idempotency,
         payments::{self, PaymentRedirectFlow},
     },
     routes::lock_utils,
@@ -48,6 +49,12 @@ pub async fn payments_create(
         return http_not_implemented();
     };
 
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     if let Err(err) = get_or_generate_payment_id(&mut payload) {
         return api::log_and_return_error_response(err);
     }
@@ -80,29 +87,37 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let key_store = auth.key_store.clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                api::AuthFlow::Client,
+            idempotency::perform_idempotent_action(
+                state.clone(),
+                key_store,
+                idempotency_input.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentCreate,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    api::AuthFlow::Client,
+                ),
             )
         },
         match env::which() {
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfilePaymentWrite),
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Some(Permission::ProfilePaymentWrite),
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -529,6 +544,7 @@ pub async fn payments_retrieve(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Some(Permission::ProfilePaymentRead),
     };
 
     let (auth_type, auth_flow) =
@@ -590,6 +606,7 @@ pub async fn payments_retrieve_with_gateway_creds(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Some(Permission::ProfilePaymentRead),
     };
 
     let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
@@ -680,6 +697,7 @@ pub async fn payments_update(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Some(Permission::ProfilePaymentWrite),
     };
     let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers(), api_auth) {
         Ok(auth) => auth,
@@ -831,6 +849,7 @@ pub async fn payments_update_metadata(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Some(Permission::ProfilePaymentWrite),
         }),
         locking_action,
     ))
@@ -865,11 +884,20 @@ pub async fn payments_confirm(
         }
     };
 
+    let payment_id = path.into_inner();
+    let idempotency_input = match idempotency::IdempotencyInput::from_request(
+        req.headers(),
+        flow.clone(),
+        &(&payment_id, &payload),
+    ) {
+        Ok(idempotency_input) => idempotency_input,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
     if let Err(err) = helpers::populate_browser_info(&req, &mut payload, &header_payload) {
         return api::log_and_return_error_response(err);
     }
 
-    let payment_id = path.into_inner();
     tracing::Span::current().record("payment_id", payment_id.get_string_repr());
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
@@ -877,6 +905,7 @@ pub async fn payments_confirm(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Some(Permission::ProfilePaymentWrite),
     };
 
     let (auth_type, auth_flow) =
@@ -893,18 +922,24 @@ pub async fn payments_confirm(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
+            let key_store = auth.key_store.clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            authorize_verify_select::<_>(
-                payments::PaymentConfirm,
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                header_payload.clone(),
-                req,
-                auth_flow,
+            idempotency::perform_idempotent_action(
+                state.clone(),
+                key_store,
+                idempotency_input.clone(),
+                authorize_verify_select::<_>(
+                    payments::PaymentConfirm,
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    header_payload.clone(),
+                    req,
+                    auth_flow,
+                ),
             )
         },
         &*auth_type,
@@ -930,6 +965,12 @@ pub async fn payments_capture(
         ..json_payload.into_inner()
     };
 
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     let locking_action = payload.get_locking_input(flow.clone());
 
     Box::pin(api::server_wrap(
@@ -938,32 +979,39 @@ pub async fn payments_capture(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, payload, req_state| {
+            let key_store = auth.key_store.clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Capture>,
-            >(
-                state,
-                req_state,
-                merchant_context,
-                auth.profile_id,
-                payments::PaymentCapture,
-                payload,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+            idempotency::perform_idempotent_action(
+                state.clone(),
+                key_store,
+                idempotency_input.clone(),
+                payments::payments_core::<
+                    api_types::Capture,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    payments::PaymentData<api_types::Capture>,
+                >(
+                    state,
+                    req_state,
+                    merchant_context,
+                    auth.profile_id,
+                    payments::PaymentCapture,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    HeaderPayload::default(),
+                ),
             )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Some(Permission::ProfilePaymentWrite),
         }),
         locking_action,
     ))
@@ -1392,6 +1440,7 @@ pub async fn payments_complete_authorize(
     let api_auth = auth::ApiKeyAuth {
         is_connected_allowed: false,
         is_platform_allowed: true,
+        permission: Some(Permission::ProfilePaymentWrite),
     };
 
     let (auth_type, auth_flow) =
@@ -1485,6 +1534,7 @@ pub async fn payments_cancel(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Some(Permission::ProfilePaymentWrite),
         }),
         locking_action,
     ))
@@ -1515,6 +1565,7 @@ pub async fn payments_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::MerchantPaymentRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPaymentRead,
@@ -1590,6 +1641,7 @@ pub async fn profile_payments_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfilePaymentRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePaymentRead,
@@ -1858,11 +1910,13 @@ pub async fn payments_approve(
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfilePaymentWrite),
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Some(Permission::ProfilePaymentWrite),
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -1930,11 +1984,13 @@ pub async fn payments_reject(
             env::Env::Production => &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfilePaymentWrite),
             }),
             _ => auth::auth_type(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: true,
+                    permission: Some(Permission::ProfilePaymentWrite),
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfilePaymentWrite,
@@ -2110,6 +2166,7 @@ pub async fn payments_incremental_authorization(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Some(Permission::ProfilePaymentWrite),
         }),
         locking_action,
     ))
@@ -2261,6 +2318,7 @@ pub async fn retrieve_extended_card_info(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: true,
+            permission: Some(Permission::ProfilePaymentRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
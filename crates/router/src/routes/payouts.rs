@@ -7,7 +7,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, payouts::*},
+    core::{api_locking, idempotency, payouts::*},
     services::{
         api,
         authentication::{self as auth},
@@ -24,21 +24,34 @@ pub async fn payouts_create(
     json_payload: web::Json<payout_types::PayoutCreateRequest>,
 ) -> HttpResponse {
     let flow = Flow::PayoutsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
 
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
+            let key_store = auth.key_store.clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            payouts_create_core(state, merchant_context, req)
+            idempotency::perform_idempotent_action(
+                state.clone(),
+                key_store,
+                idempotency_input.clone(),
+                payouts_create_core(state, merchant_context, req),
+            )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePayoutWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -76,6 +89,7 @@ pub async fn payouts_retrieve(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfilePayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -112,6 +126,7 @@ pub async fn payouts_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePayoutWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -131,7 +146,11 @@ pub async fn payouts_confirm(
     tracing::Span::current().record("payout_id", payout_id.get_string_repr());
     payload.payout_id = Some(payout_id);
     payload.confirm = Some(true);
-    let api_auth = auth::ApiKeyAuth::default();
+    let api_auth = auth::ApiKeyAuth {
+        is_connected_allowed: false,
+        is_platform_allowed: false,
+        permission: Some(Permission::ProfilePayoutWrite),
+    };
 
     let (auth_type, _auth_flow) =
         match auth::check_client_secret_and_get_auth(req.headers(), &payload, api_auth) {
@@ -182,6 +201,7 @@ pub async fn payouts_cancel(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePayoutWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -213,6 +233,7 @@ pub async fn payouts_fulfill(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfilePayoutWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -245,6 +266,7 @@ pub async fn payouts_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantPayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -287,6 +309,7 @@ pub async fn payouts_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfilePayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -324,6 +347,7 @@ pub async fn payouts_list_by_filter(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantPayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -366,6 +390,7 @@ pub async fn payouts_list_by_filter_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfilePayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
@@ -403,6 +428,7 @@ pub async fn payouts_list_available_filters_for_merchant(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantPayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantPayoutRead,
@@ -445,6 +471,7 @@ pub async fn payouts_list_available_filters_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfilePayoutRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfilePayoutRead,
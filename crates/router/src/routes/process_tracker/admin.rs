@@ -0,0 +1,180 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker::admin as process_tracker_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, process_tracker},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn list_processes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api::ProcessTrackerListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerList;
+    let constraints = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        constraints,
+        |state, _, constraints, _| process_tracker::list_processes(state, constraints),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn retrieve_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRetrieve;
+    let payload = process_tracker_api::ProcessTrackerId {
+        id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::retrieve_process(state, payload.id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetry))]
+pub async fn retry_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRetry;
+    let payload = process_tracker_api::ProcessTrackerId {
+        id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::retry_process(state, payload.id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerReschedule))]
+pub async fn reschedule_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api::ProcessTrackerRescheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerReschedule;
+    let mut payload = json_payload.into_inner();
+    payload.id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::reschedule_process(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerCancel))]
+pub async fn cancel_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerCancel;
+    let payload = process_tracker_api::ProcessTrackerId {
+        id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::cancel_process(state, payload.id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerPausedRunnersList))]
+pub async fn list_paused_runners(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::ProcessTrackerPausedRunnersList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| process_tracker::list_paused_runners(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRunnerPause))]
+pub async fn pause_runner(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<process_tracker_api::ProcessTrackerRunnerRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRunnerPause;
+    let payload = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::pause_runner(state, payload.runner),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRunnerResume))]
+pub async fn resume_runner(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<process_tracker_api::ProcessTrackerRunnerRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRunnerResume;
+    let payload = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::resume_runner(state, payload.runner),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
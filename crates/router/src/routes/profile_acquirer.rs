@@ -38,6 +38,7 @@ pub async fn create_profile_acquirer(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfileAccountWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileAccountWrite,
@@ -85,6 +86,7 @@ pub async fn profile_acquirer_update(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: true,
+                permission: Some(Permission::ProfileAccountWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileAccountWrite,
@@ -404,6 +404,7 @@ pub async fn toggle_connector_agnostic_mit(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(permissions::Permission::MerchantRoutingWrite),
             }),
             &auth::JWTAuth {
                 permission: permissions::Permission::MerchantRoutingWrite,
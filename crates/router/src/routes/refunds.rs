@@ -8,7 +8,7 @@ use crate::core::refunds::*;
 #[cfg(feature = "v2")]
 use crate::core::refunds_v2::*;
 use crate::{
-    core::api_locking,
+    core::{api_locking, idempotency},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::{api::refunds, domain},
 };
@@ -56,21 +56,35 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let idempotency_input =
+        match idempotency::IdempotencyInput::from_request(req.headers(), flow.clone(), &payload) {
+            Ok(idempotency_input) => idempotency_input,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
+            let key_store = auth.key_store.clone();
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            refund_create_core(state, merchant_context, auth.profile_id, req)
+            idempotency::perform_idempotent_action(
+                state.clone(),
+                key_store,
+                idempotency_input.clone(),
+                refund_create_core(state, merchant_context, auth.profile_id, req),
+            )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRefundWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundWrite,
@@ -185,6 +199,7 @@ pub async fn refunds_retrieve(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -343,6 +358,7 @@ pub async fn refunds_retrieve_with_body(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -378,6 +394,7 @@ pub async fn refunds_update(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRefundWrite),
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -449,6 +466,7 @@ pub async fn refunds_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -522,6 +540,7 @@ pub async fn refunds_list_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -559,6 +578,7 @@ pub async fn refunds_filter_list(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -592,6 +612,7 @@ pub async fn get_refunds_filters(state: web::Data<AppState>, req: HttpRequest) -
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -632,6 +653,7 @@ pub async fn get_refunds_filters_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
@@ -667,6 +689,7 @@ pub async fn get_refunds_aggregates(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRefundRead,
@@ -730,6 +753,7 @@ pub async fn get_refunds_aggregate_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRefundRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRefundRead,
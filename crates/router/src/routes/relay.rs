@@ -38,6 +38,7 @@ pub async fn relay(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: None,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -79,6 +80,7 @@ pub async fn relay_retrieve(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: None,
         }),
         api_locking::LockAction::NotApplicable,
     ))
@@ -63,6 +63,7 @@ pub async fn routing_create_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
@@ -120,6 +121,41 @@ pub async fn routing_create_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_simulate_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingSimulationRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingSimulateConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::simulate_routing_algorithm(state, merchant_context, auth.profile_id, payload)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingRead),
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 #[instrument(skip_all)]
 pub async fn routing_link_config(
@@ -139,7 +175,7 @@ pub async fn routing_link_config(
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
             ));
-            routing::link_routing_config(
+            routing::link_or_schedule_routing_config(
                 state,
                 merchant_context,
                 auth.profile_id,
@@ -147,12 +183,14 @@ pub async fn routing_link_config(
                 transaction_type
                     .or(json_payload.transaction_type)
                     .unwrap_or(enums::TransactionType::Payment),
+                json_payload.clone(),
             )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
@@ -247,6 +285,7 @@ pub async fn routing_retrieve_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -335,6 +374,7 @@ pub async fn list_routing_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -378,6 +418,7 @@ pub async fn list_routing_configs_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -469,6 +510,7 @@ pub async fn routing_unlink_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
@@ -556,6 +598,7 @@ pub async fn routing_update_default_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRoutingWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingWrite,
@@ -639,6 +682,7 @@ pub async fn routing_retrieve_default_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::ProfileRoutingRead,
@@ -678,6 +722,7 @@ pub async fn upsert_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantSurchargeDecisionManagerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
@@ -715,6 +760,7 @@ pub async fn delete_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantSurchargeDecisionManagerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
@@ -753,6 +799,7 @@ pub async fn retrieve_surcharge_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantSurchargeDecisionManagerRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerRead,
@@ -792,6 +839,7 @@ pub async fn upsert_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantThreeDsDecisionManagerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
@@ -871,6 +919,7 @@ pub async fn delete_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantThreeDsDecisionManagerWrite),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
@@ -946,6 +995,7 @@ pub async fn retrieve_decision_manager_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantThreeDsDecisionManagerRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerRead,
@@ -996,6 +1046,7 @@ pub async fn routing_retrieve_linked_config(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Some(Permission::ProfileRoutingRead),
                 }),
                 &auth::JWTAuthProfileFromRoute {
                     profile_id,
@@ -1030,6 +1081,7 @@ pub async fn routing_retrieve_linked_config(
                 &auth::HeaderAuth(auth::ApiKeyAuth {
                     is_connected_allowed: false,
                     is_platform_allowed: false,
+                    permission: Some(Permission::ProfileRoutingRead),
                 }),
                 &auth::JWTAuth {
                     permission: Permission::ProfileRoutingRead,
@@ -1123,6 +1175,7 @@ pub async fn routing_retrieve_default_config_for_profiles(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -1134,6 +1187,7 @@ pub async fn routing_retrieve_default_config_for_profiles(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::MerchantRoutingRead),
             }),
             &auth::JWTAuth {
                 permission: Permission::MerchantRoutingRead,
@@ -1179,6 +1233,7 @@ pub async fn routing_update_default_config_for_profile(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1228,6 +1283,7 @@ pub async fn toggle_success_based_routing(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -1272,6 +1328,7 @@ pub async fn success_based_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1316,6 +1373,7 @@ pub async fn elimination_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1368,6 +1426,7 @@ pub async fn contract_based_routing_setup_config(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1419,6 +1478,7 @@ pub async fn contract_based_routing_update_configs(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
@@ -1468,6 +1528,7 @@ pub async fn toggle_elimination_routing(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
@@ -1521,6 +1582,7 @@ pub async fn set_dynamic_routing_volume_split(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingWrite),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: payload.profile_id,
@@ -1562,6 +1624,7 @@ pub async fn get_dynamic_routing_volume_split(
             &auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+                permission: Some(Permission::ProfileRoutingRead),
             }),
             &auth::JWTAuthProfileFromRoute {
                 profile_id: payload.profile_id,
@@ -1609,6 +1672,7 @@ pub async fn evaluate_routing_rule(
         &auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRoutingRead),
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -1690,6 +1754,7 @@ pub async fn call_decide_gateway_open_router(
         &auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRoutingRead),
         },
         api_locking::LockAction::NotApplicable,
     ))
@@ -1715,6 +1780,7 @@ pub async fn call_update_gateway_score_open_router(
         &auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileRoutingWrite),
         },
         api_locking::LockAction::NotApplicable,
     ))
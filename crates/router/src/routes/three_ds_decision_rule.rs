@@ -5,7 +5,7 @@ use router_env::{instrument, tracing, Flow};
 use crate::{
     self as app,
     core::{api_locking, three_ds_decision_rule as three_ds_decision_rule_core},
-    services::{api, authentication as auth},
+    services::{api, authentication as auth, authorization::permissions::Permission},
 };
 
 #[instrument(skip_all, fields(flow = ?Flow::ThreeDsDecisionRuleExecute))]
@@ -36,6 +36,7 @@ pub async fn execute_decision_rule(
         &auth::HeaderAuth(auth::ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: Some(Permission::ProfileThreeDsDecisionManagerRead),
         }),
         api_locking::LockAction::NotApplicable,
     ))
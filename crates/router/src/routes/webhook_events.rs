@@ -10,7 +10,9 @@ use crate::{
         authorization::permissions::Permission,
     },
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+        DeadLetterEventListConstraints, DeadLetterEventListRequestInternal,
+        DeadLetterEventReplayRequest, DeadLetterEventReplayRequestInternal, EventListConstraints,
+        EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
         WebhookDeliveryRetryRequestInternal,
     },
 };
@@ -174,3 +176,84 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeadLetterList))]
+pub async fn list_dead_letter_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<DeadLetterEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeadLetterList;
+    let merchant_id = path.into_inner();
+
+    let request_internal = DeadLetterEventListRequestInternal {
+        merchant_id: merchant_id.clone(),
+        constraints: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::list_dead_letter_events(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeadLetterReplay))]
+#[cfg(feature = "v1")]
+pub async fn replay_dead_letter_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<DeadLetterEventReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeadLetterReplay;
+    let merchant_id = path.into_inner();
+
+    let request_internal = DeadLetterEventReplayRequestInternal {
+        merchant_id: merchant_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::replay_dead_letter_events(
+                state,
+                request_internal.merchant_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
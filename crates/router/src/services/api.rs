@@ -61,7 +61,7 @@ use crate::{
     core::{
         api_locking,
         errors::{self, CustomResult},
-        payments,
+        payments, rate_limit,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -70,7 +70,7 @@ use crate::{
     headers, logger,
     routes::{
         app::{AppStateInfo, ReqState, SessionStateInfo},
-        metrics, AppState, SessionState,
+        lock_utils, metrics, AppState, SessionState,
     },
     services::{
         connector_integration_interface::RouterDataConversion,
@@ -539,7 +539,7 @@ pub enum AuthFlow {
     fields(merchant_id)
 )]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
-    flow: &'a impl router_env::types::FlowMetric,
+    flow: &'a (impl router_env::types::FlowMetric + Into<lock_utils::ApiIdentifier>),
     state: web::Data<AppState>,
     incoming_request_header: &HeaderMap,
     request: &'a HttpRequest,
@@ -641,6 +641,10 @@ where
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
+    rate_limit::check_rate_limit(&session_state, flow.clone().into(), &auth_type)
+        .await
+        .switch()?;
+
     let output = {
         lock_action
             .clone()
@@ -731,7 +735,7 @@ where
     fields(request_method, request_url_path, status_code)
 )]
 pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
-    flow: impl router_env::types::FlowMetric,
+    flow: impl router_env::types::FlowMetric + Into<lock_utils::ApiIdentifier>,
     state: web::Data<AppState>,
     request: &'a HttpRequest,
     payload: T,
@@ -9,7 +9,8 @@ use api_models::payment_methods::PaymentMethodIntentConfirm;
 use api_models::payouts;
 use api_models::{payment_methods::PaymentMethodListRequest, payments};
 use async_trait::async_trait;
-use common_enums::TokenPurpose;
+use common_enums::{EntityType, TokenPurpose};
+use common_types::api_keys::ApiKeyRestrictions;
 use common_utils::{date_time, fp_utils, id_type};
 #[cfg(feature = "v2")]
 use diesel_models::ephemeral_key;
@@ -355,6 +356,68 @@ where
 pub struct ApiKeyAuth {
     pub is_connected_allowed: bool,
     pub is_platform_allowed: bool,
+    /// Permission required to access the endpoint using a restricted API key. Endpoints without
+    /// one cannot be accessed using restricted API keys.
+    pub permission: Option<Permission>,
+}
+
+/// Client IP address, taken from the last entry of the `X-Forwarded-For` header. The last entry
+/// is the one appended by the load balancer in front of the application, while the entries
+/// before it are sent by the client and can be spoofed.
+fn get_client_ip_address(request_headers: &HeaderMap) -> Option<std::net::IpAddr> {
+    request_headers
+        .get_all(headers::X_FORWARDED_FOR)
+        .last()
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(|header_value| header_value.rsplit(',').next())
+        .and_then(|ip_address| ip_address.trim().parse().ok())
+}
+
+/// Checks the permission required by the endpoint, the profile being accessed and the IP address
+/// of the request against the restrictions of a restricted API key
+fn check_api_key_restrictions(
+    restrictions: &ApiKeyRestrictions,
+    permission: Option<Permission>,
+    profile_id: Option<&id_type::ProfileId>,
+    request_headers: &HeaderMap,
+) -> RouterResult<()> {
+    let permission = permission
+        .ok_or(errors::ApiErrorResponse::AccessForbidden {
+            resource: "endpoint".to_string(),
+        })
+        .attach_printable("Endpoint cannot be accessed using restricted API keys")?;
+
+    if !restrictions.is_permitted(permission.resource(), permission.scope()) {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: permission.to_string(),
+        }))
+        .attach_printable("Restricted API key is not granted the required permission");
+    }
+
+    // Merchant level endpoints are not scoped to a profile, so they would expose the resources
+    // of all the profiles of the merchant
+    if restrictions.profile_ids.is_some() && permission.entity_type() != EntityType::Profile {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: permission.to_string(),
+        }))
+        .attach_printable("API key restricted to profiles cannot access merchant level endpoints");
+    }
+
+    if !restrictions.is_profile_allowed(profile_id) {
+        return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id
+                .map(|profile_id| profile_id.get_string_repr().to_owned())
+                .unwrap_or_else(|| "merchant".to_string()),
+        }))
+        .attach_printable("Restricted API key is not allowed to access the profile");
+    }
+
+    if !restrictions.is_ip_allowed(get_client_ip_address(request_headers)) {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Restricted API key used from an IP address that is not allowed");
+    }
+
+    Ok(())
 }
 
 pub struct NoAuth;
@@ -457,6 +520,15 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(
+                restrictions,
+                self.permission,
+                Some(&profile_id),
+                request_headers,
+            )?;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -597,6 +669,15 @@ where
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(
+                restrictions,
+                self.permission,
+                profile_id.as_ref(),
+                request_headers,
+            )?;
+        }
+
         let merchant = state
             .store()
             .find_merchant_account_by_merchant_id(
@@ -675,6 +756,7 @@ where
         let api_auth = ApiKeyAuth {
             is_connected_allowed: false,
             is_platform_allowed: false,
+            permission: None,
         };
         let (auth_data, auth_type) = api_auth
             .authenticate_and_fetch(request_headers, state)
@@ -759,6 +841,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -848,6 +934,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -998,6 +1088,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         let (_, platform_merchant) =
             Self::fetch_key_store_and_account(&stored_api_key.merchant_id, state).await?;
 
@@ -1720,6 +1814,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -1865,6 +1963,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         if fallback_merchant_ids
             .merchant_ids
             .contains(&stored_api_key.merchant_id)
@@ -2491,6 +2593,10 @@ where
                 .attach_printable("API key has expired");
         }
 
+        if let Some(restrictions) = stored_api_key.restrictions.as_ref() {
+            check_api_key_restrictions(restrictions, None, None, request_headers)?;
+        }
+
         let key_manager_state = &(&state.session_state()).into();
 
         let key_store = state
@@ -81,6 +81,15 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    DisputeDeadlineReminder {
+        dispute_id: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        connector: String,
+        deadline: String,
+        hours_remaining: i64,
+    },
 }
 
 pub mod html {
@@ -242,6 +251,24 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::DisputeDeadlineReminder {
+                dispute_id,
+                payment_id,
+                amount,
+                currency,
+                connector,
+                deadline,
+                hours_remaining,
+            } => format!(
+                include_str!("assets/dispute_deadline_reminder.html"),
+                dispute_id = dispute_id,
+                payment_id = payment_id,
+                amount = amount,
+                currency = currency,
+                connector = connector,
+                deadline = deadline,
+                hours_remaining = hours_remaining,
+            ),
         }
     }
 }
@@ -662,6 +689,41 @@ impl EmailData for ApiKeyExpiryReminder {
     }
 }
 
+pub struct DisputeDeadlineReminder {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub connector: String,
+    pub deadline: String,
+    pub hours_remaining: i64,
+}
+
+#[async_trait::async_trait]
+impl EmailData for DisputeDeadlineReminder {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body(EmailBody::DisputeDeadlineReminder {
+            dispute_id: self.dispute_id.clone(),
+            payment_id: self.payment_id.clone(),
+            amount: self.amount.clone(),
+            currency: self.currency.clone(),
+            connector: self.connector.clone(),
+            deadline: self.deadline.clone(),
+            hours_remaining: self.hours_remaining,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
 pub struct WelcomeToCommunity {
     pub recipient_email: domain::UserEmail,
 }
@@ -1,4 +1,5 @@
 pub use api_models::api_keys::{
-    ApiKeyExpiration, CreateApiKeyRequest, CreateApiKeyResponse, ListApiKeyConstraints,
-    RetrieveApiKeyResponse, RevokeApiKeyResponse, UpdateApiKeyRequest,
+    ApiKeyExpiration, ApiKeyPermission, ApiKeyRestrictions, CreateApiKeyRequest,
+    CreateApiKeyResponse, ListApiKeyConstraints, RetrieveApiKeyResponse, RevokeApiKeyResponse,
+    UpdateApiKeyRequest,
 };
@@ -1,6 +1,10 @@
 pub use api_models::webhook_events::{
-    EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
-    OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
+    DeadLetterEventListConstraints, DeadLetterEventListRequestInternal,
+    DeadLetterEventReplayRequest, DeadLetterEventReplayRequestInternal,
+    DeadLetterEventReplayResponse, DeadLetterEventReplayResult, DeadLetterEventReplayStatus,
+    DeadLetterEventResponse, EventListConstraints, EventListConstraintsInternal,
+    EventListItemResponse, EventListRequestInternal, EventRetrieveResponse,
+    OutgoingWebhookRequestContent, OutgoingWebhookResponseContent, TotalDeadLetterEventsResponse,
+    TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
     WebhookDeliveryRetryRequestInternal,
 };
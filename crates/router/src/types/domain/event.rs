@@ -71,6 +71,18 @@ pub struct Event {
 
     /// Indicates whether the event was ultimately delivered.
     pub is_overall_delivery_successful: Option<bool>,
+
+    /// Identifier of the additional webhook endpoint of the business profile the event is
+    /// delivered to, not set for events delivered to the webhook URL of the business profile.
+    pub webhook_endpoint_id: Option<String>,
+
+    /// The timestamp when the automatic retries of the event were exhausted without a successful
+    /// delivery, moving the event to the dead-letter queue.
+    pub dead_lettered_at: Option<time::PrimitiveDateTime>,
+
+    /// The HTTP status code received for the last delivery attempt before the event was moved to
+    /// the dead-letter queue.
+    pub dead_letter_status_code: Option<i32>,
 }
 
 #[derive(Debug)]
@@ -82,6 +94,10 @@ pub enum EventUpdate {
     OverallDeliveryStatusUpdate {
         is_overall_delivery_successful: bool,
     },
+    DeadLetterUpdate {
+        dead_lettered_at: time::PrimitiveDateTime,
+        dead_letter_status_code: Option<i32>,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -94,6 +110,8 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
                 is_overall_delivery_successful: None,
+                dead_lettered_at: None,
+                dead_letter_status_code: None,
             },
             EventUpdate::OverallDeliveryStatusUpdate {
                 is_overall_delivery_successful,
@@ -101,6 +119,18 @@ impl From<EventUpdate> for EventUpdateInternal {
                 is_webhook_notified: None,
                 response: None,
                 is_overall_delivery_successful: Some(is_overall_delivery_successful),
+                dead_lettered_at: None,
+                dead_letter_status_code: None,
+            },
+            EventUpdate::DeadLetterUpdate {
+                dead_lettered_at,
+                dead_letter_status_code,
+            } => Self {
+                is_webhook_notified: None,
+                response: None,
+                is_overall_delivery_successful: None,
+                dead_lettered_at: Some(dead_lettered_at),
+                dead_letter_status_code,
             },
         }
     }
@@ -130,6 +160,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            dead_lettered_at: self.dead_lettered_at,
+            dead_letter_status_code: self.dead_letter_status_code,
         })
     }
 
@@ -180,6 +213,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
             is_overall_delivery_successful: item.is_overall_delivery_successful,
+            webhook_endpoint_id: item.webhook_endpoint_id,
+            dead_lettered_at: item.dead_lettered_at,
+            dead_letter_status_code: item.dead_letter_status_code,
         })
     }
 
@@ -202,6 +238,9 @@ impl super::behaviour::Conversion for Event {
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
             is_overall_delivery_successful: self.is_overall_delivery_successful,
+            webhook_endpoint_id: self.webhook_endpoint_id,
+            dead_lettered_at: self.dead_lettered_at,
+            dead_letter_status_code: self.dead_letter_status_code,
         })
     }
 }
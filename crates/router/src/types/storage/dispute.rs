@@ -143,3 +143,16 @@ impl DisputeDbExt for Dispute {
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
 }
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DisputeAutoResponseTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub dispute_id: String,
+    pub action: api_models::disputes::DisputeAutoResponseAction,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DisputeDeadlineReminderTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub dispute_id: String,
+}
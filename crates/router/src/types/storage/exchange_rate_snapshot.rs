@@ -0,0 +1,3 @@
+pub use diesel_models::exchange_rate_snapshot::{
+    ExchangeRateSnapshot, ExchangeRateSnapshotNew, ExchangeRateSnapshotUpdate,
+};
@@ -0,0 +1 @@
+pub use diesel_models::idempotency_key::{IdempotencyKey, IdempotencyKeyNew};
@@ -227,6 +227,8 @@ mod tests {
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
             connector_request_reference_id: Default::default(),
+            retry_details: Default::default(),
+            triggered_velocity_rules: Default::default(),
         };
 
         let store = state
@@ -319,6 +321,8 @@ mod tests {
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
             connector_request_reference_id: Default::default(),
+            retry_details: Default::default(),
+            triggered_velocity_rules: Default::default(),
         };
         let store = state
             .stores
@@ -424,6 +428,8 @@ mod tests {
             setup_future_usage_applied: Default::default(),
             routing_approach: Default::default(),
             connector_request_reference_id: Default::default(),
+            retry_details: Default::default(),
+            triggered_velocity_rules: Default::default(),
         };
         let store = state
             .stores
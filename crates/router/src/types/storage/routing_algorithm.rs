@@ -1,3 +1,26 @@
 pub use diesel_models::routing_algorithm::{
     RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingActivationStage {
+    /// The routing algorithm is yet to be activated
+    Activate,
+    /// The routing algorithm has been activated and is being monitored for a rollback
+    Guard,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub transaction_type: common_enums::TransactionType,
+    pub stage: RoutingActivationStage,
+    pub rollback_config: Option<api_models::routing::RoutingRollbackConfig>,
+    /// The algorithm that was active before `algorithm_id` got activated
+    pub previous_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub activated_at: Option<time::PrimitiveDateTime>,
+}
@@ -614,6 +614,7 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -628,6 +629,7 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -641,6 +643,7 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            restrictions: api_key.restrictions,
         }
     }
 }
@@ -1621,6 +1624,8 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
             unified_message: value.unified_message,
             error_category: value.error_category,
             clear_pan_possible: value.clear_pan_possible,
+            hit_count: value.hit_count,
+            last_hit_at: value.last_hit_at,
         }
     }
 }
@@ -1723,6 +1728,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_class: item.event_class,
             is_delivery_successful: item.is_overall_delivery_successful,
             initial_attempt_id,
+            webhook_endpoint_id: item.webhook_endpoint_id,
             created: item.created_at,
         })
     }
@@ -1767,6 +1773,33 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
     }
 }
 
+#[cfg(feature = "olap")]
+impl TryFrom<domain::Event> for api_models::webhook_events::DeadLetterEventResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::Event) -> Result<Self, Self::Error> {
+        use crate::utils::OptionExt;
+
+        let dead_lettered_at = item
+            .dead_lettered_at
+            .get_required_value("dead_lettered_at")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let status_code = item
+            .dead_letter_status_code
+            .map(u16::try_from)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid HTTP status code stored for dead-lettered event")?;
+        let event_information = api_models::webhook_events::EventListItemResponse::try_from(item)?;
+
+        Ok(Self {
+            event_information,
+            dead_lettered_at,
+            status_code,
+        })
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -1834,6 +1867,11 @@ impl ForeignFrom<api_models::admin::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item
+                .velocity_rules
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
         }
     }
 }
@@ -1859,6 +1897,41 @@ impl ForeignFrom<diesel_models::business_profile::CardTestingGuardConfig>
             },
             customer_id_blocking_threshold: item.customer_id_blocking_threshold,
             card_testing_guard_expiry: item.card_testing_guard_expiry,
+            velocity_rules: item
+                .velocity_rules
+                .into_iter()
+                .map(ForeignFrom::foreign_from)
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::VelocityRule>
+    for diesel_models::business_profile::VelocityRule
+{
+    fn foreign_from(item: api_models::admin::VelocityRule) -> Self {
+        Self {
+            name: item.name,
+            keys: item.keys,
+            aggregation: item.aggregation,
+            threshold: item.threshold,
+            window_in_secs: item.window_in_secs,
+            action: item.action,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::VelocityRule>
+    for api_models::admin::VelocityRule
+{
+    fn foreign_from(item: diesel_models::business_profile::VelocityRule) -> Self {
+        Self {
+            name: item.name,
+            keys: item.keys,
+            aggregation: item.aggregation,
+            threshold: item.threshold,
+            window_in_secs: item.window_in_secs,
+            action: item.action,
         }
     }
 }
@@ -1878,6 +1951,15 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            webhook_endpoints: item.webhook_endpoints.map(|endpoints| {
+                endpoints
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
+            signing_secret_rotation_grace_period_in_secs: item
+                .signing_secret_rotation_grace_period_in_secs,
+            payment_response_hash_key_rotation: None,
         }
     }
 }
@@ -1897,6 +1979,43 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            webhook_endpoints: item.webhook_endpoints.map(|endpoints| {
+                endpoints
+                    .into_iter()
+                    .map(ForeignInto::foreign_into)
+                    .collect()
+            }),
+            signing_secret_rotation_grace_period_in_secs: item
+                .signing_secret_rotation_grace_period_in_secs,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::WebhookEndpoint>
+    for diesel_models::business_profile::WebhookEndpoint
+{
+    fn foreign_from(item: api_models::admin::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            url: item.url,
+            is_enabled: item.is_enabled,
+            enabled_events: item.enabled_events,
+            signing_secret: item.signing_secret,
+            signing_secret_rotation: None,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::WebhookEndpoint>
+    for api_models::admin::WebhookEndpoint
+{
+    fn foreign_from(item: diesel_models::business_profile::WebhookEndpoint) -> Self {
+        Self {
+            endpoint_id: item.endpoint_id,
+            url: item.url,
+            is_enabled: item.is_enabled,
+            enabled_events: item.enabled_events,
+            signing_secret: item.signing_secret,
         }
     }
 }
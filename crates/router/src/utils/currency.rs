@@ -1,34 +1,35 @@
+pub mod providers;
+
 use std::{
     collections::HashMap,
-    ops::Deref,
     str::FromStr,
     sync::{Arc, LazyLock},
 };
 
 use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
-use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use common_utils::{
+    date_time,
+    errors::CustomResult,
+    events::ApiEventMetric,
+    ext_traits::{AsyncExt, Encode, ValueExt},
+};
+use currency_conversion::types::{CurrencyFactors, ExchangeRates, HistoricalExchangeRates};
 use error_stack::ResultExt;
 use masking::PeekInterface;
 use redis_interface::DelReply;
 use router_env::{instrument, tracing};
 use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
 use tokio::sync::RwLock;
 use tracing_futures::Instrument;
 
 use crate::{
     logger,
-    routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, SessionState,
+    routes::app::settings::{Conversion, DefaultExchangeRates, ForexProviderConfig},
+    types::storage,
+    SessionState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
-const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
@@ -81,27 +82,8 @@ pub enum ForexError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
-
-impl Deref for FloatDecimal {
-    type Target = Decimal;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+    #[error("Error reading or writing exchange rate snapshots")]
+    SnapshotStorageError,
 }
 
 impl FxExchangeRatesCacheEntry {
@@ -206,8 +188,10 @@ async fn call_forex_api_and_save_data_to_cache_and_redis(
     stale_redis_data: Option<FxExchangeRatesCacheEntry>,
 ) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
     // spawn a new thread and do the api fetch and write operations on redis.
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-    if forex_api_key.is_empty() {
+    let forex_api = state.conf.forex_api.get_inner();
+    if matches!(forex_api.provider, ForexProviderConfig::Remote)
+        && forex_api.api_key.peek().is_empty()
+    {
         Err(ForexError::ConfigurationError("api_keys not provided".into()).into())
     } else {
         let state = state.clone();
@@ -226,6 +210,13 @@ async fn call_forex_api_and_save_data_to_cache_and_redis(
     }
 }
 
+/// Fetches the exchange rates from the forex providers and saves them, irrespective of whether the
+/// saved rates have expired
+#[instrument(skip_all)]
+pub async fn refresh_forex_rates(state: &SessionState) -> CustomResult<(), ForexError> {
+    acquire_redis_lock_and_call_forex_api(state).await
+}
+
 async fn acquire_redis_lock_and_call_forex_api(
     state: &SessionState,
 ) -> CustomResult<(), ForexError> {
@@ -234,25 +225,94 @@ async fn acquire_redis_lock_and_call_forex_api(
         Err(ForexError::CouldNotAcquireLock.into())
     } else {
         logger::debug!("forex_log: redis lock acquired");
-        let api_rates = fetch_forex_rates_from_primary_api(state).await;
-        match api_rates {
-            Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-            Err(error) => {
-                logger::error!(forex_error=?error,"primary_forex_error");
-                // API not able to fetch data call secondary service
-                let secondary_api_rates = fetch_forex_rates_from_fallback_api(state).await;
-                match secondary_api_rates {
-                    Ok(rates) => save_forex_data_to_cache_and_redis(state, rates).await,
-                    Err(error) => {
-                        release_redis_lock(state).await?;
-                        Err(error)
-                    }
+        let mut last_error = None;
+        // Try the configured providers in order until one of them returns the rates
+        for provider in providers::get_forex_providers(state.conf.forex_api.get_inner()) {
+            match provider.fetch_rates(state).await {
+                Ok(rates) => {
+                    save_exchange_rate_snapshot(state, provider.name(), &rates)
+                        .await
+                        .map_err(|error| {
+                            logger::error!(forex_error=?error, "Failed to save exchange rate snapshot");
+                        })
+                        .ok();
+                    return save_forex_data_to_cache_and_redis(
+                        state,
+                        FxExchangeRatesCacheEntry::new(rates),
+                    )
+                    .await;
+                }
+                Err(error) => {
+                    logger::error!(forex_error=?error, provider = provider.name(), "forex_provider_error");
+                    last_error = Some(error);
                 }
             }
         }
+        release_redis_lock(state).await?;
+        Err(last_error.unwrap_or_else(|| {
+            ForexError::ConfigurationError("no forex providers configured".into()).into()
+        }))
     }
 }
 
+/// Persists the rates as the snapshot of the current day, used for converting amounts with
+/// historical rates
+async fn save_exchange_rate_snapshot(
+    state: &SessionState,
+    provider: &str,
+    rates: &ExchangeRates,
+) -> CustomResult<(), ForexError> {
+    let now = date_time::now();
+    let snapshot = storage::ExchangeRateSnapshotNew {
+        rate_date: now.date(),
+        base_currency: rates.base_currency,
+        rates: rates
+            .conversion
+            .encode_to_value()
+            .change_context(ForexError::ParsingError)
+            .attach_printable("Unable to encode exchange rates")?,
+        provider: provider.to_string(),
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    state
+        .store
+        .upsert_exchange_rate_snapshot(snapshot)
+        .await
+        .change_context(ForexError::SnapshotStorageError)
+        .attach_printable("Unable to save exchange rate snapshot")?;
+
+    logger::debug!("forex_log: forex snapshot saved");
+    Ok(())
+}
+
+/// Fetches the exchange rates that were in effect at `as_of`, from the latest snapshot taken on
+/// or before that day
+pub async fn get_historical_forex_rates(
+    state: &SessionState,
+    as_of: time::PrimitiveDateTime,
+) -> CustomResult<HistoricalExchangeRates, ForexError> {
+    let snapshot = state
+        .store
+        .find_latest_exchange_rate_snapshot_on_or_before(as_of.date())
+        .await
+        .change_context(ForexError::SnapshotStorageError)
+        .attach_printable("Unable to fetch exchange rate snapshot")?
+        .ok_or(ForexError::ForexDataUnavailable)
+        .attach_printable_lazy(|| format!("No exchange rate snapshot found as of {as_of}"))?;
+
+    let conversion: HashMap<enums::Currency, CurrencyFactors> = snapshot
+        .rates
+        .parse_value("ExchangeRateSnapshotRates")
+        .change_context(ForexError::ParsingError)?;
+
+    Ok(HistoricalExchangeRates::new([(
+        snapshot.rate_date,
+        ExchangeRates::new(snapshot.base_currency, conversion),
+    )]))
+}
+
 async fn save_forex_data_to_cache_and_redis(
     state: &SessionState,
     forex: FxExchangeRatesCacheEntry,
@@ -285,156 +345,6 @@ async fn call_forex_api_if_redis_data_expired(
     }
 }
 
-async fn fetch_forex_rates_from_primary_api(
-    state: &SessionState,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexError>> {
-    let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
-
-    logger::debug!("forex_log: Primary api call for forex fetch");
-    let forex_url: String = format!("{FOREX_BASE_URL}{forex_api_key}{FOREX_BASE_CURRENCY}");
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Primary forex fetch api unresponsive")?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from primary api into ForexResponse",
-        )?;
-
-    logger::info!(primary_forex_response=?forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!(
-                    "forex_error: Rates for {} not received from API",
-                    &enum_curr
-                );
-            }
-        };
-    }
-
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
-}
-
-pub async fn fetch_forex_rates_from_fallback_api(
-    state: &SessionState,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexError> {
-    let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
-
-    let fallback_forex_url: String = format!("{FALLBACK_FOREX_BASE_URL}{fallback_forex_api_key}");
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexError::ApiUnresponsive)
-        .attach_printable("Fallback forex fetch api unresponsive")?;
-
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .change_context(ForexError::ParsingError)
-        .attach_printable(
-            "Unable to parse response received from fallback api into ForexResponse",
-        )?;
-
-    logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
-            )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!(
-                            "forex_error: Rates for {} not received from API",
-                            &enum_curr
-                        );
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                if enum_curr == enums::Currency::USD {
-                    let currency_factors =
-                        CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
-                    conversions.insert(enum_curr, currency_factors);
-                } else {
-                    logger::error!(
-                        "forex_error: Rates for {} not received from API",
-                        &enum_curr
-                    );
-                }
-            }
-        };
-    }
-
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => {
-            save_forex_data_to_cache_and_redis(state, rates.clone()).await?;
-            Ok(rates)
-        }
-        Err(e) => Err(e),
-    }
-}
-
 async fn release_redis_lock(
     state: &SessionState,
 ) -> Result<DelReply, error_stack::Report<ForexError>> {
@@ -521,12 +431,8 @@ pub async fn convert_currency(
     amount: i64,
     to_currency: String,
     from_currency: String,
+    as_of: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<api_models::currency::CurrencyConversionResponse, ForexError> {
-    let forex_api = state.conf.forex_api.get_inner();
-    let rates = get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
-        .await
-        .change_context(ForexError::ApiError)?;
-
     let to_currency = enums::Currency::from_str(to_currency.as_str())
         .change_context(ForexError::CurrencyNotAcceptable)
         .attach_printable("The provided currency is not acceptable")?;
@@ -535,13 +441,52 @@ pub async fn convert_currency(
         .change_context(ForexError::CurrencyNotAcceptable)
         .attach_printable("The provided currency is not acceptable")?;
 
-    let converted_amount =
-        currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
+    let converted_amount = match as_of {
+        Some(as_of) => {
+            convert_currency_as_of(&state, amount, from_currency, to_currency, as_of).await?
+        }
+        None => {
+            let forex_api = state.conf.forex_api.get_inner();
+            let rates = get_forex_rates(&state, forex_api.data_expiration_delay_in_seconds)
+                .await
+                .change_context(ForexError::ApiError)?;
+
+            currency_conversion::conversion::convert(
+                &rates.data,
+                from_currency,
+                to_currency,
+                amount,
+            )
             .change_context(ForexError::ConversionError)
-            .attach_printable("Unable to perform currency conversion")?;
+            .attach_printable("Unable to perform currency conversion")?
+        }
+    };
 
     Ok(api_models::currency::CurrencyConversionResponse {
         converted_amount: converted_amount.to_string(),
         currency: to_currency.to_string(),
     })
 }
+
+/// Converts the amount with the exchange rates that were in effect at `as_of`, so that refunds
+/// and reconciliation can use the rates from the date of the original payment
+#[instrument(skip_all)]
+pub async fn convert_currency_as_of(
+    state: &SessionState,
+    amount: i64,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+    as_of: time::PrimitiveDateTime,
+) -> CustomResult<Decimal, ForexError> {
+    let historical_rates = get_historical_forex_rates(state, as_of).await?;
+
+    currency_conversion::conversion::convert_as_of(
+        &historical_rates,
+        as_of,
+        from_currency,
+        to_currency,
+        amount,
+    )
+    .change_context(ForexError::ConversionError)
+    .attach_printable("Unable to perform currency conversion with historical rates")
+}
@@ -0,0 +1,237 @@
+use std::{collections::HashMap, ops::Deref};
+
+use api_models::enums;
+use common_utils::errors::CustomResult;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::ForexError;
+use crate::{
+    logger,
+    routes::app::settings::{DefaultExchangeRates, ForexApi, ForexProviderConfig},
+    services, SessionState,
+};
+
+const FOREX_API_TIMEOUT: u64 = 5;
+const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const FOREX_BASE_CURRENCY: &str = "&base=USD";
+const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
+
+/// A source of the latest exchange rates
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    /// Name of the provider, stored along with the rate snapshots fetched from it
+    fn name(&self) -> &'static str;
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError>;
+}
+
+/// Providers to be tried in order until one of them returns the exchange rates
+pub fn get_forex_providers(forex_api: &ForexApi) -> Vec<Box<dyn ForexProvider>> {
+    match &forex_api.provider {
+        ForexProviderConfig::Remote => {
+            vec![
+                Box::new(OpenExchangeRatesProvider),
+                Box::new(ApiLayerProvider),
+            ]
+        }
+        ForexProviderConfig::StaticFile { path } => {
+            vec![Box::new(StaticFileProvider { path: path.clone() })]
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ForexResponse {
+    pub rates: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct FallbackForexResponse {
+    pub quotes: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
+
+impl Deref for FloatDecimal {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Builds the USD based conversion factors from the rates quoted against USD
+fn usd_conversions(
+    get_rate: impl Fn(enums::Currency) -> Option<Decimal>,
+) -> HashMap<enums::Currency, CurrencyFactors> {
+    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+    for enum_curr in enums::Currency::iter() {
+        match get_rate(enum_curr) {
+            Some(rate) => {
+                let from_factor = match Decimal::new(1, 0).checked_div(rate) {
+                    Some(rate) => rate,
+                    None => {
+                        logger::error!(
+                            "forex_error: Rates for {} not received from API",
+                            &enum_curr
+                        );
+                        continue;
+                    }
+                };
+                let currency_factors = CurrencyFactors::new(rate, from_factor);
+                conversions.insert(enum_curr, currency_factors);
+            }
+            None => {
+                if enum_curr == enums::Currency::USD {
+                    let currency_factors =
+                        CurrencyFactors::new(Decimal::new(1, 0), Decimal::new(1, 0));
+                    conversions.insert(enum_curr, currency_factors);
+                } else {
+                    logger::error!(
+                        "forex_error: Rates for {} not received from API",
+                        &enum_curr
+                    );
+                }
+            }
+        };
+    }
+    conversions
+}
+
+/// openexchangerates.org, used as the primary provider
+pub struct OpenExchangeRatesProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for OpenExchangeRatesProvider {
+    fn name(&self) -> &'static str {
+        "open_exchange_rates"
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let forex_api_key = state.conf.forex_api.get_inner().api_key.peek();
+
+        logger::debug!("forex_log: Primary api call for forex fetch");
+        let forex_url: String = format!("{FOREX_BASE_URL}{forex_api_key}{FOREX_BASE_CURRENCY}");
+        let forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&forex_url)
+            .build();
+
+        logger::info!(primary_forex_request=?forex_request,"forex_log: Primary api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Primary forex fetch api unresponsive")?;
+        let forex_response = response
+            .json::<ForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from primary api into ForexResponse",
+            )?;
+
+        logger::info!(primary_forex_response=?forex_response,"forex_log");
+
+        let conversions = usd_conversions(|currency| {
+            forex_response
+                .rates
+                .get(&currency.to_string())
+                .map(|rate| **rate)
+        });
+
+        Ok(ExchangeRates::new(enums::Currency::USD, conversions))
+    }
+}
+
+/// apilayer.net, used when the primary provider is unavailable
+pub struct ApiLayerProvider;
+
+#[async_trait::async_trait]
+impl ForexProvider for ApiLayerProvider {
+    fn name(&self) -> &'static str {
+        "api_layer"
+    }
+
+    async fn fetch_rates(&self, state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        let fallback_forex_api_key = state.conf.forex_api.get_inner().fallback_api_key.peek();
+
+        let fallback_forex_url: String =
+            format!("{FALLBACK_FOREX_BASE_URL}{fallback_forex_api_key}");
+        let fallback_forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&fallback_forex_url)
+            .build();
+
+        logger::info!(fallback_forex_request=?fallback_forex_request,"forex_log: Fallback api call for forex fetch");
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                fallback_forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexError::ApiUnresponsive)
+            .attach_printable("Fallback forex fetch api unresponsive")?;
+
+        let fallback_forex_response = response
+            .json::<FallbackForexResponse>()
+            .await
+            .change_context(ForexError::ParsingError)
+            .attach_printable(
+                "Unable to parse response received from fallback api into ForexResponse",
+            )?;
+
+        logger::info!(fallback_forex_response=?fallback_forex_response,"forex_log");
+
+        let conversions = usd_conversions(|currency| {
+            fallback_forex_response
+                .quotes
+                .get(format!("{FALLBACK_FOREX_API_CURRENCY_PREFIX}{currency}").as_str())
+                .map(|rate| **rate)
+        });
+
+        Ok(ExchangeRates::new(enums::Currency::USD, conversions))
+    }
+}
+
+/// Reads the exchange rates from a JSON file in the format of [`DefaultExchangeRates`], meant for
+/// environments without access to the forex APIs and for tests
+pub struct StaticFileProvider {
+    pub path: String,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for StaticFileProvider {
+    fn name(&self) -> &'static str {
+        "static_file"
+    }
+
+    async fn fetch_rates(&self, _state: &SessionState) -> CustomResult<ExchangeRates, ForexError> {
+        logger::debug!(path = %self.path, "forex_log: Reading forex from static file");
+        let contents = tokio::fs::read_to_string(&self.path)
+            .await
+            .change_context(ForexError::LocalReadError)
+            .attach_printable_lazy(|| format!("Unable to read forex file at {}", self.path))?;
+
+        let default_rates: DefaultExchangeRates = serde_json::from_str(&contents)
+            .change_context(ForexError::DefaultCurrencyParsingError)
+            .attach_printable("Unable to parse forex file into DefaultExchangeRates")?;
+
+        ExchangeRates::try_from(default_rates)
+    }
+}
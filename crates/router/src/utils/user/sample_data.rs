@@ -384,6 +384,8 @@ pub async fn generate_sample_data(
             setup_future_usage_applied: None,
             routing_approach: None,
             connector_request_reference_id: None,
+            retry_details: None,
+            triggered_velocity_rules: None,
         };
 
         let refund = if refunds_count < number_of_refunds && !is_failed_payment {
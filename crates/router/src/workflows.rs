@@ -2,12 +2,17 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod dispute_auto_response;
+pub mod dispute_deadline_reminder;
+pub mod forex_refresh;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
 
 pub mod refund_router;
 
+pub mod routing_activation;
+
 pub mod tokenized_data;
 
 pub mod revenue_recovery;
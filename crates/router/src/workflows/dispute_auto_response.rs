@@ -0,0 +1,146 @@
+#[cfg(feature = "v1")]
+use api_models::disputes::DisputeAutoResponseAction;
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::disputes::{self, auto_response},
+    types::{api::disputes as dispute_types, domain},
+};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct DisputeAutoResponseWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeAutoResponseWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::DisputeAutoResponseTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeAutoResponseTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute has been responded to or closed since the task was scheduled
+        if !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened)
+        {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let final_business_status = match tracking_data.action {
+            DisputeAutoResponseAction::Accept => {
+                Box::pin(disputes::accept_dispute(
+                    state.clone(),
+                    merchant_context,
+                    None,
+                    dispute_types::DisputeId {
+                        dispute_id: dispute.dispute_id,
+                    },
+                ))
+                .await?;
+                business_status::DISPUTE_ACCEPTED
+            }
+            DisputeAutoResponseAction::SubmitEvidence { evidence_template } => {
+                let config = auto_response::find_dispute_auto_response_config(
+                    db,
+                    &tracking_data.merchant_id,
+                )
+                .await?
+                .ok_or(errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "DisputeAutoResponseWorkflow",
+                })?;
+                let template = config.get_evidence_template(&evidence_template).ok_or(
+                    errors::ProcessTrackerError::FlowExecutionError {
+                        flow: "DisputeAutoResponseWorkflow",
+                    },
+                )?;
+
+                let submit_evidence_request = auto_response::get_evidence_request_from_template(
+                    state,
+                    &merchant_context,
+                    &dispute,
+                    template,
+                )
+                .await?;
+                Box::pin(disputes::submit_evidence(
+                    state.clone(),
+                    merchant_context,
+                    None,
+                    submit_evidence_request,
+                ))
+                .await?;
+                business_status::DISPUTE_EVIDENCE_SUBMITTED
+            }
+            DisputeAutoResponseAction::ManualReview => business_status::COMPLETED_BY_PT,
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, final_business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)?
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "v1")]
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+#[cfg(feature = "v1")]
+use crate::{core::disputes::deadline_reminder, routes::metrics, types::domain, utils::OptionExt};
+use crate::{errors, routes::SessionState, types::storage};
+
+pub struct DisputeDeadlineReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineReminderWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::DisputeDeadlineReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineReminderTrackingData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute has been responded to or closed since the task was scheduled
+        if dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    business_status::RESOURCE_STATUS_MISMATCH,
+                )
+                .await
+                .map_err(Into::into);
+        }
+        let Some(challenge_required_by) = dispute.challenge_required_by else {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        };
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let profile_id = dispute
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+            .await?;
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        let now = common_utils::date_time::now();
+        if now >= challenge_required_by {
+            deadline_reminder::expire_dispute(state, &merchant_context, business_profile, dispute)
+                .await?;
+
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::DISPUTE_EXPIRED)
+                .await
+                .map_err(Into::into);
+        }
+
+        // The retry count distinguishes the reminders sent for the dispute from one another
+        deadline_reminder::send_dispute_deadline_reminder(
+            state,
+            &merchant_context,
+            business_profile,
+            &dispute,
+            format!("reminder_{}", process.retry_count),
+        )
+        .await?;
+
+        // Once all the reminders have been sent, the task is scheduled at the deadline to mark the
+        // dispute as expired if it is still not responded to
+        let schedule_time = deadline_reminder::get_next_reminder_time(
+            &state.conf.dispute_deadline_reminder,
+            challenge_required_by,
+            now,
+        )
+        .unwrap_or(challenge_required_by);
+        let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(process.retry_count + 1),
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        db.process_tracker_update_process_status_by_ids(
+            vec![process.id.clone()],
+            updated_process_tracker_data,
+        )
+        .await?;
+        metrics::TASKS_RESET_COUNT.add(
+            1,
+            router_env::metric_attributes!(("flow", "DisputeDeadlineReminder")),
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)?
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,49 @@
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{errors, logger, routes::SessionState, types::storage, utils::currency};
+
+/// Refreshes the exchange rates saved in redis. Runs as a recurring job, configured under
+/// `scheduler.recurring_jobs`.
+pub struct ForexRefreshWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ForexRefreshWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        currency::refresh_forex_rates(state)
+            .await
+            .map_err(|error| {
+                logger::error!(forex_error = ?error, "Failed to refresh forex rates");
+                errors::ProcessTrackerError::FlowExecutionError {
+                    flow: "ForexRefreshWorkflow",
+                }
+            })?;
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        // The failed occurrence is not retried, the rates are refreshed by the next occurrence
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::FAILURE)
+            .await
+            .map_err(Into::<errors::ProcessTrackerError>::into)?;
+        Ok(())
+    }
+}
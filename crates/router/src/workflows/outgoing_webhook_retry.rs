@@ -120,6 +120,9 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
             is_overall_delivery_successful: Some(false),
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
+            dead_lettered_at: None,
+            dead_letter_status_code: None,
         };
 
         let event = db
@@ -184,11 +187,18 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             timestamp: event.created_at,
                         };
 
-                        let request_content = webhooks_core::get_outgoing_webhook_request(
-                            &merchant_context,
-                            outgoing_webhook,
+                        let request_content = webhooks_core::get_webhook_endpoint(
                             &business_profile,
+                            event.webhook_endpoint_id.as_deref(),
                         )
+                        .and_then(|webhook_endpoint| {
+                            webhooks_core::get_outgoing_webhook_request(
+                                &merchant_context,
+                                outgoing_webhook,
+                                &business_profile,
+                                &webhook_endpoint,
+                            )
+                        })
                         .map_err(|error| {
                             logger::error!(
                                 ?error,
@@ -325,14 +335,14 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
-/// Schedule the webhook delivery task for retry
+/// Schedule the webhook delivery task for retry, returns `false` if the retries are exhausted
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) async fn retry_webhook_delivery_task(
     db: &dyn StorageInterface,
     merchant_id: &id_type::MerchantId,
     process: storage::ProcessTracker,
-) -> errors::CustomResult<(), errors::StorageError> {
+) -> errors::CustomResult<bool, errors::StorageError> {
     let schedule_time =
         get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
 
@@ -340,12 +350,14 @@ pub(crate) async fn retry_webhook_delivery_task(
         Some(schedule_time) => {
             db.as_scheduler()
                 .retry_process(process, schedule_time)
-                .await
+                .await?;
+            Ok(true)
         }
         None => {
             db.as_scheduler()
                 .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
-                .await
+                .await?;
+            Ok(false)
         }
     }
 }
@@ -0,0 +1,287 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use error_stack::ResultExt;
+use scheduler::{consumer, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::routing::{self, helpers as routing_helpers},
+    errors, logger,
+    routes::SessionState,
+    types::{api::routing as routing_types, domain, storage},
+};
+
+/// Number of times a failed routing activation task is retried before it is marked as failed
+const ROUTING_ACTIVATION_MAX_RETRIES: i32 = 3;
+const ROUTING_ACTIVATION_RETRY_DELAY_IN_SECS: i64 = 300;
+
+pub struct RoutingActivationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingActivationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::RoutingActivationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("RoutingActivationTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                &key_store,
+                &tracking_data.profile_id,
+            )
+            .await?;
+
+        let active_algorithm_ref = routing_helpers::get_active_routing_algorithm_ref(
+            &business_profile,
+            &tracking_data.transaction_type,
+        )?;
+        let active_algorithm_id = active_algorithm_ref.algorithm_id.clone();
+
+        let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+            merchant_account,
+            key_store,
+        )));
+
+        match tracking_data.stage {
+            storage::RoutingActivationStage::Activate => {
+                if active_algorithm_id.as_ref() == Some(&tracking_data.algorithm_id) {
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::COMPLETED_BY_PT,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+
+                Box::pin(routing::link_routing_config(
+                    state.clone(),
+                    merchant_context,
+                    None,
+                    tracking_data.algorithm_id.clone(),
+                    tracking_data.transaction_type,
+                ))
+                .await?;
+
+                match tracking_data.rollback_config.as_ref() {
+                    Some(rollback_config) => {
+                        let activated_at = common_utils::date_time::now();
+                        let schedule_time = activated_at.saturating_add(time::Duration::seconds(
+                            i64::from(rollback_config.guard_window_in_secs),
+                        ));
+                        let updated_tracking_data = storage::RoutingActivationTrackingData {
+                            stage: storage::RoutingActivationStage::Guard,
+                            previous_algorithm_id: active_algorithm_id,
+                            activated_at: Some(activated_at),
+                            ..tracking_data
+                        }
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to encode routing activation tracking data")?;
+
+                        db.as_scheduler()
+                            .update_process(
+                                process,
+                                storage::ProcessTrackerUpdate::Update {
+                                    name: None,
+                                    retry_count: None,
+                                    schedule_time: Some(schedule_time),
+                                    tracking_data: Some(updated_tracking_data),
+                                    business_status: Some(String::from(
+                                        business_status::ROUTING_ALGORITHM_ACTIVATED,
+                                    )),
+                                    status: Some(storage_enums::ProcessTrackerStatus::New),
+                                    updated_at: Some(common_utils::date_time::now()),
+                                },
+                            )
+                            .await?;
+                    }
+                    None => {
+                        db.as_scheduler()
+                            .finish_process_with_business_status(
+                                process,
+                                business_status::COMPLETED_BY_PT,
+                            )
+                            .await?;
+                    }
+                }
+            }
+            storage::RoutingActivationStage::Guard => {
+                // The algorithm was changed after the scheduled activation, nothing to roll back
+                if active_algorithm_id.as_ref() != Some(&tracking_data.algorithm_id) {
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RESOURCE_STATUS_MISMATCH,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+
+                let rollback_config = tracking_data
+                    .rollback_config
+                    .as_ref()
+                    .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+                let activated_at = tracking_data
+                    .activated_at
+                    .ok_or(errors::ProcessTrackerError::MissingRequiredField)?;
+
+                // The algorithm was re-activated during the guard window, so the payments in the
+                // window cannot be attributed to this activation
+                if active_algorithm_ref.timestamp > activated_at.assume_utc().unix_timestamp() {
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RESOURCE_STATUS_MISMATCH,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+
+                // The success rate is measured from the dynamic routing stats recorded during the
+                // guard window, which are attributed to the activated algorithm as it has been
+                // continuously active since `activated_at`
+                let status_counts = db
+                    .get_dynamic_routing_stats_payment_status_with_count(
+                        &tracking_data.merchant_id,
+                        &tracking_data.profile_id,
+                        &common_utils::types::TimeRange {
+                            start_time: activated_at,
+                            end_time: Some(activated_at.saturating_add(time::Duration::seconds(
+                                i64::from(rollback_config.guard_window_in_secs),
+                            ))),
+                        },
+                    )
+                    .await?;
+                let (sample_size, success_rate) =
+                    routing_helpers::get_authorization_success_rate(&status_counts);
+
+                if sample_size < rollback_config.get_min_sample_size() {
+                    logger::info!(
+                        algorithm_id = ?tracking_data.algorithm_id,
+                        sample_size,
+                        "Not enough payments in the guard window to evaluate a routing rollback"
+                    );
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::COMPLETED_BY_PT,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+
+                if success_rate >= rollback_config.success_rate_threshold {
+                    return db
+                        .as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::COMPLETED_BY_PT,
+                        )
+                        .await
+                        .map_err(Into::into);
+                }
+
+                logger::warn!(
+                    algorithm_id = ?tracking_data.algorithm_id,
+                    previous_algorithm_id = ?tracking_data.previous_algorithm_id,
+                    success_rate,
+                    threshold = rollback_config.success_rate_threshold,
+                    "Rolling back routing algorithm as success rate dropped below the threshold"
+                );
+
+                match tracking_data.previous_algorithm_id.clone() {
+                    Some(previous_algorithm_id) => {
+                        Box::pin(routing::link_routing_config(
+                            state.clone(),
+                            merchant_context,
+                            None,
+                            previous_algorithm_id,
+                            tracking_data.transaction_type,
+                        ))
+                        .await?;
+                    }
+                    None => {
+                        Box::pin(routing::unlink_routing_config(
+                            state.clone(),
+                            merchant_context,
+                            routing_types::RoutingConfigRequest {
+                                name: None,
+                                description: None,
+                                algorithm: None,
+                                profile_id: Some(tracking_data.profile_id.clone()),
+                                transaction_type: Some(tracking_data.transaction_type),
+                            },
+                            None,
+                            tracking_data.transaction_type,
+                        ))
+                        .await?;
+                    }
+                }
+
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        business_status::ROUTING_ALGORITHM_ROLLED_BACK,
+                    )
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Err(errors::ProcessTrackerError::NotImplemented)?
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler_with_retries(
+            state.store.as_scheduler(),
+            process,
+            error,
+            ROUTING_ACTIVATION_MAX_RETRIES,
+            time::Duration::seconds(ROUTING_ACTIVATION_RETRY_DELAY_IN_SECS),
+        )
+        .await
+    }
+}
@@ -465,6 +465,8 @@ async fn payments_create_core() {
         issuer_error_message: None,
         is_iframe_redirection_enabled: None,
         whole_connector_response: None,
+        dcc_offer: None,
+        triggered_velocity_rules: None,
     };
     let expected_response =
         services::ApplicationResponse::JsonWithHeaders((expected_response, vec![]));
@@ -743,6 +745,8 @@ async fn payments_create_core_adyen_no_redirect() {
             issuer_error_message: None,
             is_iframe_redirection_enabled: None,
             whole_connector_response: None,
+            dcc_offer: None,
+            triggered_velocity_rules: None,
         },
         vec![],
     ));
@@ -227,6 +227,8 @@ async fn payments_create_core() {
         issuer_error_message: None,
         is_iframe_redirection_enabled: None,
         whole_connector_response: None,
+        dcc_offer: None,
+        triggered_velocity_rules: None,
     };
 
     let expected_response =
@@ -513,6 +515,8 @@ async fn payments_create_core_adyen_no_redirect() {
             issuer_error_message: None,
             is_iframe_redirection_enabled: None,
             whole_connector_response: None,
+            dcc_offer: None,
+            triggered_velocity_rules: None,
         },
         vec![],
     ));
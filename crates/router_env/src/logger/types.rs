@@ -313,6 +313,12 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute Auto Response config upsert flow
+    DisputeAutoResponseConfigUpsert,
+    /// Dispute Auto Response config retrieve flow
+    DisputeAutoResponseConfigRetrieve,
+    /// Dispute Auto Response config delete flow
+    DisputeAutoResponseConfigDelete,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -349,6 +355,10 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Gsm Rule Evaluate flow
+    GsmRuleEvaluate,
+    /// Gsm Rule List flow
+    GsmRuleList,
     /// Get data from embedded flow
     GetDataFromHyperswitchAiFlow,
     /// User Sign Up
@@ -543,6 +553,10 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List webhook events in the dead-letter queue
+    WebhookEventDeadLetterList,
+    /// Replay webhook events in the dead-letter queue
+    WebhookEventDeadLetterReplay,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -568,6 +582,8 @@ pub enum Flow {
     VolumeSplitOnRoutingType,
     /// Routing evaluate rule flow
     RoutingEvaluateRule,
+    /// Routing simulate config over historical payments flow
+    RoutingSimulateConfig,
     /// Relay flow
     Relay,
     /// Relay retrieve flow
@@ -608,6 +624,22 @@ pub enum Flow {
     TotalPaymentMethodCount,
     /// Process Tracker Revenue Recovery Workflow Retrieve
     RevenueRecoveryRetrieve,
+    /// Process Tracker task list flow
+    ProcessTrackerList,
+    /// Process Tracker task retrieve flow
+    ProcessTrackerRetrieve,
+    /// Process Tracker task force retry flow
+    ProcessTrackerRetry,
+    /// Process Tracker task reschedule flow
+    ProcessTrackerReschedule,
+    /// Process Tracker task cancel flow
+    ProcessTrackerCancel,
+    /// Process Tracker paused runners list flow
+    ProcessTrackerPausedRunnersList,
+    /// Process Tracker runner pause flow
+    ProcessTrackerRunnerPause,
+    /// Process Tracker runner resume flow
+    ProcessTrackerRunnerResume,
     /// Tokenization flow
     TokenizationCreate,
     /// Tokenization retrieve flow
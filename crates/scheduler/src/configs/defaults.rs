@@ -7,6 +7,8 @@ impl Default for super::settings::SchedulerSettings {
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
             server: super::settings::Server::default(),
+            recurring_jobs: std::collections::HashMap::new(),
+            lanes: std::collections::HashMap::new(),
         }
     }
 }
@@ -28,6 +30,7 @@ impl Default for super::settings::ConsumerSettings {
         Self {
             disabled: false,
             consumer_group: "SCHEDULER_GROUP".into(),
+            runner_concurrency: std::collections::HashMap::new(),
         }
     }
 }
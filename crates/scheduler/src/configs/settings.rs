@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+
+use diesel_models::process_tracker::ProcessTrackerRunner;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
+use crate::recurring::RecurringJob;
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(default)]
 pub struct SchedulerSettings {
@@ -10,6 +15,80 @@ pub struct SchedulerSettings {
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
     pub server: Server,
+    /// Recurring jobs, keyed by the name of the job
+    pub recurring_jobs: HashMap<String, RecurringJob>,
+    /// Lanes, keyed by the name of the lane. The tasks of the runners assigned to a lane are queued
+    /// on a stream of their own, which the consumer polls independently of the other streams.
+    /// The tasks of the remaining runners are queued on the default stream, which has a weight
+    /// of 1. Lanes of higher weight read more batches in each poll and are polled first.
+    pub lanes: HashMap<String, LaneSettings>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LaneSettings {
+    /// Runners whose tasks are queued on the lane
+    pub runners: Vec<ProcessTrackerRunner>,
+    /// Interval at which the consumer polls the lane (in milliseconds), defaults to the
+    /// `loop_interval` of the scheduler
+    #[serde(default)]
+    pub loop_interval: Option<u64>,
+    /// Number of batches the consumer reads from the lane in each poll, defaults to 1
+    #[serde(default)]
+    pub weight: Option<u64>,
+}
+
+/// A stream polled by the consumer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsumerLane {
+    pub stream_name: String,
+    pub loop_interval: u64,
+    /// Number of batches read from the stream in each poll
+    pub weight: u64,
+}
+
+impl SchedulerSettings {
+    /// Returns the stream on which the tasks of the runner are queued
+    pub fn get_stream_for_runner(&self, runner: Option<&str>) -> String {
+        runner
+            .and_then(|runner| {
+                self.lanes.iter().find(|(_, lane)| {
+                    lane.runners
+                        .iter()
+                        .any(|lane_runner| lane_runner.to_string() == runner)
+                })
+            })
+            .map(|(lane_name, _)| self.get_lane_stream_name(lane_name))
+            .unwrap_or_else(|| self.stream.clone())
+    }
+
+    /// Returns the streams polled by the consumer in descending order of weight, the default
+    /// stream coming first among the streams of the same weight
+    pub fn get_consumer_lanes(&self) -> Vec<ConsumerLane> {
+        let mut lanes = self
+            .lanes
+            .iter()
+            .map(|(lane_name, lane)| ConsumerLane {
+                stream_name: self.get_lane_stream_name(lane_name),
+                loop_interval: lane.loop_interval.unwrap_or(self.loop_interval),
+                weight: lane.weight.unwrap_or(1),
+            })
+            .collect::<Vec<_>>();
+        lanes.sort_by(|a, b| a.stream_name.cmp(&b.stream_name));
+        lanes.insert(
+            0,
+            ConsumerLane {
+                stream_name: self.stream.clone(),
+                loop_interval: self.loop_interval,
+                weight: 1,
+            },
+        );
+        lanes.sort_by(|a, b| b.weight.cmp(&a.weight));
+        lanes
+    }
+
+    fn get_lane_stream_name(&self, lane_name: &str) -> String {
+        format!("{}_{lane_name}", self.stream)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -36,4 +115,7 @@ pub struct ProducerSettings {
 pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
+    /// Maximum number of tasks of a runner executed concurrently by a consumer, the tasks of the
+    /// runners not listed here are not limited
+    pub runner_concurrency: HashMap<ProcessTrackerRunner, usize>,
 }
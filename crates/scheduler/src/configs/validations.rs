@@ -19,8 +19,62 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        self.consumer.validate()?;
+
         self.server.validate()?;
 
+        let now = common_utils::date_time::now();
+        for (job_name, job) in &self.recurring_jobs {
+            when(job.cron.next_after(now).is_none(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "cron expression of recurring job `{job_name}` does not have any occurrences"
+                )))
+            })?;
+        }
+
+        let mut lane_runners = std::collections::HashSet::new();
+        for (lane_name, lane) in &self.lanes {
+            when(lane_name.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "scheduler lane name must not be empty".into(),
+                ))
+            })?;
+
+            when(lane.loop_interval == Some(0), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "loop interval of scheduler lane `{lane_name}` must be greater than zero"
+                )))
+            })?;
+
+            when(lane.weight == Some(0), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "weight of scheduler lane `{lane_name}` must be greater than zero"
+                )))
+            })?;
+
+            for runner in &lane.runners {
+                when(!lane_runners.insert(runner), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "runner `{runner}` must not be assigned to more than one scheduler lane"
+                    )))
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl super::settings::ConsumerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        for (runner, concurrency) in &self.runner_concurrency {
+            common_utils::fp_utils::when(*concurrency == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "concurrency limit of runner `{runner}` must be greater than zero"
+                )))
+            })?;
+        }
+
         Ok(())
     }
 }
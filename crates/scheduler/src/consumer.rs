@@ -1,6 +1,7 @@
 // TODO: Figure out what to log
 
 use std::{
+    collections::HashMap,
     sync::{self, atomic},
     time as std_time,
 };
@@ -18,14 +19,16 @@ use router_env::{
     tracing::{self, Instrument},
 };
 use time::PrimitiveDateTime;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Semaphore};
 use uuid::Uuid;
 
 use super::env::logger;
 pub use super::workflows::ProcessTrackerWorkflow;
 use crate::{
-    configs::settings::SchedulerSettings, db::process_tracker::ProcessTrackerInterface, errors,
-    metrics, utils as pt_utils, SchedulerAppState, SchedulerInterface, SchedulerSessionState,
+    configs::settings::{ConsumerLane, SchedulerSettings},
+    db::process_tracker::ProcessTrackerInterface,
+    errors, metrics, recurring, utils as pt_utils, SchedulerAppState, SchedulerInterface,
+    SchedulerSessionState,
 };
 
 // Valid consumer business statuses
@@ -59,12 +62,11 @@ where
 
     tokio::time::sleep(Duration::from_millis(timeout.sample(&mut rng))).await;
 
-    let mut interval = tokio::time::interval(Duration::from_millis(settings.loop_interval));
-
     let mut shutdown_interval =
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let is_shutting_down = atomic::AtomicBool::new(false);
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!(?error, "Signal Handler Error");
@@ -75,54 +77,52 @@ where
     let task_handle =
         tokio::spawn(common_utils::signals::signal_handler(signal, tx).in_current_span());
 
-    'consumer: loop {
-        match rx.try_recv() {
-            Err(mpsc::error::TryRecvError::Empty) => {
-                interval.tick().await;
-
-                // A guard from env to disable the consumer
-                if settings.consumer.disabled {
-                    continue;
-                }
-                consumer_operation_counter.fetch_add(1, atomic::Ordering::SeqCst);
-                let start_time = std_time::Instant::now();
-                let tenants = state.get_tenants();
-                for tenant in tenants {
-                    let session_state = app_state_to_session_state(state, &tenant)?;
-                    pt_utils::consumer_operation_handler(
-                        session_state.clone(),
-                        settings.clone(),
-                        |error| {
-                            logger::error!(?error, "Failed to perform consumer operation");
-                        },
-                        workflow_selector,
-                    )
-                    .await;
-                }
-
-                let end_time = std_time::Instant::now();
-                let duration = end_time.saturating_duration_since(start_time).as_secs_f64();
-                logger::debug!("Time taken to execute consumer_operation: {}s", duration);
+    // The concurrency limits apply to the consumer as a whole, so the same limiter is shared by
+    // all the lanes and polls
+    let concurrency_limiter = RunnerConcurrencyLimiter::new(&settings.consumer.runner_concurrency);
+
+    // Each lane is polled independently, so that a backlog of tasks on one lane does not delay
+    // the execution of the tasks on the other lanes. The lanes are in descending order of weight,
+    // so that the lanes of higher weight are polled first whenever several of them are due.
+    let lane_consumers = settings.get_consumer_lanes().into_iter().map(|lane| {
+        run_lane_consumer(
+            state,
+            &settings,
+            lane,
+            &concurrency_limiter,
+            workflow_selector,
+            &app_state_to_session_state,
+            &consumer_operation_counter,
+            &is_shutting_down,
+        )
+    });
+    let mut lane_consumers = std::pin::pin!(future::try_join_all(lane_consumers));
 
-                let current_count =
-                    consumer_operation_counter.fetch_sub(1, atomic::Ordering::SeqCst);
-                logger::info!("Current tasks being executed: {}", current_count);
-            }
-            Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
-                logger::debug!("Awaiting shutdown!");
-                rx.close();
-                loop {
-                    shutdown_interval.tick().await;
-                    let active_tasks = consumer_operation_counter.load(atomic::Ordering::Acquire);
-                    logger::info!("Active tasks: {active_tasks}");
-                    match active_tasks {
-                        0 => {
-                            logger::info!("Terminating consumer");
-                            break 'consumer;
-                        }
-                        _ => continue,
-                    }
+    let is_shutdown_requested = tokio::select! {
+        result = &mut lane_consumers => {
+            result?;
+            false
+        }
+        _ = rx.recv() => true,
+    };
+
+    if is_shutdown_requested {
+        logger::debug!("Awaiting shutdown!");
+        rx.close();
+        is_shutting_down.store(true, atomic::Ordering::SeqCst);
+        lane_consumers.await?;
+
+        loop {
+            shutdown_interval.tick().await;
+            let active_tasks = consumer_operation_counter.load(atomic::Ordering::Acquire)
+                + concurrency_limiter.get_waiting_task_count();
+            logger::info!("Active tasks: {active_tasks}");
+            match active_tasks {
+                0 => {
+                    logger::info!("Terminating consumer");
+                    break;
                 }
+                _ => continue,
             }
         }
     }
@@ -134,25 +134,83 @@ where
     Ok(())
 }
 
+#[instrument(skip_all, fields(stream_name = %lane.stream_name))]
+async fn run_lane_consumer<T, U, F>(
+    state: &T,
+    settings: &sync::Arc<SchedulerSettings>,
+    lane: ConsumerLane,
+    concurrency_limiter: &RunnerConcurrencyLimiter,
+    workflow_selector: impl workflows::ProcessTrackerWorkflows<U> + 'static + Copy + std::fmt::Debug,
+    app_state_to_session_state: &F,
+    consumer_operation_counter: &atomic::AtomicU64,
+    is_shutting_down: &atomic::AtomicBool,
+) -> CustomResult<(), errors::ProcessTrackerError>
+where
+    T: SchedulerAppState + 'static,
+    U: SchedulerSessionState + 'static,
+    F: Fn(&T, &id_type::TenantId) -> CustomResult<U, errors::ProcessTrackerError>,
+{
+    let mut interval = tokio::time::interval(std_time::Duration::from_millis(lane.loop_interval));
+
+    loop {
+        interval.tick().await;
+
+        if is_shutting_down.load(atomic::Ordering::Acquire) {
+            return Ok(());
+        }
+
+        // A guard from env to disable the consumer
+        if settings.consumer.disabled {
+            continue;
+        }
+        consumer_operation_counter.fetch_add(1, atomic::Ordering::SeqCst);
+        let start_time = std_time::Instant::now();
+        let tenants = state.get_tenants();
+        for tenant in tenants {
+            let session_state = app_state_to_session_state(state, &tenant)?;
+            pt_utils::consumer_operation_handler(
+                session_state.clone(),
+                settings.clone(),
+                &lane,
+                concurrency_limiter,
+                |error| {
+                    logger::error!(?error, "Failed to perform consumer operation");
+                },
+                workflow_selector,
+            )
+            .await;
+        }
+
+        let end_time = std_time::Instant::now();
+        let duration = end_time.saturating_duration_since(start_time).as_secs_f64();
+        logger::debug!("Time taken to execute consumer_operation: {}s", duration);
+
+        let current_count = consumer_operation_counter.fetch_sub(1, atomic::Ordering::SeqCst);
+        logger::info!("Current tasks being executed: {}", current_count);
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
     state: &T,
-    settings: &SchedulerSettings,
+    settings: &sync::Arc<SchedulerSettings>,
+    lane: &ConsumerLane,
+    concurrency_limiter: &RunnerConcurrencyLimiter,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    let stream_name = settings.stream.clone();
+    let stream_name = lane.stream_name.as_str();
     let group_name = settings.consumer.consumer_group.clone();
     let consumer_name = format!("consumer_{}", Uuid::new_v4());
 
     let _group_created = &mut state
         .get_db()
-        .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
+        .consumer_group_create(stream_name, &group_name, &RedisEntryId::AfterLastID)
         .await;
 
-    let mut tasks = state
+    let tasks = state
         .get_db()
         .as_scheduler()
-        .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
+        .fetch_consumer_tasks(stream_name, &group_name, &consumer_name, lane.weight)
         .await?;
 
     if !tasks.is_empty() {
@@ -160,25 +218,78 @@ pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
     }
     let mut handler = vec![];
 
-    for task in tasks.iter_mut() {
-        let pickup_time = common_utils::date_time::now();
-
-        pt_utils::add_histogram_metrics(&pickup_time, task, &stream_name);
-
-        metrics::TASK_CONSUMED.add(1, &[]);
+    for task in tasks {
+        let state = state.clone();
+        let settings = settings.clone();
+        let stream_name = stream_name.to_owned();
+
+        let permit = match concurrency_limiter.get_semaphore(task.runner.as_deref()) {
+            Some(runner_semaphore) => match runner_semaphore.clone().try_acquire_owned() {
+                Ok(permit) => Some(permit),
+                // The runner has reached its concurrency limit, so the task waits for a permit on
+                // its own, without holding up the next poll of the lane. Tasks wait for a permit
+                // in the order in which they were picked.
+                Err(_) => {
+                    let waiting_task_guard = concurrency_limiter.track_waiting_task();
+                    tokio::task::spawn(
+                        async move {
+                            let _waiting_task_guard = waiting_task_guard;
+                            let _permit = runner_semaphore.acquire_owned().await.ok();
+                            execute_task(state, &settings, task, &stream_name, workflow_selector)
+                                .await
+                        }
+                        .in_current_span(),
+                    );
+                    continue;
+                }
+            },
+            None => None,
+        };
 
-        handler.push(tokio::task::spawn(start_workflow(
-            state.clone(),
-            task.clone(),
-            pickup_time,
-            workflow_selector,
-        )))
+        handler.push(tokio::task::spawn(
+            async move {
+                let _permit = permit;
+                execute_task(state, &settings, task, &stream_name, workflow_selector).await
+            }
+            .in_current_span(),
+        ))
     }
     future::join_all(handler).await;
 
     Ok(())
 }
 
+/// Executes the task picked by the consumer and enqueues the next occurrence of the task if it is
+/// a recurring job
+async fn execute_task<T: SchedulerSessionState + 'static>(
+    state: T,
+    settings: &SchedulerSettings,
+    mut task: storage::ProcessTracker,
+    stream_name: &str,
+    workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
+) {
+    let pickup_time = common_utils::date_time::now();
+
+    pt_utils::add_histogram_metrics(&pickup_time, &mut task, stream_name);
+
+    metrics::TASK_CONSUMED.add(1, &[]);
+
+    // The failures are logged by `start_workflow` and handled by the error handler of the workflow
+    start_workflow(state.clone(), task.clone(), pickup_time, workflow_selector)
+        .await
+        .ok();
+
+    recurring::enqueue_next_occurrence(
+        state.get_db().as_scheduler(),
+        &settings.recurring_jobs,
+        &task,
+    )
+    .await
+    .unwrap_or_else(|error| {
+        logger::error!(?error, pt.id = %task.id, "Failed to enqueue next occurrence of recurring job");
+    });
+}
+
 #[instrument(skip(db, redis_conn))]
 pub async fn fetch_consumer_tasks(
     db: &dyn ProcessTrackerInterface,
@@ -186,8 +297,16 @@ pub async fn fetch_consumer_tasks(
     stream_name: &str,
     group_name: &str,
     consumer_name: &str,
+    batch_count: u64,
 ) -> CustomResult<Vec<storage::ProcessTracker>, errors::ProcessTrackerError> {
-    let batches = pt_utils::get_batches(redis_conn, stream_name, group_name, consumer_name).await?;
+    let batches = pt_utils::get_batches(
+        redis_conn,
+        stream_name,
+        group_name,
+        consumer_name,
+        batch_count,
+    )
+    .await?;
 
     // Returning early to avoid execution of database queries when `batches` is empty
     if batches.is_empty() {
@@ -269,6 +388,83 @@ pub async fn consumer_error_handler(
     Ok(())
 }
 
+/// Retries the failed task after `retry_delay` until it has been retried `max_retries` times, after
+/// which the task is marked as failed by [`consumer_error_handler`].
+pub async fn consumer_error_handler_with_retries(
+    state: &(dyn SchedulerInterface + 'static),
+    process: storage::ProcessTracker,
+    error: errors::ProcessTrackerError,
+    max_retries: i32,
+    retry_delay: time::Duration,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    if process.retry_count >= max_retries {
+        return consumer_error_handler(state, process, error).await;
+    }
+
+    logger::warn!(
+        pt.name=?process.name,
+        pt.id=%process.id,
+        pt.retry_count=process.retry_count,
+        ?error,
+        "Failed to execute workflow, retrying"
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(retry_delay);
+    state
+        .retry_process(process, schedule_time)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+}
+
+/// Limits the number of tasks of each runner executed concurrently, as per the configured limits.
+/// A single limiter is created per consumer, so that the limits hold across its lanes and polls.
+pub struct RunnerConcurrencyLimiter {
+    semaphores: HashMap<String, sync::Arc<Semaphore>>,
+    /// Number of tasks waiting for a permit, or being executed after having waited for one, which
+    /// the consumer must wait for before shutting down
+    waiting_tasks: sync::Arc<atomic::AtomicU64>,
+}
+
+/// Counts a task as waiting on the limiter until it is dropped
+struct WaitingTaskGuard(sync::Arc<atomic::AtomicU64>);
+
+impl Drop for WaitingTaskGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, atomic::Ordering::SeqCst);
+    }
+}
+
+impl RunnerConcurrencyLimiter {
+    pub fn new(runner_concurrency: &HashMap<storage::ProcessTrackerRunner, usize>) -> Self {
+        Self {
+            semaphores: runner_concurrency
+                .iter()
+                .map(|(runner, concurrency)| {
+                    (
+                        runner.to_string(),
+                        sync::Arc::new(Semaphore::new(*concurrency)),
+                    )
+                })
+                .collect(),
+            waiting_tasks: sync::Arc::new(atomic::AtomicU64::new(0)),
+        }
+    }
+
+    fn track_waiting_task(&self) -> WaitingTaskGuard {
+        self.waiting_tasks.fetch_add(1, atomic::Ordering::SeqCst);
+        WaitingTaskGuard(self.waiting_tasks.clone())
+    }
+
+    fn get_waiting_task_count(&self) -> u64 {
+        self.waiting_tasks.load(atomic::Ordering::Acquire)
+    }
+
+    /// Returns the semaphore from which a task of the runner must acquire a permit before it is
+    /// executed, if the runner has a concurrency limit
+    fn get_semaphore(&self, runner: Option<&str>) -> Option<sync::Arc<Semaphore>> {
+        runner.and_then(|runner| self.semaphores.get(runner).cloned())
+    }
+}
+
 pub async fn create_task(
     db: &dyn ProcessTrackerInterface,
     process_tracker_entry: storage::ProcessTrackerNew,
@@ -26,6 +26,12 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError>;
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
+
     async fn process_tracker_update_process_status_by_ids(
         &self,
         task_ids: Vec<String>,
@@ -62,6 +68,22 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn reschedule_overdue_processes_by_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        overdue_since: PrimitiveDateTime,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -128,17 +150,33 @@ impl ProcessTrackerInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_status_unchanged(&conn, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
         schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
+        let event = this.record_event(
+            storage_enums::ProcessTrackerEventType::Reset,
+            Some(schedule_time),
+        );
         self.update_process(
             this,
             storage::ProcessTrackerUpdate::StatusRetryUpdate {
                 status: storage_enums::ProcessTrackerStatus::New,
                 retry_count: 0,
                 schedule_time,
+                event,
             },
         )
         .await?;
@@ -152,12 +190,17 @@ impl ProcessTrackerInterface for Store {
     ) -> CustomResult<(), errors::StorageError> {
         metrics::TASK_RETRIED.add(1, &[]);
         let retry_count = this.retry_count + 1;
+        let event = this.record_event(
+            storage_enums::ProcessTrackerEventType::Retried,
+            Some(schedule_time),
+        );
         self.update_process(
             this,
             storage::ProcessTrackerUpdate::StatusRetryUpdate {
                 status: storage_enums::ProcessTrackerStatus::Pending,
                 retry_count,
                 schedule_time,
+                event,
             },
         )
         .await?;
@@ -192,6 +235,45 @@ impl ProcessTrackerInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::filter_by_constraints(
+            &conn,
+            runner.map(|runner| runner.to_string()),
+            status,
+            business_status,
+            limit,
+            offset,
+            common_types::consts::API_VERSION,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn reschedule_overdue_processes_by_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        overdue_since: PrimitiveDateTime,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::reschedule_overdue_processes_by_runner(
+            &conn,
+            runner.to_string(),
+            overdue_since,
+            schedule_time,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -264,6 +346,15 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        _this: storage::ProcessTracker,
+        _process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn reset_process(
         &self,
         _this: storage::ProcessTracker,
@@ -299,4 +390,48 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_processes_by_constraints(
+        &self,
+        runner: Option<storage::ProcessTrackerRunner>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let runner = runner.map(|runner| runner.to_string());
+        let mut processes: Vec<_> = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                runner
+                    .as_ref()
+                    .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+                    && status.map_or(true, |status| process.status == status)
+                    && business_status.as_ref().map_or(true, |business_status| {
+                        &process.business_status == business_status
+                    })
+            })
+            .cloned()
+            .collect();
+        processes.sort_by(|a, b| b.schedule_time.cmp(&a.schedule_time));
+
+        Ok(processes
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or(0))
+            .take(usize::try_from(limit).unwrap_or(0))
+            .collect())
+    }
+
+    async fn reschedule_overdue_processes_by_runner(
+        &self,
+        _runner: storage::ProcessTrackerRunner,
+        _overdue_since: PrimitiveDateTime,
+        _schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -1,11 +1,18 @@
+use std::collections::HashMap;
+
 use common_utils::errors::CustomResult;
 use diesel_models::process_tracker as storage;
 use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
 use router_env::logger;
 use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
+use time::PrimitiveDateTime;
 
 use crate::{errors::ProcessTrackerError, scheduler::Store};
 
+/// Redis hash holding the runners whose tasks are not being scheduled, keyed by runner with the
+/// unix timestamp at which the runner was paused as the value.
+pub const PAUSED_RUNNERS_KEY: &str = "PAUSED_PROCESS_TRACKER_RUNNERS";
+
 #[async_trait::async_trait]
 pub trait QueueInterface {
     async fn fetch_consumer_tasks(
@@ -13,6 +20,7 @@ pub trait QueueInterface {
         stream_name: &str,
         group_name: &str,
         consumer_name: &str,
+        batch_count: u64,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError>;
 
     async fn consumer_group_create(
@@ -40,6 +48,22 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    async fn pause_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<(), RedisError>;
+
+    /// Returns `true` if the runner was paused before the call.
+    async fn resume_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<bool, RedisError>;
+
+    /// Returns the paused runners along with the time at which they were paused.
+    async fn get_paused_runners(
+        &self,
+    ) -> CustomResult<Vec<(storage::ProcessTrackerRunner, PrimitiveDateTime)>, RedisError>;
 }
 
 #[async_trait::async_trait]
@@ -49,6 +73,7 @@ impl QueueInterface for Store {
         stream_name: &str,
         group_name: &str,
         consumer_name: &str,
+        batch_count: u64,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
         crate::consumer::fetch_consumer_tasks(
             self,
@@ -59,6 +84,7 @@ impl QueueInterface for Store {
             stream_name,
             group_name,
             consumer_name,
+            batch_count,
         )
         .await
     }
@@ -132,6 +158,58 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.get_redis_conn()?.get_key::<Vec<u8>>(&key.into()).await
     }
+
+    async fn pause_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<(), RedisError> {
+        self.get_redis_conn()?
+            .set_hash_fields_without_modifying_ttl(
+                &PAUSED_RUNNERS_KEY.into(),
+                (
+                    runner.to_string(),
+                    common_utils::date_time::now_unix_timestamp(),
+                ),
+            )
+            .await
+    }
+
+    async fn resume_runner(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<bool, RedisError> {
+        let deleted_fields = self
+            .get_redis_conn()?
+            .delete_hash_fields(&PAUSED_RUNNERS_KEY.into(), vec![runner.to_string()])
+            .await?;
+        Ok(deleted_fields > 0)
+    }
+
+    async fn get_paused_runners(
+        &self,
+    ) -> CustomResult<Vec<(storage::ProcessTrackerRunner, PrimitiveDateTime)>, RedisError> {
+        let paused_runners = self
+            .get_redis_conn()?
+            .get_hash_fields::<HashMap<String, i64>>(&PAUSED_RUNNERS_KEY.into())
+            .await?;
+
+        Ok(paused_runners
+            .into_iter()
+            .filter_map(|(runner, paused_at)| {
+                let runner = runner
+                    .parse::<storage::ProcessTrackerRunner>()
+                    .inspect_err(|error| {
+                        logger::warn!(?error, %runner, "Ignoring unknown paused runner");
+                    })
+                    .ok()?;
+                let paused_at = time::OffsetDateTime::from_unix_timestamp(paused_at).ok()?;
+                Some((
+                    runner,
+                    PrimitiveDateTime::new(paused_at.date(), paused_at.time()),
+                ))
+            })
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
@@ -141,6 +219,7 @@ impl QueueInterface for MockDb {
         _stream_name: &str,
         _group_name: &str,
         _consumer_name: &str,
+        _batch_count: u64,
     ) -> CustomResult<Vec<storage::ProcessTracker>, ProcessTrackerError> {
         // [#172]: Implement function for `MockDb`
         Err(ProcessTrackerError::ResourceFetchingFailed {
@@ -187,4 +266,27 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
         Err(RedisError::RedisConnectionError.into())
     }
+
+    async fn pause_runner(
+        &self,
+        _runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<(), RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Err(RedisError::SetHashFailed)?
+    }
+
+    async fn resume_runner(
+        &self,
+        _runner: storage::ProcessTrackerRunner,
+    ) -> CustomResult<bool, RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Err(RedisError::DeleteHashFieldFailed)?
+    }
+
+    async fn get_paused_runners(
+        &self,
+    ) -> CustomResult<Vec<(storage::ProcessTrackerRunner, PrimitiveDateTime)>, RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Ok(Vec::new())
+    }
 }
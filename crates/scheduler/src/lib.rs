@@ -6,6 +6,7 @@ pub mod errors;
 pub mod flow;
 pub mod metrics;
 pub mod producer;
+pub mod recurring;
 pub mod scheduler;
 pub mod settings;
 pub mod utils;
@@ -12,3 +12,4 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+counter_metric!(RECURRING_JOBS_ENQUEUED, PT_METER); // Occurrences of recurring jobs enqueued
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use common_utils::{errors::CustomResult, id_type};
 use diesel_models::enums::ProcessTrackerStatus;
@@ -15,7 +15,7 @@ use super::{
     metrics,
 };
 use crate::{
-    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow,
+    configs::settings::SchedulerSettings, errors, flow::SchedulerFlow, recurring,
     scheduler::SchedulerInterface, utils::*, SchedulerAppState, SchedulerSessionState,
 };
 
@@ -107,6 +107,9 @@ where
     T: SchedulerSessionState,
 {
     lock_acquire_release::<_, _, _>(state.get_db().as_scheduler(), settings, move || async {
+        recurring::enqueue_recurring_jobs(state.get_db().as_scheduler(), &settings.recurring_jobs)
+            .await;
+
         let tasks = fetch_producer_tasks(state.get_db().as_scheduler(), settings).await?;
         debug!("Producer count of tasks {}", tasks.len());
 
@@ -172,9 +175,45 @@ pub async fn fetch_producer_tasks(
     }
 
     new_tasks.append(&mut pending_tasks);
+    skip_paused_runner_tasks(db, &mut new_tasks).await;
 
     // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
     #[allow(clippy::as_conversions)]
     metrics::TASKS_PICKED_COUNT.add(new_tasks.len() as u64, &[]);
     Ok(new_tasks)
 }
+
+/// Removes the tasks whose runner has been paused. The tasks are left untouched in the database
+/// and are picked up again once the runner is resumed.
+async fn skip_paused_runner_tasks(
+    db: &dyn SchedulerInterface,
+    tasks: &mut Vec<storage::ProcessTracker>,
+) {
+    let paused_runners = match db.get_paused_runners().await {
+        Ok(paused_runners) => paused_runners
+            .into_iter()
+            .map(|(runner, _)| runner.to_string())
+            .collect::<HashSet<_>>(),
+        Err(error) => {
+            // Scheduling continues for all runners if the paused runners cannot be read
+            error!(?error, "Failed to fetch paused process tracker runners");
+            return;
+        }
+    };
+
+    if paused_runners.is_empty() {
+        return;
+    }
+
+    let tasks_count = tasks.len();
+    tasks.retain(|task| {
+        task.runner
+            .as_ref()
+            .map_or(true, |runner| !paused_runners.contains(runner))
+    });
+    debug!(
+        ?paused_runners,
+        skipped_tasks_count = tasks_count.saturating_sub(tasks.len()),
+        "Skipped tasks of paused runners"
+    );
+}
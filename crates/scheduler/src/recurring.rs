@@ -0,0 +1,422 @@
+//! Recurring jobs, scheduled using cron expressions.
+//!
+//! Every occurrence of a recurring job is a separate process tracker entry, whose id is derived
+//! from the job name and the time of the occurrence. The database rejects a second entry with the
+//! same id, so each occurrence is enqueued only once even when multiple producers attempt to
+//! enqueue it. The producer enqueues the next occurrence of the jobs which do not have one, such as
+//! newly configured jobs, and the consumer enqueues the next occurrence of a job once it has run.
+
+use std::{collections::HashMap, str::FromStr};
+
+use common_utils::errors::CustomResult;
+use diesel_models::process_tracker::{self as storage, ProcessTrackerRunner};
+use error_stack::ResultExt;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use time::{Date, PrimitiveDateTime, Time};
+
+use crate::{env::logger, errors, metrics, SchedulerInterface};
+
+/// Tag added to the process tracker entries of recurring jobs
+pub const RECURRING_JOB_TAG: &str = "RECURRING_JOB";
+
+/// The number of days searched for the next occurrence of a cron schedule, sufficient to find
+/// occurrences which happen only on the 29th of February
+const MAX_SEARCH_DAYS: u16 = 366 * 8;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecurringJob {
+    /// Cron expression with the minute, hour, day of month, month and day of week fields,
+    /// evaluated in UTC
+    pub cron: CronSchedule,
+    pub runner: ProcessTrackerRunner,
+    /// Upper limit of the random delay added to the schedule time of each occurrence, so that the
+    /// jobs scheduled at the same time do not all run at once
+    #[serde(default)]
+    pub jitter_seconds: u32,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+/// Tracking data of the process tracker entries of recurring jobs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringJobTrackingData {
+    pub job_name: String,
+    /// The time of the occurrence as per the cron schedule, before the jitter was added
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub occurrence: PrimitiveDateTime,
+}
+
+/// A parsed cron expression, holding the allowed values of each field as a bitmask
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Returns the first occurrence strictly after the given time
+    pub fn next_after(&self, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let start = after
+            .replace_second(0)
+            .ok()?
+            .checked_add(time::Duration::minutes(1))?;
+
+        let mut date = start.date();
+        for _ in 0..MAX_SEARCH_DAYS {
+            if self.matches_date(date) {
+                let (from_hour, from_minute) = if date == start.date() {
+                    (start.hour(), start.minute())
+                } else {
+                    (0, 0)
+                };
+
+                for hour in (from_hour..24).filter(|hour| contains(self.hours, *hour)) {
+                    let first_minute = if hour == from_hour { from_minute } else { 0 };
+                    if let Some(minute) =
+                        (first_minute..60).find(|minute| contains(self.minutes, *minute))
+                    {
+                        return Time::from_hms(hour, minute, 0)
+                            .ok()
+                            .map(|time| date.with_time(time));
+                    }
+                }
+            }
+            date = date.next_day()?;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        if !contains(self.months, u8::from(date.month())) {
+            return false;
+        }
+
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().number_days_from_sunday());
+
+        // As in cron, a day matches either of the day fields when both of them are restricted
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = errors::ApplicationError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            errors::ApplicationError::InvalidConfigurationValueError(format!(
+                "invalid cron expression `{expression}`: {reason}"
+            ))
+        };
+
+        let [minutes, hours, days_of_month, months, days_of_week] = expression
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| invalid("expected 5 fields"))?;
+
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7).map_err(|e| invalid(&e))?;
+        // Both 0 and 7 represent Sunday
+        if contains(days_of_week_mask, 7) {
+            days_of_week_mask |= 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minutes, 0, 59).map_err(|e| invalid(&e))?,
+            hours: parse_field(hours, 0, 23).map_err(|e| invalid(&e))?,
+            days_of_month: parse_field(days_of_month, 1, 31).map_err(|e| invalid(&e))?,
+            months: parse_field(months, 1, 12).map_err(|e| invalid(&e))?,
+            days_of_week: days_of_week_mask,
+            day_of_month_restricted: !days_of_month.starts_with('*'),
+            day_of_week_restricted: !days_of_week.starts_with('*'),
+        })
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = errors::ApplicationError;
+
+    fn try_from(expression: String) -> Result<Self, Self::Error> {
+        expression.parse()
+    }
+}
+
+fn contains(mask: u64, value: u8) -> bool {
+    (mask >> value) & 1 == 1
+}
+
+/// Parses a field made of comma separated values, ranges (`1-5`) and steps (`*/15`, `0-30/10`)
+fn parse_field(field: &str, min: u8, max: u8) -> Result<u64, String> {
+    let parse_value = |value: &str| {
+        value
+            .parse::<u8>()
+            .ok()
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(|| format!("`{value}` is not a value between {min} and {max}"))
+    };
+
+    let mut mask = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (
+                range,
+                Some(
+                    step.parse::<usize>()
+                        .ok()
+                        .filter(|step| *step > 0)
+                        .ok_or_else(|| format!("`{step}` is not a valid step"))?,
+                ),
+            ),
+            None => (part, None),
+        };
+
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+            // A single value with a step, such as `5/15`, starts a range ending at the maximum
+            None if step.is_some() => (parse_value(range)?, max),
+            None => {
+                let value = parse_value(range)?;
+                (value, value)
+            }
+        };
+        if start > end {
+            return Err(format!("`{range}` is not a valid range"));
+        }
+
+        for value in (start..=end).step_by(step.unwrap_or(1)) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+pub fn get_occurrence_process_id(job_name: &str, occurrence: PrimitiveDateTime) -> String {
+    format!(
+        "{RECURRING_JOB_TAG}_{job_name}_{}",
+        occurrence.assume_utc().unix_timestamp()
+    )
+}
+
+/// Enqueues the next occurrence of the recurring jobs which have not been enqueued yet
+pub async fn enqueue_recurring_jobs(
+    db: &dyn SchedulerInterface,
+    recurring_jobs: &HashMap<String, RecurringJob>,
+) {
+    let now = common_utils::date_time::now();
+    for (job_name, job) in recurring_jobs.iter().filter(|(_, job)| !job.disabled) {
+        let Some(occurrence) = job.cron.next_after(now) else {
+            logger::warn!(%job_name, "Recurring job does not have an upcoming occurrence");
+            continue;
+        };
+
+        let result = match db
+            .find_process_by_id(&get_occurrence_process_id(job_name, occurrence))
+            .await
+        {
+            Ok(Some(_)) => continue,
+            Ok(None) => enqueue_occurrence(db, job_name, job, occurrence).await,
+            Err(error) => {
+                Err(error.change_context(errors::ProcessTrackerError::ProcessFetchingFailed))
+            }
+        };
+
+        if let Err(error) = result {
+            logger::error!(?error, %job_name, "Failed to enqueue recurring job");
+        }
+    }
+}
+
+/// Enqueues the occurrence of the recurring job following the one the process was created for.
+/// Processes which are not occurrences of recurring jobs are ignored.
+pub async fn enqueue_next_occurrence(
+    db: &dyn SchedulerInterface,
+    recurring_jobs: &HashMap<String, RecurringJob>,
+    process: &storage::ProcessTracker,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    if !process.tag.iter().any(|tag| tag == RECURRING_JOB_TAG) {
+        return Ok(());
+    }
+
+    let tracking_data: RecurringJobTrackingData =
+        serde_json::from_value(process.tracking_data.clone())
+            .change_context(errors::ProcessTrackerError::DeserializationFailed)
+            .attach_printable("Failed to parse recurring job tracking data")?;
+
+    let Some(job) = recurring_jobs
+        .get(&tracking_data.job_name)
+        .filter(|job| !job.disabled)
+    else {
+        logger::info!(
+            job_name = %tracking_data.job_name,
+            "Recurring job is no longer enabled, not enqueuing the next occurrence"
+        );
+        return Ok(());
+    };
+
+    // The occurrence after the current time is used when the process ran late, so that the
+    // missed occurrences are skipped instead of all running at once
+    let after = tracking_data.occurrence.max(common_utils::date_time::now());
+    let occurrence = job
+        .cron
+        .next_after(after)
+        .ok_or(errors::ProcessTrackerError::ConfigurationError)
+        .attach_printable("Recurring job does not have an upcoming occurrence")?;
+
+    enqueue_occurrence(db, &tracking_data.job_name, job, occurrence).await
+}
+
+async fn enqueue_occurrence(
+    db: &dyn SchedulerInterface,
+    job_name: &str,
+    job: &RecurringJob,
+    occurrence: PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let jitter = rand::thread_rng().gen_range(0..=job.jitter_seconds);
+    let schedule_time = occurrence.saturating_add(time::Duration::seconds(i64::from(jitter)));
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_occurrence_process_id(job_name, occurrence),
+        job_name,
+        job.runner,
+        [RECURRING_JOB_TAG],
+        RecurringJobTrackingData {
+            job_name: job_name.to_string(),
+            occurrence,
+        },
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ProcessTrackerError::SerializationFailed)
+    .attach_printable("Failed to construct recurring job process tracker entry")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            metrics::RECURRING_JOBS_ENQUEUED.add(
+                1,
+                router_env::metric_attributes!(("job_name", job_name.to_string())),
+            );
+            logger::info!(%job_name, %occurrence, %schedule_time, "Enqueued recurring job");
+            Ok(())
+        }
+        // The occurrence has already been enqueued by another producer or consumer
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => {
+            Err(error.change_context(errors::ProcessTrackerError::ProcessInsertionFailed))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use time::Month;
+
+    use super::*;
+
+    fn datetime(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> PrimitiveDateTime {
+        Date::from_calendar_date(year, Month::try_from(month).unwrap(), day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let cases = [
+            (
+                "0 2 * * *",
+                datetime(2024, 3, 10, 1, 59),
+                datetime(2024, 3, 10, 2, 0),
+            ),
+            (
+                "0 2 * * *",
+                datetime(2024, 3, 10, 2, 0),
+                datetime(2024, 3, 11, 2, 0),
+            ),
+            (
+                "*/15 * * * *",
+                datetime(2024, 3, 10, 10, 16),
+                datetime(2024, 3, 10, 10, 30),
+            ),
+            (
+                "0 * * * *",
+                datetime(2024, 12, 31, 23, 30),
+                datetime(2025, 1, 1, 0, 0),
+            ),
+            (
+                "30 9 * * 1-5",
+                datetime(2024, 3, 9, 12, 0),
+                datetime(2024, 3, 11, 9, 30),
+            ),
+            (
+                "0 0 29 2 *",
+                datetime(2024, 3, 1, 0, 0),
+                datetime(2028, 2, 29, 0, 0),
+            ),
+            (
+                "0 0 1 * 0",
+                datetime(2024, 3, 2, 0, 0),
+                datetime(2024, 3, 3, 0, 0),
+            ),
+            (
+                "0 0 * * 7",
+                datetime(2024, 3, 4, 0, 0),
+                datetime(2024, 3, 10, 0, 0),
+            ),
+            (
+                "5/20 8-10 * * *",
+                datetime(2024, 3, 10, 8, 46),
+                datetime(2024, 3, 10, 9, 5),
+            ),
+        ];
+
+        for (expression, after, expected) in cases {
+            let schedule = CronSchedule::from_str(expression).unwrap();
+            assert_eq!(
+                schedule.next_after(after),
+                Some(expected),
+                "Unexpected occurrence of `{expression}` after {after}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "0 2 * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "*/0 * * * *",
+            "10-5 * * * *",
+            "a * * * *",
+        ] {
+            assert!(
+                CronSchedule::from_str(expression).is_err(),
+                "`{expression}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_schedule_without_occurrences() {
+        let schedule = CronSchedule::from_str("0 0 31 2 *").unwrap();
+        assert_eq!(schedule.next_after(datetime(2024, 1, 1, 0, 0)), None);
+    }
+}
@@ -13,8 +13,11 @@ use super::{
     env::logger,
 };
 use crate::{
-    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
-    flow::SchedulerFlow, metrics, SchedulerInterface, SchedulerSessionState,
+    configs::settings::{ConsumerLane, SchedulerSettings},
+    consumer::types::ProcessTrackerBatch,
+    errors,
+    flow::SchedulerFlow,
+    metrics, SchedulerInterface, SchedulerSessionState,
 };
 
 pub async fn divide_and_append_tasks<T>(
@@ -144,13 +147,33 @@ where
     }
 }
 
+/// Divides the tasks into batches, with the tasks of each lane batched separately so that they
+/// are appended to the stream of the lane.
 pub fn divide(
     tasks: Vec<storage::ProcessTracker>,
     conf: &SchedulerSettings,
 ) -> Vec<ProcessTrackerBatch> {
     let now = common_utils::date_time::now();
     let batch_size = conf.producer.batch_size;
-    divide_into_batches(batch_size, tasks, now, conf)
+
+    let mut tasks_by_stream: Vec<(String, Vec<storage::ProcessTracker>)> = Vec::new();
+    for task in tasks {
+        let stream_name = conf.get_stream_for_runner(task.runner.as_deref());
+        match tasks_by_stream
+            .iter_mut()
+            .find(|(stream, _)| *stream == stream_name)
+        {
+            Some((_, stream_tasks)) => stream_tasks.push(task),
+            None => tasks_by_stream.push((stream_name, vec![task])),
+        }
+    }
+
+    tasks_by_stream
+        .into_iter()
+        .flat_map(|(stream_name, tasks)| {
+            divide_into_batches(batch_size, tasks, now, conf, stream_name)
+        })
+        .collect()
 }
 
 pub fn divide_into_batches(
@@ -158,6 +181,7 @@ pub fn divide_into_batches(
     tasks: Vec<storage::ProcessTracker>,
     batch_creation_time: time::PrimitiveDateTime,
     conf: &SchedulerSettings,
+    stream_name: String,
 ) -> Vec<ProcessTrackerBatch> {
     let batch_id = Uuid::new_v4().to_string();
 
@@ -167,7 +191,7 @@ pub fn divide_into_batches(
             let batch = ProcessTrackerBatch {
                 id: batch_id.clone(),
                 group_name: conf.consumer.consumer_group.clone(),
-                stream_name: conf.stream.clone(),
+                stream_name: stream_name.clone(),
                 connection_name: String::new(),
                 created_time: batch_creation_time,
                 rule: String::new(), // is it required?
@@ -184,13 +208,13 @@ pub async fn get_batches(
     stream_name: &str,
     group_name: &str,
     consumer_name: &str,
+    batch_count: u64,
 ) -> CustomResult<Vec<ProcessTrackerBatch>, errors::ProcessTrackerError> {
     let response = match conn
         .stream_read_with_options(
             stream_name,
             RedisEntryId::UndeliveredEntryID,
-            // Update logic for collecting to Vec and flattening, if count > 1 is provided
-            Some(1),
+            Some(batch_count),
             None,
             Some((group_name, consumer_name)),
         )
@@ -226,8 +250,7 @@ pub async fn get_batches(
     }).collect::<CustomResult<Vec<(Vec<ProcessTrackerBatch>, Vec<String>)>, errors::ProcessTrackerError>>()?
     .into_iter()
     .unzip();
-    // Flattening the Vec's since the count provided above is 1. This needs to be updated if a
-    // count greater than 1 is provided.
+    // Flattening the batches and entry IDs read from the stream
     let batches = batches.into_iter().flatten().collect::<Vec<_>>();
     let entry_ids = entry_ids.into_iter().flatten().collect::<Vec<_>>();
 
@@ -267,6 +290,8 @@ pub fn get_time_from_delta(delta: Option<i32>) -> Option<time::PrimitiveDateTime
 pub async fn consumer_operation_handler<E, T>(
     state: T,
     settings: sync::Arc<SchedulerSettings>,
+    lane: &ConsumerLane,
+    concurrency_limiter: &consumer::RunnerConcurrencyLimiter,
     error_handler_fun: E,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) where
@@ -274,7 +299,15 @@ pub async fn consumer_operation_handler<E, T>(
     E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
     T: SchedulerSessionState + Send + Sync + 'static,
 {
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(
+        &state,
+        &settings,
+        lane,
+        concurrency_limiter,
+        workflow_selector,
+    )
+    .await
+    {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -455,4 +488,121 @@ mod tests {
             );
         }
     }
+    fn get_task(runner: storage::ProcessTrackerRunner) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: Uuid::new_v4().to_string(),
+            name: None,
+            tag: vec![],
+            runner: Some(runner.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: String::from(storage::business_status::PENDING),
+            status: ProcessTrackerStatus::New,
+            event: vec![],
+            created_at: now,
+            updated_at: now,
+            version: common_types::consts::API_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_divide_by_lane() {
+        use crate::configs::settings::LaneSettings;
+
+        let mut settings = SchedulerSettings::default();
+        settings.producer.batch_size = 2;
+        settings.lanes.insert(
+            "webhooks".to_string(),
+            LaneSettings {
+                runners: vec![storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow],
+                loop_interval: None,
+                weight: None,
+            },
+        );
+
+        let tasks = [
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+        ]
+        .into_iter()
+        .map(get_task)
+        .collect::<Vec<_>>();
+
+        let batches = divide(tasks, &settings)
+            .into_iter()
+            .map(|batch| (batch.stream_name, batch.trackers.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            batches,
+            vec![
+                ("SCHEDULER_STREAM".to_string(), 2),
+                ("SCHEDULER_STREAM".to_string(), 1),
+                ("SCHEDULER_STREAM_webhooks".to_string(), 1),
+            ]
+        );
+        assert_eq!(
+            settings
+                .get_consumer_lanes()
+                .into_iter()
+                .map(|lane| lane.stream_name)
+                .collect::<Vec<_>>(),
+            vec![
+                "SCHEDULER_STREAM".to_string(),
+                "SCHEDULER_STREAM_webhooks".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_consumer_lanes_by_weight() {
+        use crate::configs::settings::LaneSettings;
+
+        let mut settings = SchedulerSettings::default();
+        for (lane_name, runner, weight) in [
+            (
+                "webhooks",
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                Some(4),
+            ),
+            (
+                "refunds",
+                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+                Some(2),
+            ),
+            (
+                "sync",
+                storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+                None,
+            ),
+        ] {
+            settings.lanes.insert(
+                lane_name.to_string(),
+                LaneSettings {
+                    runners: vec![runner],
+                    loop_interval: None,
+                    weight,
+                },
+            );
+        }
+
+        assert_eq!(
+            settings
+                .get_consumer_lanes()
+                .into_iter()
+                .map(|lane| (lane.stream_name, lane.weight))
+                .collect::<Vec<_>>(),
+            vec![
+                ("SCHEDULER_STREAM_webhooks".to_string(), 4),
+                ("SCHEDULER_STREAM_refunds".to_string(), 2),
+                ("SCHEDULER_STREAM".to_string(), 1),
+                ("SCHEDULER_STREAM_sync".to_string(), 1),
+            ]
+        );
+    }
 }
@@ -42,7 +42,9 @@ pub enum StorageError {
 impl From<error_stack::Report<RedisError>> for StorageError {
     fn from(err: error_stack::Report<RedisError>) -> Self {
         match err.current_context() {
-            RedisError::NotFound => Self::ValueNotFound("redis value not found".to_string()),
+            RedisError::NotFound | RedisError::ValueDeleted => {
+                Self::ValueNotFound("redis value not found".to_string())
+            }
             RedisError::JsonSerializationFailed => Self::SerializationFailed,
             RedisError::JsonDeserializationFailed => Self::DeserializationFailed,
             _ => Self::RedisError(err),
@@ -99,9 +101,9 @@ pub trait RedisErrorExt {
 impl RedisErrorExt for error_stack::Report<RedisError> {
     fn to_redis_failed_response(self, key: &str) -> error_stack::Report<StorageError> {
         match self.current_context() {
-            RedisError::NotFound => self.change_context(StorageError::ValueNotFound(format!(
-                "Data does not exist for key {key}",
-            ))),
+            RedisError::NotFound | RedisError::ValueDeleted => self.change_context(
+                StorageError::ValueNotFound(format!("Data does not exist for key {key}",)),
+            ),
             RedisError::SetNxFailed | RedisError::SetAddMembersFailed => {
                 self.change_context(StorageError::DuplicateValue {
                     entity: "redis",
@@ -25,8 +25,8 @@ use crate::{
     lookup::ReverseLookupInterface,
     metrics,
     redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, KvStorePartition, Op, PartitionKey,
-        RedisConnInterface,
+        decide_storage_scheme, kv_wrapper, KvOperation, KvStorePartition, KvTombstone, Op,
+        PartitionKey, RedisConnInterface,
     },
     utils::{find_all_combined_kv_database, try_redis_get_else_try_database_get},
     RouterStore, UniqueConstraints,
@@ -58,6 +58,11 @@ pub struct UpdateResourceParams<'a> {
     pub operation: Op<'a>,
 }
 
+pub struct DeleteResourceParams<'a> {
+    pub deletable: kv::Deletable,
+    pub find_by: FindResourceBy<'a>,
+}
+
 pub struct FilterResourceParams<'a> {
     pub key: PartitionKey<'a>,
     pub pattern: &'static str,
@@ -318,13 +323,20 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                         }
                     };
 
+                    let redis_fut = async {
+                        match Box::pin(kv_wrapper(self, KvOperation::<M>::HGet(&field), key)).await
+                        {
+                            Err(error)
+                                if matches!(error.current_context(), RedisError::ValueDeleted) =>
+                            {
+                                Ok(None)
+                            }
+                            result => result?.try_into_hget().map(Some),
+                        }
+                    };
+
                     Box::pin(try_redis_get_else_try_database_get(
-                        async {
-                            Box::pin(kv_wrapper(self, KvOperation::<M>::HGet(&field), key))
-                                .await?
-                                .try_into_hget()
-                                .map(Some)
-                        },
+                        redis_fut,
                         database_call,
                     ))
                     .await
@@ -490,6 +502,122 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         .await
         .change_context(errors::StorageError::DecryptionError)
     }
+    /// Deletes the resource and returns it. In the KV storage scheme the record is replaced with a
+    /// tombstone in redis and the deletion is pushed to the drainer, while resources which are
+    /// present only in the database are deleted from the database directly.
+    pub async fn delete_resource<D, R, M>(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+        delete_resource_fut: R,
+        params: DeleteResourceParams<'_>,
+    ) -> error_stack::Result<D, errors::StorageError>
+    where
+        D: DomainType,
+        M: StorageModel<D>,
+        R: futures::Future<Output = error_stack::Result<M, DatabaseError>> + Send,
+    {
+        self.delete_storage_resource(storage_scheme, delete_resource_fut, params)
+            .await?
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    /// Deletes the resource in the same way as [`Self::delete_resource`], for resources which are
+    /// not converted to a domain type.
+    pub async fn delete_storage_resource<R, M>(
+        &self,
+        storage_scheme: MerchantStorageScheme,
+        delete_resource_fut: R,
+        DeleteResourceParams { deletable, find_by }: DeleteResourceParams<'_>,
+    ) -> error_stack::Result<M, errors::StorageError>
+    where
+        M: de::DeserializeOwned
+            + serde::Serialize
+            + Debug
+            + KvStorePartition
+            + UniqueConstraints
+            + Sync
+            + Send,
+        R: futures::Future<Output = error_stack::Result<M, DatabaseError>> + Send,
+    {
+        let database_call = || async {
+            delete_resource_fut.await.map_err(|error| {
+                let new_err = diesel_error_to_data_error(*error.current_context());
+                error.change_context(new_err)
+            })
+        };
+        let storage_scheme = Box::pin(decide_storage_scheme::<T, M>(
+            self,
+            storage_scheme,
+            Op::Delete,
+        ))
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => database_call().await,
+            MerchantStorageScheme::RedisKv => {
+                let (field, key) = match find_by {
+                    FindResourceBy::Id(field, key) => (field, key),
+                    FindResourceBy::LookupId(lookup_id) => {
+                        let lookup = fallback_reverse_lookup_not_found!(
+                            self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                                .await,
+                            database_call().await
+                        );
+                        (
+                            lookup.clone().sk_id,
+                            PartitionKey::CombinationKey {
+                                combination: &lookup.clone().pk_id,
+                            },
+                        )
+                    }
+                };
+                let key_str = key.to_string();
+
+                let resource = match Box::pin(kv_wrapper::<M, _, M>(
+                    self,
+                    KvOperation::HGet(&field),
+                    key.clone(),
+                ))
+                .await
+                .and_then(|result| result.try_into_hget())
+                {
+                    Ok(resource) => resource,
+                    Err(error) if matches!(error.current_context(), RedisError::NotFound) => {
+                        metrics::KV_MISS.add(1, &[]);
+                        return database_call().await;
+                    }
+                    Err(error) => return Err(error.to_redis_failed_response(&key_str)),
+                };
+
+                let redis_entry = kv::TypedSql {
+                    op: kv::DBOperation::Delete {
+                        deletable: Box::new(deletable),
+                        pk_id: key_str.clone(),
+                        sk_id: field.clone(),
+                    },
+                };
+                Box::pin(kv_wrapper::<(), _, _>(
+                    self,
+                    KvOperation::Delete(&field, &resource, redis_entry),
+                    key,
+                ))
+                .await
+                .map_err(|err| err.to_redis_failed_response(&key_str))?
+                .try_into_delete()
+                .change_context(errors::StorageError::KVError)?;
+
+                Ok(resource)
+            }
+        }
+    }
+
     pub async fn filter_resources<D, R, M>(
         &self,
         state: &KeyManagerState,
@@ -528,7 +656,30 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                     kv_result.map(|records| records.into_iter().filter(filter_fn).collect())
                 };
 
-                Box::pin(find_all_combined_kv_database(redis_fut, db_call, limit)).await
+                let resources =
+                    Box::pin(find_all_combined_kv_database(redis_fut, db_call, limit)).await?;
+
+                // The deleted records are still present in the database until the drainer
+                // deletes them
+                let deleted_records = self
+                    .get_redis_conn()
+                    .map_err(|err| err.to_redis_failed_response(&key.to_string()))?
+                    .hscan_and_deserialize::<KvTombstone>(&key.to_string().into(), pattern, None)
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key.to_string()))?
+                    .into_iter()
+                    .flat_map(|tombstone| tombstone.unique_constraints)
+                    .collect::<std::collections::HashSet<_>>();
+
+                Ok(resources
+                    .into_iter()
+                    .filter(|resource| {
+                        !resource
+                            .unique_constraints()
+                            .iter()
+                            .any(|constraint| deleted_records.contains(constraint))
+                    })
+                    .collect())
             }
         }?;
         let resource_futures = resources
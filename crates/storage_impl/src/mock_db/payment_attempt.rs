@@ -238,6 +238,8 @@ impl PaymentAttemptInterface for MockDb {
             routing_approach: payment_attempt.routing_approach,
             connector_request_reference_id: payment_attempt.connector_request_reference_id,
             debit_routing_savings: None,
+            retry_details: payment_attempt.retry_details,
+            triggered_velocity_rules: payment_attempt.triggered_velocity_rules,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
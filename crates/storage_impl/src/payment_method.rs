@@ -28,7 +28,9 @@ use crate::{
 };
 #[cfg(feature = "v1")]
 use crate::{
-    kv_router_store::{FilterResourceParams, InsertResourceParams, UpdateResourceParams},
+    kv_router_store::{
+        DeleteResourceParams, FilterResourceParams, InsertResourceParams, UpdateResourceParams,
+    },
     redis::kv_store::{Op, PartitionKey},
 };
 
@@ -359,15 +361,27 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         key_store: &MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
-        self.router_store
-            .delete_payment_method_by_merchant_id_payment_method_id(
-                state,
-                key_store,
+        let conn = pg_connection_write(self).await?;
+        self.delete_resource(
+            state,
+            key_store,
+            storage_scheme,
+            PaymentMethod::delete_by_merchant_id_payment_method_id(
+                &conn,
                 merchant_id,
                 payment_method_id,
-            )
-            .await
+            ),
+            DeleteResourceParams {
+                deletable: kv::Deletable::PaymentMethodDelete(kv::PaymentMethodDeleteMems {
+                    merchant_id: merchant_id.clone(),
+                    payment_method_id: payment_method_id.to_string(),
+                }),
+                find_by: FindResourceBy::LookupId(format!("payment_method_{payment_method_id}")),
+            },
+        )
+        .await
     }
 
     // Soft delete, Check if KV stuff is needed here
@@ -652,6 +666,7 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         key_store: &MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
         self.call_database(
@@ -899,6 +914,7 @@ impl PaymentMethodInterface for MockDb {
         key_store: &MerchantKeyStore,
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
+        _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
         let mut payment_methods = self.payment_methods.lock().await;
         match payment_methods
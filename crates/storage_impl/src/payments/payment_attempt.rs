@@ -690,6 +690,8 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .connector_request_reference_id
                         .clone(),
                     debit_routing_savings: None,
+                    retry_details: payment_attempt.retry_details.clone(),
+                    triggered_velocity_rules: payment_attempt.triggered_velocity_rules.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1898,6 +1900,8 @@ impl DataModelExt for PaymentAttempt {
             processor_merchant_id: Some(self.processor_merchant_id),
             created_by: self.created_by.map(|created_by| created_by.to_string()),
             connector_request_reference_id: self.connector_request_reference_id,
+            retry_details: self.retry_details,
+            triggered_velocity_rules: self.triggered_velocity_rules,
         }
     }
 
@@ -1991,6 +1995,8 @@ impl DataModelExt for PaymentAttempt {
             routing_approach: storage_model.routing_approach,
             connector_request_reference_id: storage_model.connector_request_reference_id,
             debit_routing_savings: None,
+            retry_details: storage_model.retry_details,
+            triggered_velocity_rules: storage_model.triggered_velocity_rules,
         }
     }
 }
@@ -2082,6 +2088,8 @@ impl DataModelExt for PaymentAttemptNew {
             setup_future_usage_applied: self.setup_future_usage_applied,
             routing_approach: self.routing_approach,
             connector_request_reference_id: self.connector_request_reference_id,
+            retry_details: self.retry_details,
+            triggered_velocity_rules: self.triggered_velocity_rules,
         }
     }
 
@@ -2166,6 +2174,8 @@ impl DataModelExt for PaymentAttemptNew {
             setup_future_usage_applied: storage_model.setup_future_usage_applied,
             routing_approach: storage_model.routing_approach,
             connector_request_reference_id: storage_model.connector_request_reference_id,
+            retry_details: storage_model.retry_details,
+            triggered_velocity_rules: storage_model.triggered_velocity_rules,
         }
     }
 }
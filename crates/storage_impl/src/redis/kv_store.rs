@@ -2,7 +2,7 @@ use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
 use diesel_models::enums::MerchantStorageScheme;
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use redis_interface::errors::RedisError;
 use router_derive::TryGetEnumVariant;
 use router_env::logger;
@@ -136,6 +136,16 @@ pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     HGet(&'a str),
     Get,
     Scan(&'a str),
+    /// Replaces the hash field of the record with a tombstone
+    Delete(&'a str, &'a S, TypedSql),
+}
+
+/// Stored in place of a deleted record until the key expires, so that reads do not fall back to
+/// the database row which the drainer has not yet deleted
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct KvTombstone {
+    /// The unique constraints of the deleted record
+    pub unique_constraints: Vec<String>,
 }
 
 #[derive(TryGetEnumVariant)]
@@ -147,6 +157,7 @@ pub enum KvResult<T: de::DeserializeOwned> {
     SetNx(redis_interface::SetnxReply),
     HSetNx(redis_interface::HsetnxReply),
     Scan(Vec<T>),
+    Delete(()),
 }
 
 impl<T> std::fmt::Display for KvOperation<'_, T>
@@ -161,6 +172,7 @@ where
             KvOperation::HGet(_) => f.write_str("Hget"),
             KvOperation::Get => f.write_str("Get"),
             KvOperation::Scan(_) => f.write_str("Scan"),
+            KvOperation::Delete(_, _, _) => f.write_str("Delete"),
         }
     }
 }
@@ -202,9 +214,28 @@ where
 
             KvOperation::HGet(field) => {
                 let result = redis_conn
-                    .get_hash_field_and_deserialize(&key.into(), field, type_name)
-                    .await?;
-                Ok(KvResult::HGet(result))
+                    .get_hash_field_and_deserialize(&key.clone().into(), field, type_name)
+                    .await;
+
+                match result {
+                    Ok(result) => Ok(KvResult::HGet(result)),
+                    Err(error)
+                        if matches!(
+                            error.current_context(),
+                            RedisError::JsonDeserializationFailed
+                        ) && redis_conn
+                            .get_hash_field_and_deserialize::<KvTombstone>(
+                                &key.into(),
+                                field,
+                                "KvTombstone",
+                            )
+                            .await
+                            .is_ok() =>
+                    {
+                        Err(report!(RedisError::ValueDeleted))
+                    }
+                    Err(error) => Err(error),
+                }
             }
 
             KvOperation::Scan(pattern) => {
@@ -265,6 +296,25 @@ where
                     .await?;
                 Ok(KvResult::Get(result))
             }
+
+            KvOperation::Delete(field, value, sql) => {
+                logger::debug!(kv_operation= %operation, value = ?value);
+
+                let tombstone = serde_json::to_string(&KvTombstone {
+                    unique_constraints: value.unique_constraints(),
+                })
+                .change_context(RedisError::JsonSerializationFailed)?;
+
+                redis_conn
+                    .set_hash_fields(&key.into(), (field, tombstone), Some(ttl.into()))
+                    .await?;
+
+                store
+                    .push_to_drainer_stream::<S>(sql, partition_key)
+                    .await?;
+
+                Ok(KvResult::Delete(()))
+            }
         }
     };
 
@@ -285,6 +335,7 @@ pub enum Op<'a> {
     Insert,
     Update(PartitionKey<'a>, &'a str, Option<&'a str>),
     Find,
+    Delete,
 }
 
 impl std::fmt::Display for Op<'_> {
@@ -292,6 +343,7 @@ impl std::fmt::Display for Op<'_> {
         match self {
             Op::Insert => f.write_str("insert"),
             Op::Find => f.write_str("find"),
+            Op::Delete => f.write_str("delete"),
             Op::Update(p_key, _, updated_by) => {
                 f.write_str(&format!("update_{p_key} for updated_by_{updated_by:?}"))
             }
@@ -318,6 +370,8 @@ where
         let updated_scheme = match operation {
             Op::Insert => MerchantStorageScheme::PostgresOnly,
             Op::Find => MerchantStorageScheme::RedisKv,
+            // Records which are not present in redis are deleted from the database directly
+            Op::Delete => MerchantStorageScheme::RedisKv,
             Op::Update(_, _, Some("postgres_only")) => MerchantStorageScheme::PostgresOnly,
             Op::Update(partition_key, field, Some(_updated_by)) => {
                 match Box::pin(kv_wrapper::<D, _, _>(
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'bin_range';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'issuer_country';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'card_network';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email_domain';
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'ip_cidr';
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS exchange_rate_snapshot (
+    rate_date DATE NOT NULL PRIMARY KEY,
+    base_currency "Currency" NOT NULL,
+    rates JSONB NOT NULL,
+    provider VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
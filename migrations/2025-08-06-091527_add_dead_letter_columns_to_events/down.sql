@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_merchant_id_dead_lettered_at_index;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS dead_lettered_at,
+DROP COLUMN IF EXISTS dead_letter_status_code;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS dead_lettered_at TIMESTAMP,
+ADD COLUMN IF NOT EXISTS dead_letter_status_code INTEGER;
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_dead_lettered_at_index ON events (merchant_id, dead_lettered_at);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE gateway_status_map DROP COLUMN IF EXISTS hit_count,
+    DROP COLUMN IF EXISTS last_hit_at;